@@ -0,0 +1,48 @@
+use bumpalo::Bump;
+
+/// A bump-allocated arena for AST nodes.
+///
+/// Parsing allocates a large number of small, never-individually-freed
+/// nodes; bump allocation turns that into a pointer-bump per node instead of
+/// a heap allocation, and frees the whole tree in one deallocation when the
+/// arena is dropped. [`AstArena::alloc`] hands out references tied to the
+/// arena's lifetime, so the parser's return type becomes `Node<'arena>`
+/// instead of an owned, individually-boxed tree.
+pub struct AstArena {
+    bump: Bump,
+}
+
+impl AstArena {
+    pub fn new() -> Self {
+        Self { bump: Bump::new() }
+    }
+
+    pub fn with_capacity(bytes: usize) -> Self {
+        Self { bump: Bump::with_capacity(bytes) }
+    }
+
+    pub fn alloc<T>(&self, value: T) -> &T {
+        self.bump.alloc(value)
+    }
+
+    pub fn alloc_slice<T: Copy>(&self, values: &[T]) -> &[T] {
+        self.bump.alloc_slice_copy(values)
+    }
+
+    pub fn allocated_bytes(&self) -> usize {
+        self.bump.allocated_bytes()
+    }
+
+    /// Drops every node allocated so far, reusing the underlying memory for
+    /// the next file. Used by the parallel parsing driver to avoid
+    /// reallocating an arena per file.
+    pub fn reset(&mut self) {
+        self.bump.reset();
+    }
+}
+
+impl Default for AstArena {
+    fn default() -> Self {
+        Self::new()
+    }
+}
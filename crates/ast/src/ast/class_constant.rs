@@ -0,0 +1,63 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use mago_span::HasSpan;
+use mago_span::Span;
+
+use crate::ast::expression::Expression;
+use crate::ast::identifier::LocalIdentifier;
+use crate::ast::r#type::Type;
+use crate::ast::modifier::Modifier;
+use crate::sequence::Sequence;
+use crate::sequence::TokenSeparatedSequence;
+
+/// A class constant declaration, e.g. `public const int MAX = 10;` (PHP 8.3
+/// adds the optional type; earlier versions only allow the bare form).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClassLikeConstant {
+    pub modifiers: Sequence<Modifier>,
+    pub r#const: Span,
+    /// The declared type, when present (PHP 8.3+).
+    pub hint: Option<Type>,
+    pub items: TokenSeparatedSequence<ClassLikeConstantItem>,
+    pub terminator: Span,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClassLikeConstantItem {
+    pub name: LocalIdentifier,
+    pub equals: Span,
+    pub value: Expression,
+}
+
+impl HasSpan for ClassLikeConstant {
+    fn span(&self) -> Span {
+        match self.modifiers.first() {
+            Some(first) => first.span().join(self.terminator),
+            None => self.r#const.join(self.terminator),
+        }
+    }
+}
+
+impl HasSpan for ClassLikeConstantItem {
+    fn span(&self) -> Span {
+        self.name.span().join(self.value.span())
+    }
+}
+
+/// `Foo::{$name}` / `Foo::$name` - a class constant fetched via a dynamic
+/// name, as opposed to the statically-known `Foo::BAR` form.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClassConstantDynamicAccess {
+    pub class: Box<Expression>,
+    pub double_colon: Span,
+    pub left_brace: Option<Span>,
+    pub expression: Box<Expression>,
+    pub right_brace: Option<Span>,
+}
+
+impl HasSpan for ClassConstantDynamicAccess {
+    fn span(&self) -> Span {
+        self.class.span().join(self.right_brace.unwrap_or_else(|| self.expression.span()))
+    }
+}
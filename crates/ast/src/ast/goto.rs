@@ -0,0 +1,34 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use mago_span::HasSpan;
+use mago_span::Span;
+
+use crate::ast::identifier::LocalIdentifier;
+
+/// A `goto label;` statement.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Goto {
+    pub goto: Span,
+    pub label: LocalIdentifier,
+    pub terminator: Span,
+}
+
+impl HasSpan for Goto {
+    fn span(&self) -> Span {
+        self.goto.join(self.terminator)
+    }
+}
+
+/// A `label:` statement, marking a valid jump target for `goto`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Label {
+    pub name: LocalIdentifier,
+    pub colon: Span,
+}
+
+impl HasSpan for Label {
+    fn span(&self) -> Span {
+        self.name.span().join(self.colon)
+    }
+}
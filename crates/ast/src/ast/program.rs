@@ -0,0 +1,44 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use mago_span::HasSpan;
+use mago_span::Span;
+
+use crate::ast::statement::Statement;
+use crate::sequence::Sequence;
+
+/// A `#!` line at the very start of a file, e.g. `#!/usr/bin/env php`.
+///
+/// PHP itself treats this as inline HTML and simply skips it, but modeling
+/// it as its own node lets tooling (the formatter, linters, "is this an
+/// executable script" checks) recognize it without pattern-matching on the
+/// first `InlineHtml` statement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Shebang {
+    pub span: Span,
+}
+
+impl HasSpan for Shebang {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+/// The root node of a parsed PHP file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Program {
+    /// The `#!...` line, if the file started with one.
+    pub shebang: Option<Shebang>,
+    pub statements: Sequence<Statement>,
+}
+
+impl HasSpan for Program {
+    fn span(&self) -> Span {
+        match (&self.shebang, self.statements.first(), self.statements.last()) {
+            (Some(shebang), _, Some(last)) => shebang.span.join(last.span()),
+            (Some(shebang), _, None) => shebang.span,
+            (None, Some(first), Some(last)) => first.span().join(last.span()),
+            (None, _, _) => Span::zero(),
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use mago_span::HasSpan;
+use mago_span::Span;
+
+use crate::ast::identifier::Identifier;
+use crate::ast::identifier::LocalIdentifier;
+use crate::sequence::Sequence;
+use crate::sequence::TokenSeparatedSequence;
+
+/// A `use Trait1, Trait2 { ... }` declaration inside a class body.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TraitUse {
+    pub r#use: Span,
+    pub trait_names: TokenSeparatedSequence<Identifier>,
+    pub specification: TraitUseSpecification,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum TraitUseSpecification {
+    Terminator(Span),
+    Block { left_brace: Span, adaptations: Sequence<TraitUseAdaptation>, right_brace: Span },
+}
+
+/// A single adaptation clause: either `Trait::method insteadof Other;` or
+/// `Trait::method as newName;` / `Trait::method as visibility newName;`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum TraitUseAdaptation {
+    Precedence { method: TraitUseMethodReference, insteadof: Span, excluded: TokenSeparatedSequence<Identifier>, terminator: Span },
+    Alias { method: TraitUseMethodReference, r#as: Span, visibility: Option<LocalIdentifier>, alias: Option<LocalIdentifier>, terminator: Span },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TraitUseMethodReference {
+    pub trait_name: Option<Identifier>,
+    pub double_colon: Option<Span>,
+    pub method_name: LocalIdentifier,
+}
+
+impl HasSpan for TraitUse {
+    fn span(&self) -> Span {
+        let end = match &self.specification {
+            TraitUseSpecification::Terminator(span) => *span,
+            TraitUseSpecification::Block { right_brace, .. } => *right_brace,
+        };
+
+        self.r#use.join(end)
+    }
+}
@@ -0,0 +1,56 @@
+use crate::ast::Program;
+
+/// A fixed magic prefix, followed by [`FORMAT_VERSION`], written before every
+/// serialized program, so a file that isn't a cache entry, or one written
+/// with a different format version, is rejected outright instead of being
+/// deserialized into garbage. The header records neither the Mago build
+/// version nor a hash of the source file.
+const MAGIC: &[u8; 4] = b"MGA1";
+const FORMAT_VERSION: u16 = 1;
+
+/// Serializes a [`Program`] with `bincode` rather than JSON.
+///
+/// This exists purely for the on-disk parse cache (`mago`'s `--cache-dir`):
+/// JSON's self-describing overhead (field names, string escaping) costs
+/// real time at the scale of "every file in the project, every run", while
+/// the cache never needs to be human-readable.
+pub fn serialize(program: &Program) -> Result<Vec<u8>, BinaryCacheError> {
+    let mut buffer = Vec::with_capacity(64 * 1024);
+    buffer.extend_from_slice(MAGIC);
+    buffer.extend_from_slice(&FORMAT_VERSION.to_le_bytes());
+
+    bincode::serialize_into(&mut buffer, program).map_err(BinaryCacheError::Encode)?;
+
+    Ok(buffer)
+}
+
+pub fn deserialize(bytes: &[u8]) -> Result<Program, BinaryCacheError> {
+    let Some(rest) = bytes.strip_prefix(MAGIC) else {
+        return Err(BinaryCacheError::BadMagic);
+    };
+
+    let Some((version_bytes, payload)) = rest.split_at_checked(2) else {
+        return Err(BinaryCacheError::Truncated);
+    };
+
+    let version = u16::from_le_bytes([version_bytes[0], version_bytes[1]]);
+    if version != FORMAT_VERSION {
+        return Err(BinaryCacheError::UnsupportedVersion(version));
+    }
+
+    bincode::deserialize(payload).map_err(BinaryCacheError::Decode)
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum BinaryCacheError {
+    #[error("not a mago AST cache file")]
+    BadMagic,
+    #[error("truncated cache file")]
+    Truncated,
+    #[error("cache file was written by an incompatible format version {0}")]
+    UnsupportedVersion(u16),
+    #[error("failed to encode AST: {0}")]
+    Encode(#[source] bincode::Error),
+    #[error("failed to decode AST: {0}")]
+    Decode(#[source] bincode::Error),
+}
@@ -0,0 +1,91 @@
+use std::str::FromStr;
+
+use crate::Node;
+
+/// The "tag" of an AST [`Node`], without any of its data.
+///
+/// This exists so tools (the `mago query` CLI command, rule configuration
+/// that wants to target "any expression", editor extensions) can refer to a
+/// node shape by name rather than matching on the `Node` enum directly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NodeKind {
+    Program,
+    Class,
+    Interface,
+    Trait,
+    Enum,
+    Function,
+    Method,
+    Closure,
+    ArrowFunction,
+    Property,
+    Constant,
+    Statement,
+    Expression,
+    Binary,
+    Call,
+    Match,
+    Switch,
+    For,
+    Foreach,
+    While,
+}
+
+impl NodeKind {
+    pub fn of(node: &Node<'_>) -> NodeKind {
+        match node {
+            Node::Program(_) => NodeKind::Program,
+            Node::Class(_) => NodeKind::Class,
+            Node::Interface(_) => NodeKind::Interface,
+            Node::Trait(_) => NodeKind::Trait,
+            Node::Enum(_) => NodeKind::Enum,
+            Node::Function(_) => NodeKind::Function,
+            Node::Method(_) => NodeKind::Method,
+            Node::Closure(_) => NodeKind::Closure,
+            Node::ArrowFunction(_) => NodeKind::ArrowFunction,
+            Node::Binary(_) => NodeKind::Binary,
+            Node::Call(_) => NodeKind::Call,
+            Node::Match(_) => NodeKind::Match,
+            Node::Switch(_) => NodeKind::Switch,
+            Node::For(_) => NodeKind::For,
+            Node::Foreach(_) => NodeKind::Foreach,
+            Node::While(_) => NodeKind::While,
+            other if other.is_statement() => NodeKind::Statement,
+            _ => NodeKind::Expression,
+        }
+    }
+}
+
+impl FromStr for NodeKind {
+    type Err = UnknownNodeKind;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        Ok(match name {
+            "program" => NodeKind::Program,
+            "class" => NodeKind::Class,
+            "interface" => NodeKind::Interface,
+            "trait" => NodeKind::Trait,
+            "enum" => NodeKind::Enum,
+            "function" => NodeKind::Function,
+            "method" => NodeKind::Method,
+            "closure" => NodeKind::Closure,
+            "arrow_function" => NodeKind::ArrowFunction,
+            "property" => NodeKind::Property,
+            "constant" => NodeKind::Constant,
+            "statement" => NodeKind::Statement,
+            "expression" => NodeKind::Expression,
+            "binary" => NodeKind::Binary,
+            "call" => NodeKind::Call,
+            "match" => NodeKind::Match,
+            "switch" => NodeKind::Switch,
+            "for" => NodeKind::For,
+            "foreach" => NodeKind::Foreach,
+            "while" => NodeKind::While,
+            _ => return Err(UnknownNodeKind(name.to_string())),
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("unknown node kind `{0}`")]
+pub struct UnknownNodeKind(pub String);
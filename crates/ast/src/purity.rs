@@ -0,0 +1,119 @@
+use mago_reflection::CodebaseReflection;
+
+use crate::ast::*;
+
+/// Conservatively determines whether evaluating `expression` could do
+/// anything beyond producing its value: assigning to a variable or
+/// property, or calling something that isn't known to be pure.
+///
+/// "Conservative" here means a false positive - reporting a side effect
+/// that can't actually happen - is acceptable, but a false negative is
+/// not: callers like dead-store removal or redundant-call detection delete
+/// code based on this returning `false`, so a call we can't prove pure is
+/// always treated as impure. The one gap this doesn't close is an
+/// expression kind that performs an effect without going through a call or
+/// an assignment (object construction running a side-effecting
+/// constructor, for instance) - those aren't modeled yet and fall through
+/// to "no effect", same as any other expression this function doesn't
+/// specifically recognize.
+pub fn has_side_effects(expression: &Expression, codebase: &CodebaseReflection) -> bool {
+    let mut found = false;
+
+    walk_expression(expression, &mut |candidate| {
+        if found {
+            return;
+        }
+
+        found = match candidate {
+            Expression::Assignment(_) | Expression::AssignmentOperation(_) => true,
+            // No per-method purity inference exists yet, so any method or
+            // static call is treated as potentially impure.
+            Expression::Call(Call::Method(_)) | Expression::Call(Call::StaticMethod(_)) => true,
+            Expression::Call(Call::Function(call)) => match call.function.as_ref() {
+                Expression::Identifier(Identifier::Local(identifier)) => {
+                    !is_known_pure_call(&identifier.value, codebase)
+                }
+                // A dynamic call target (`$fn()`, `(...)()`) could resolve
+                // to anything, including something impure.
+                _ => true,
+            },
+            // An array/list literal's elements aren't opened up by this
+            // walk, and a false negative isn't acceptable here (see above),
+            // so a literal that might hide an assignment (`[$a = 1]`,
+            // destructuring) is conservatively treated as impure rather
+            // than silently skipped.
+            Expression::Array(_) | Expression::List(_) => true,
+            _ => false,
+        };
+    });
+
+    found
+}
+
+/// Walks `expression` and every subexpression this analysis knows how to
+/// open up, feeding each one (including `expression` itself) to `f`.
+fn walk_expression<'a>(expression: &'a Expression, f: &mut impl FnMut(&'a Expression)) {
+    f(expression);
+
+    match expression {
+        Expression::Throw(r#throw) => walk_expression(&r#throw.exception, f),
+        Expression::Assignment(assignment) => {
+            walk_expression(&assignment.lhs, f);
+            walk_expression(&assignment.rhs, f);
+        }
+        Expression::AssignmentOperation(assignment) => {
+            walk_expression(&assignment.lhs, f);
+            walk_expression(&assignment.rhs, f);
+        }
+        Expression::Binary(binary) => {
+            walk_expression(&binary.lhs, f);
+            walk_expression(&binary.rhs, f);
+        }
+        Expression::Call(Call::Function(call)) => {
+            walk_expression(&call.function, f);
+            for argument in &call.arguments.arguments {
+                walk_expression(argument_value(argument), f);
+            }
+        }
+        Expression::Call(Call::Method(call)) => {
+            walk_expression(&call.object, f);
+            for argument in &call.arguments.arguments {
+                walk_expression(argument_value(argument), f);
+            }
+        }
+        Expression::Call(Call::StaticMethod(call)) => {
+            for argument in &call.arguments.arguments {
+                walk_expression(argument_value(argument), f);
+            }
+        }
+        Expression::Access(Access::Property(access)) => walk_expression(&access.object, f),
+        Expression::ArrayAccess(access) => {
+            walk_expression(&access.array, f);
+            if let Some(index) = access.index.as_deref() {
+                walk_expression(index, f);
+            }
+        }
+        Expression::Isset(isset) => {
+            for value in &isset.values {
+                walk_expression(value, f);
+            }
+        }
+        Expression::Empty(empty) => walk_expression(&empty.value, f),
+        _ => {}
+    }
+}
+
+fn argument_value(argument: &Argument) -> &Expression {
+    match argument {
+        Argument::Positional(positional) => &positional.value,
+        Argument::Named(named) => &named.value,
+    }
+}
+
+fn is_known_pure_call(name: &str, codebase: &CodebaseReflection) -> bool {
+    if mago_php_stdlib::is_known_pure(name) {
+        return true;
+    }
+
+    codebase.get_function(name).is_some_and(|function| function.is_pure())
+}
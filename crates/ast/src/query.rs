@@ -0,0 +1,90 @@
+use crate::Node;
+use crate::kind::NodeKind;
+
+/// A small query language for matching AST nodes without writing a visitor,
+/// e.g. `class > method[name=__construct]`.
+///
+/// The grammar is intentionally tiny: a chain of `>`-separated
+/// [`NodeKind`] selectors, each optionally constrained by one
+/// `[attribute=value]` predicate. It is meant for ad-hoc searches (the
+/// `mago query` command, rule-configuration "apply only within X" filters),
+/// not as a replacement for a hand-written recursive walk over [`crate::Node`]
+/// when the logic is non-trivial.
+#[derive(Debug, Clone)]
+pub struct Query {
+    selectors: Vec<Selector>,
+}
+
+#[derive(Debug, Clone)]
+struct Selector {
+    kind: NodeKind,
+    attribute: Option<(String, String)>,
+}
+
+impl Query {
+    pub fn parse(source: &str) -> Result<Query, QueryParseError> {
+        let mut selectors = Vec::new();
+
+        for part in source.split('>') {
+            let part = part.trim();
+            let (kind_name, attribute) = match part.split_once('[') {
+                Some((kind, rest)) => {
+                    let rest = rest.trim_end_matches(']');
+                    let (key, value) = rest.split_once('=').ok_or_else(|| QueryParseError(part.to_string()))?;
+                    (kind, Some((key.trim().to_string(), value.trim().to_string())))
+                }
+                None => (part, None),
+            };
+
+            let kind = kind_name.parse().map_err(|_| QueryParseError(part.to_string()))?;
+            selectors.push(Selector { kind, attribute });
+        }
+
+        if selectors.is_empty() {
+            return Err(QueryParseError(source.to_string()));
+        }
+
+        Ok(Query { selectors })
+    }
+
+    /// Whether `ancestors` (outermost first, ending with the candidate node
+    /// itself) satisfies this query.
+    pub fn matches(&self, ancestors: &[Node<'_>]) -> bool {
+        let Some(candidate) = ancestors.last() else {
+            return false;
+        };
+
+        let Some(last_selector) = self.selectors.last() else {
+            return false;
+        };
+
+        if !last_selector.matches(candidate) {
+            return false;
+        }
+
+        // Every earlier selector must match some ancestor, in order.
+        let mut remaining = &ancestors[..ancestors.len() - 1];
+        for selector in self.selectors[..self.selectors.len() - 1].iter().rev() {
+            let Some(position) = remaining.iter().rposition(|node| selector.matches(node)) else {
+                return false;
+            };
+
+            remaining = &remaining[..position];
+        }
+
+        true
+    }
+}
+
+impl Selector {
+    fn matches(&self, node: &Node<'_>) -> bool {
+        NodeKind::of(node) == self.kind
+            && self.attribute.as_ref().map_or(true, |(attribute, value)| {
+                crate::attribute_of(node, attribute).as_deref() == Some(value.as_str())
+            })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("invalid query segment `{0}`")]
+pub struct QueryParseError(pub String);
@@ -0,0 +1,12 @@
+use mago_span::Span;
+
+/// A plain, position-based text edit, independent of the AST.
+///
+/// This is the output format [`super::Rewriter::into_edits`] produces and the
+/// format consumed by LSP's `TextEdit` and by `mago_fixer`, so codemods and
+/// lint autofixes share a single representation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextEdit {
+    pub span: Span,
+    pub replacement: String,
+}
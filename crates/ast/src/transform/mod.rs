@@ -0,0 +1,31 @@
+use mago_span::Span;
+
+mod edit;
+mod rewriter;
+
+pub use edit::TextEdit;
+pub use rewriter::Rewriter;
+
+/// A single structural change to apply to the original source text, always
+/// expressed in terms of the original [`Span`]s rather than the (possibly
+/// already-edited) tree, so that an independent set of transforms can be
+/// computed without threading mutable tree state between them.
+#[derive(Debug, Clone)]
+pub enum Transform {
+    /// Replace the text covered by `span` with `replacement`.
+    Replace { span: Span, replacement: String },
+    /// Insert `text` immediately before `at`.
+    InsertBefore { at: Span, text: String },
+    /// Remove the text covered by `span` entirely.
+    Remove { span: Span },
+}
+
+impl Transform {
+    pub fn span(&self) -> Span {
+        match self {
+            Transform::Replace { span, .. } => *span,
+            Transform::InsertBefore { at, .. } => *at,
+            Transform::Remove { span } => *span,
+        }
+    }
+}
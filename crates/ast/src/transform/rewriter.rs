@@ -0,0 +1,83 @@
+use mago_span::Span;
+
+use super::Transform;
+use super::TextEdit;
+
+/// Accumulates a set of [`Transform`]s and turns them into [`TextEdit`]s, or
+/// into rewritten source text.
+///
+/// Transforms are always expressed against the *original* spans; this lets
+/// independent passes (e.g. several lint autofixes, or several steps of a
+/// rename) each describe their change without knowing about the others, as
+/// long as their spans don't overlap.
+#[derive(Debug, Default)]
+pub struct Rewriter {
+    transforms: Vec<Transform>,
+}
+
+impl Rewriter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, transform: Transform) {
+        self.transforms.push(transform);
+    }
+
+    /// Applies every accumulated transform to `source`, returning the
+    /// rewritten text.
+    ///
+    /// Transforms are applied from the end of the file backward so that
+    /// earlier spans stay valid as later ones are rewritten. Overlapping
+    /// transforms are a caller error: of several transforms starting at the
+    /// same offset only the first pushed is applied, and overlaps between
+    /// transforms with different start offsets are not detected.
+    pub fn apply(&self, source: &str) -> String {
+        let mut transforms = self.transforms.clone();
+        transforms.sort_by_key(|transform| std::cmp::Reverse(transform.span().start.offset));
+
+        let mut result = source.to_string();
+        let mut last_handled_start = usize::MAX;
+
+        for transform in transforms {
+            let span = transform.span();
+            if span.start.offset >= last_handled_start {
+                continue;
+            }
+            last_handled_start = span.start.offset;
+
+            let range = span.start.offset..span.end.offset;
+            match transform {
+                Transform::Replace { replacement, .. } => result.replace_range(range, &replacement),
+                Transform::Remove { .. } => result.replace_range(range, ""),
+                Transform::InsertBefore { text, .. } => result.insert_str(range.start, &text),
+            }
+        }
+
+        result
+    }
+
+    /// Converts the accumulated transforms into [`TextEdit`]s, one per
+    /// transform in push order, without touching the source text, for
+    /// callers (like the LSP crate) that need to hand edits to an external
+    /// editor instead of a rewritten string. Overlaps are not resolved.
+    pub fn into_edits(self) -> Vec<TextEdit> {
+        self.transforms
+            .into_iter()
+            .map(|transform| {
+                let span = transform.span();
+                let (span, replacement) = match transform {
+                    Transform::Replace { replacement, .. } => (span, replacement),
+                    Transform::InsertBefore { text, .. } => (Span::new(span.file_id, span.start, span.start), text),
+                    Transform::Remove { .. } => (span, String::new()),
+                };
+
+                TextEdit { span, replacement }
+            })
+            .collect()
+    }
+
+    pub fn span_of(transform: &Transform) -> Span {
+        transform.span()
+    }
+}
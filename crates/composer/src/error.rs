@@ -0,0 +1,38 @@
+use std::fmt;
+
+/// Something went wrong reading or parsing a `composer.json`/`composer.lock`.
+#[derive(Debug)]
+pub enum ComposerError {
+    Io(std::io::Error),
+    Json(serde_json::Error),
+}
+
+impl fmt::Display for ComposerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ComposerError::Io(error) => write!(f, "failed to read composer file: {error}"),
+            ComposerError::Json(error) => write!(f, "failed to parse composer file: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for ComposerError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ComposerError::Io(error) => Some(error),
+            ComposerError::Json(error) => Some(error),
+        }
+    }
+}
+
+impl From<std::io::Error> for ComposerError {
+    fn from(error: std::io::Error) -> Self {
+        ComposerError::Io(error)
+    }
+}
+
+impl From<serde_json::Error> for ComposerError {
+    fn from(error: serde_json::Error) -> Self {
+        ComposerError::Json(error)
+    }
+}
@@ -0,0 +1,9 @@
+pub mod error;
+pub mod lock;
+pub mod manifest;
+pub mod version_constraint;
+
+pub use error::ComposerError;
+pub use lock::ComposerLock;
+pub use manifest::ComposerManifest;
+pub use manifest::Psr4Mapping;
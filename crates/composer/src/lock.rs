@@ -0,0 +1,105 @@
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::error::ComposerError;
+
+/// A parsed `composer.lock`, reduced to the extensions the resolved
+/// dependency graph actually needs.
+#[derive(Debug, Clone, Default)]
+pub struct ComposerLock {
+    packages: Vec<RawPackage>,
+    platform_overrides: HashMap<String, String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct RawLock {
+    #[serde(default)]
+    packages: Vec<RawPackage>,
+    #[serde(rename = "packages-dev", default)]
+    packages_dev: Vec<RawPackage>,
+    #[serde(rename = "platform-overrides", default)]
+    platform_overrides: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+struct RawPackage {
+    #[serde(default)]
+    require: HashMap<String, String>,
+}
+
+impl ComposerLock {
+    /// Parses the raw contents of a `composer.lock` file.
+    pub fn parse(content: &str) -> Result<Self, ComposerError> {
+        let raw: RawLock = serde_json::from_str(content)?;
+
+        let mut packages = raw.packages;
+        packages.extend(raw.packages_dev);
+
+        Ok(Self { packages, platform_overrides: raw.platform_overrides })
+    }
+
+    /// Reads and parses `composer.lock` from `path`.
+    pub fn read(path: &Path) -> Result<Self, ComposerError> {
+        Self::parse(&std::fs::read_to_string(path)?)
+    }
+
+    /// The `ext-*` names referenced anywhere in the resolved dependency
+    /// graph, without the `ext-` prefix, sorted and deduplicated.
+    pub fn enabled_extensions(&self) -> Vec<String> {
+        let mut extensions: BTreeSet<String> = self
+            .packages
+            .iter()
+            .flat_map(|package| package.require.keys())
+            .chain(self.platform_overrides.keys())
+            .filter_map(|name| name.strip_prefix("ext-"))
+            .map(str::to_owned)
+            .collect();
+
+        // `ext-` entries sometimes also appear bare in platform-overrides
+        // without the packages' requirements agreeing on casing; normalizing
+        // isn't our job here, just report what's declared.
+        extensions.retain(|extension| !extension.is_empty());
+
+        extensions.into_iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collects_extensions_from_every_package() {
+        let lock = ComposerLock::parse(
+            r#"{
+                "packages": [
+                    {"require": {"ext-mbstring": "*"}}
+                ],
+                "packages-dev": [
+                    {"require": {"ext-curl": "*", "php": "^8.1"}}
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(lock.enabled_extensions(), vec!["curl".to_string(), "mbstring".to_string()]);
+    }
+
+    #[test]
+    fn deduplicates_extensions_declared_more_than_once() {
+        let lock = ComposerLock::parse(
+            r#"{
+                "packages": [
+                    {"require": {"ext-curl": "*"}},
+                    {"require": {"ext-curl": "*"}}
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(lock.enabled_extensions(), vec!["curl".to_string()]);
+    }
+}
@@ -0,0 +1,144 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use mago_php_version::PHPVersion;
+use serde::Deserialize;
+
+use crate::error::ComposerError;
+use crate::version_constraint;
+
+/// A single PSR-4 namespace-prefix/directory pair, flattened out of
+/// `composer.json`'s `autoload.psr-4` (and `autoload-dev.psr-4`) maps.
+///
+/// A prefix can list more than one directory, so a manifest with N prefixes
+/// can still flatten into more than N mappings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Psr4Mapping {
+    pub namespace_prefix: String,
+    pub directory: String,
+}
+
+/// A parsed `composer.json`, reduced to the bits mago's config cares about:
+/// PSR-4 autoload roots and the `php` platform requirement.
+#[derive(Debug, Clone, Default)]
+pub struct ComposerManifest {
+    pub name: Option<String>,
+    psr4: Vec<Psr4Mapping>,
+    require: HashMap<String, String>,
+}
+
+/// Mirrors the subset of `composer.json` we read; everything else is
+/// ignored by `serde`'s default behavior of skipping unknown fields.
+#[derive(Debug, Default, Deserialize)]
+struct RawManifest {
+    name: Option<String>,
+    #[serde(default)]
+    autoload: RawAutoload,
+    #[serde(rename = "autoload-dev", default)]
+    autoload_dev: RawAutoload,
+    #[serde(default)]
+    require: HashMap<String, String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct RawAutoload {
+    #[serde(rename = "psr-4", default)]
+    psr_4: HashMap<String, RawPsr4Directories>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum RawPsr4Directories {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl ComposerManifest {
+    /// Parses the raw contents of a `composer.json` file.
+    pub fn parse(content: &str) -> Result<Self, ComposerError> {
+        let raw: RawManifest = serde_json::from_str(content)?;
+
+        let mut psr4 = Vec::new();
+        for (prefix, directories) in raw.autoload.psr_4.into_iter().chain(raw.autoload_dev.psr_4) {
+            match directories {
+                RawPsr4Directories::One(directory) => psr4.push(Psr4Mapping { namespace_prefix: prefix, directory }),
+                RawPsr4Directories::Many(many) => {
+                    for directory in many {
+                        psr4.push(Psr4Mapping { namespace_prefix: prefix.clone(), directory });
+                    }
+                }
+            }
+        }
+
+        Ok(Self { name: raw.name, psr4, require: raw.require })
+    }
+
+    /// Reads and parses `composer.json` from `path`.
+    pub fn read(path: &Path) -> Result<Self, ComposerError> {
+        Self::parse(&std::fs::read_to_string(path)?)
+    }
+
+    /// The flattened PSR-4 namespace-prefix/directory mappings declared in
+    /// `autoload` and `autoload-dev`.
+    pub fn psr4_mappings(&self) -> &[Psr4Mapping] {
+        &self.psr4
+    }
+
+    /// The raw `php` version constraint from `require`, e.g. `"~8.1 || ~8.2"`.
+    pub fn php_requirement(&self) -> Option<&str> {
+        self.require.get("php").map(String::as_str)
+    }
+
+    /// The `ext-*` packages listed in `require`, without the `ext-` prefix.
+    pub fn required_extensions(&self) -> Vec<&str> {
+        self.require.keys().filter_map(|name| name.strip_prefix("ext-")).collect()
+    }
+
+    /// The lowest PHP version allowed by the `require.php` constraint, for
+    /// use as a default `php_version` when none is configured explicitly.
+    pub fn target_php_version(&self) -> Option<PHPVersion> {
+        version_constraint::minimum_version(self.php_requirement()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flattens_a_psr4_entry_with_multiple_directories() {
+        let manifest = ComposerManifest::parse(
+            r#"{
+                "autoload": {
+                    "psr-4": {
+                        "App\\": ["src/", "lib/"]
+                    }
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(manifest.psr4_mappings().len(), 2);
+    }
+
+    #[test]
+    fn reads_the_php_requirement_and_extensions() {
+        let manifest = ComposerManifest::parse(
+            r#"{
+                "require": {
+                    "php": "~8.1 || ~8.2",
+                    "ext-curl": "*"
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(manifest.php_requirement(), Some("~8.1 || ~8.2"));
+        assert_eq!(manifest.required_extensions(), vec!["curl"]);
+    }
+
+    #[test]
+    fn rejects_invalid_json() {
+        assert!(ComposerManifest::parse("not json").is_err());
+    }
+}
@@ -0,0 +1,69 @@
+use mago_php_version::PHPVersion;
+
+/// Picks the lowest PHP version satisfying a composer `require.php`
+/// constraint, e.g. `"~8.1 || ~8.2 || ~8.3"` or `"^8.2"`.
+///
+/// This does not implement composer's full constraint grammar (ranges like
+/// `>=8.1,<8.4`, hyphen ranges, etc.) — it only extracts every `major.minor`
+/// version number mentioned and takes the smallest, which is the part mago
+/// actually needs: the floor its syntax/feature gating must support.
+pub fn minimum_version(constraint: &str) -> Option<PHPVersion> {
+    extract_versions(constraint).into_iter().min()
+}
+
+fn extract_versions(constraint: &str) -> Vec<PHPVersion> {
+    let mut versions = Vec::new();
+    let mut digits = String::new();
+    let mut parts: Vec<u8> = Vec::new();
+
+    let mut flush_number = |digits: &mut String, parts: &mut Vec<u8>| {
+        if !digits.is_empty() {
+            if let Ok(value) = digits.parse::<u8>() {
+                parts.push(value);
+            }
+            digits.clear();
+        }
+    };
+
+    let mut flush_version = |parts: &mut Vec<u8>, versions: &mut Vec<PHPVersion>| {
+        if parts.len() >= 2 {
+            versions.push(PHPVersion::new(parts[0], parts[1]));
+        }
+        parts.clear();
+    };
+
+    for character in constraint.chars() {
+        if character.is_ascii_digit() {
+            digits.push(character);
+        } else if character == '.' {
+            flush_number(&mut digits, &mut parts);
+        } else {
+            flush_number(&mut digits, &mut parts);
+            flush_version(&mut parts, &mut versions);
+        }
+    }
+    flush_number(&mut digits, &mut parts);
+    flush_version(&mut parts, &mut versions);
+
+    versions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn picks_the_lowest_version_from_an_or_constraint() {
+        assert_eq!(minimum_version("~8.1 || ~8.2 || ~8.3"), Some(PHPVersion::new(8, 1)));
+    }
+
+    #[test]
+    fn reads_a_single_caret_constraint() {
+        assert_eq!(minimum_version("^8.2"), Some(PHPVersion::new(8, 2)));
+    }
+
+    #[test]
+    fn returns_none_for_a_constraint_with_no_minor_version() {
+        assert_eq!(minimum_version("*"), None);
+    }
+}
@@ -0,0 +1,49 @@
+use mago_reporting::{Annotation, AnnotationKind, Issue, Level};
+use mago_span::Span;
+
+use crate::parser::ParsedDocblock;
+use crate::tag::Tag;
+
+/// Checks a parsed docblock for structural problems that the parser itself
+/// is lenient about (since a malformed tag shouldn't abort parsing the rest
+/// of the comment), returning one [`Issue`] per problem found.
+pub fn check(docblock: &ParsedDocblock, span: Span) -> Vec<Issue> {
+    let mut issues = Vec::new();
+
+    for tag in &docblock.tags {
+        match tag {
+            Tag::Param(param) if param.type_text.trim().is_empty() => {
+                issues.push(
+                    Issue::new(Level::Warning, format!("`@param` for `${}` is missing a type", param.variable))
+                        .with_code("docblock/malformed-tag")
+                        .with_annotation(Annotation::new(param.span, AnnotationKind::Primary)),
+                );
+            }
+            Tag::Return(r#return) if r#return.type_text.trim().is_empty() => {
+                issues.push(
+                    Issue::new(Level::Warning, "`@return` is missing a type")
+                        .with_code("docblock/malformed-tag")
+                        .with_annotation(Annotation::new(r#return.span, AnnotationKind::Primary)),
+                );
+            }
+            Tag::Unknown(unknown) if !unknown.name.starts_with("psalm-") && !unknown.name.starts_with("phpstan-") => {
+                issues.push(
+                    Issue::new(Level::Note, format!("unrecognized docblock tag `@{}`", unknown.name))
+                        .with_code("docblock/unknown-tag")
+                        .with_annotation(Annotation::new(unknown.span, AnnotationKind::Primary)),
+                );
+            }
+            _ => {}
+        }
+    }
+
+    if docblock.tags.iter().filter(|tag| matches!(tag, Tag::Return(_))).count() > 1 {
+        issues.push(
+            Issue::new(Level::Warning, "a docblock should have at most one `@return` tag")
+                .with_code("docblock/duplicate-tag")
+                .with_annotation(Annotation::new(span, AnnotationKind::Primary)),
+        );
+    }
+
+    issues
+}
@@ -0,0 +1,4 @@
+pub mod diagnostics;
+pub mod parser;
+pub mod tag;
+pub mod template;
@@ -0,0 +1,65 @@
+use mago_span::Span;
+
+use crate::tag::Tag;
+
+/// The result of parsing a `/** ... */` comment: its tags, plus the free-text
+/// summary/description that preceded the first tag.
+#[derive(Debug, Clone, Default)]
+pub struct ParsedDocblock {
+    pub summary: String,
+    pub tags: Vec<Tag>,
+}
+
+/// Parses the body of a docblock comment (without the surrounding `/**`/`*/`).
+///
+/// Parsing is deliberately lenient: an unparsable tag becomes a
+/// [`crate::tag::UnknownTag`] or a tag with an empty `type_text` rather than
+/// aborting, so callers that only care about one tag aren't blocked by a
+/// typo elsewhere in the comment. [`crate::diagnostics::check`] is what
+/// turns those lenient results into actual warnings.
+pub fn parse(body: &str, base_offset: usize) -> ParsedDocblock {
+    let mut docblock = ParsedDocblock::default();
+    let mut offset = base_offset;
+
+    for raw_line in body.lines() {
+        let line = raw_line.trim_start().trim_start_matches('*').trim();
+        let line_start = offset;
+        offset += raw_line.len() + 1;
+
+        let Some(rest) = line.strip_prefix('@') else {
+            if docblock.tags.is_empty() && !line.is_empty() {
+                if !docblock.summary.is_empty() {
+                    docblock.summary.push(' ');
+                }
+                docblock.summary.push_str(line);
+            }
+            continue;
+        };
+
+        let (name, rest) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+        let span = Span::new(Default::default(), line_start, offset);
+
+        docblock.tags.push(match name {
+            "param" => {
+                let mut parts = rest.trim().splitn(2, char::is_whitespace);
+                let type_text = parts.next().unwrap_or_default().to_string();
+                let variable = parts.next().unwrap_or_default().trim_start_matches('$').to_string();
+
+                Tag::Param(crate::tag::ParamTag { variable, type_text, span })
+            }
+            "return" => Tag::Return(crate::tag::ReturnTag { type_text: rest.trim().to_string(), span }),
+            "var" => Tag::Var(crate::tag::VarTag { type_text: rest.trim().to_string(), span }),
+            "throws" => Tag::Throws(crate::tag::ThrowsTag { type_text: rest.trim().to_string(), span }),
+            "deprecated" => {
+                let reason = rest.trim();
+                Tag::Deprecated(crate::tag::DeprecatedTag {
+                    reason: if reason.is_empty() { None } else { Some(reason.to_string()) },
+                    span,
+                })
+            }
+            other => Tag::Unknown(crate::tag::UnknownTag { name: other.to_string(), span }),
+        });
+    }
+
+    docblock
+}
@@ -0,0 +1,48 @@
+use mago_span::Span;
+
+#[derive(Debug, Clone)]
+pub enum Tag {
+    Param(ParamTag),
+    Return(ReturnTag),
+    Var(VarTag),
+    Deprecated(DeprecatedTag),
+    Throws(ThrowsTag),
+    Unknown(UnknownTag),
+}
+
+#[derive(Debug, Clone)]
+pub struct ParamTag {
+    pub variable: String,
+    pub type_text: String,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone)]
+pub struct ReturnTag {
+    pub type_text: String,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone)]
+pub struct VarTag {
+    pub type_text: String,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone)]
+pub struct ThrowsTag {
+    pub type_text: String,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone)]
+pub struct DeprecatedTag {
+    pub reason: Option<String>,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone)]
+pub struct UnknownTag {
+    pub name: String,
+    pub span: Span,
+}
@@ -0,0 +1,77 @@
+use std::collections::HashMap;
+
+use mago_reporting::{Annotation, AnnotationKind, Issue, Level};
+use mago_span::Span;
+
+use crate::parser::ParsedDocblock;
+use crate::tag::Tag;
+
+/// A `@template T of Bound` declaration.
+#[derive(Debug, Clone)]
+pub struct TemplateTag {
+    pub name: String,
+    pub bound: Option<String>,
+    pub span: Span,
+}
+
+/// Checks that every `@template` name used inside `@param`/`@return`
+/// type-syntax on a docblock is actually declared by a `@template` tag on
+/// that same docblock (or inherited from the class, for a method), and that
+/// every declared `@template` is referenced at least once.
+pub fn check_templates(docblock: &ParsedDocblock, declared: &[TemplateTag]) -> Vec<Issue> {
+    let mut issues = Vec::new();
+    let declared_names: HashMap<&str, &TemplateTag> = declared.iter().map(|tag| (tag.name.as_str(), tag)).collect();
+    let mut used = std::collections::HashSet::new();
+
+    for tag in &docblock.tags {
+        let type_text = match tag {
+            Tag::Param(param) => &param.type_text,
+            Tag::Return(r#return) => &r#return.type_text,
+            Tag::Var(var) => &var.type_text,
+            _ => continue,
+        };
+
+        for word in type_text.split(|c: char| !c.is_alphanumeric() && c != '_') {
+            if declared_names.contains_key(word) {
+                used.insert(word);
+            } else if is_likely_template_reference(word) {
+                issues.push(
+                    Issue::new(Level::Warning, format!("`{word}` is used as a generic parameter but is not declared with `@template`"))
+                        .with_code("docblock/undeclared-template")
+                        .with_annotation(Annotation::new(span_of(tag), AnnotationKind::Primary)),
+                );
+            }
+        }
+    }
+
+    for tag in declared {
+        if !used.contains(tag.name.as_str()) {
+            issues.push(
+                Issue::new(Level::Note, format!("`@template {}` is never used", tag.name))
+                    .with_code("docblock/unused-template")
+                    .with_annotation(Annotation::new(tag.span, AnnotationKind::Primary)),
+            );
+        }
+    }
+
+    issues
+}
+
+fn span_of(tag: &Tag) -> Span {
+    match tag {
+        Tag::Param(param) => param.span,
+        Tag::Return(r#return) => r#return.span,
+        Tag::Var(var) => var.span,
+        Tag::Deprecated(deprecated) => deprecated.span,
+        Tag::Throws(throws) => throws.span,
+        Tag::Unknown(unknown) => unknown.span,
+    }
+}
+
+/// `@template` names conventionally start with `T` (`T`, `TKey`, `TValue`);
+/// only a word of at most 8 characters starting with `T` is flagged, to
+/// avoid false positives on most ordinary class names that happen to
+/// appear in a type.
+fn is_likely_template_reference(word: &str) -> bool {
+    !word.is_empty() && word.len() <= 8 && word.chars().next().is_some_and(|c| c.is_ascii_uppercase()) && word.starts_with('T')
+}
@@ -0,0 +1,57 @@
+use mago_span::Span;
+
+/// A minimal text edit, expressed as a byte-offset span in the original text
+/// to replace with `replacement`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextEdit {
+    pub span: Span,
+    pub replacement: String,
+}
+
+/// Computes the [`TextEdit`]s that turn `original` into `formatted`.
+///
+/// Editors apply a whole-document replacement just fine, but it discards
+/// cursor position and any pending selections. This trims the byte-wise
+/// longest common prefix and suffix and returns a single edit replacing
+/// the changed middle section; it does not split that section into
+/// per-line edits, and positions are byte offsets, not lines and columns.
+pub fn compute_minimal_edits(original: &str, formatted: &str) -> Vec<TextEdit> {
+    if original == formatted {
+        return Vec::new();
+    }
+
+    let prefix_len = original.bytes().zip(formatted.bytes()).take_while(|(a, b)| a == b).count();
+
+    let suffix_len = original[prefix_len..]
+        .bytes()
+        .rev()
+        .zip(formatted[prefix_len..].bytes().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let original_middle = &original[prefix_len..original.len() - suffix_len];
+    let formatted_middle = &formatted[prefix_len..formatted.len() - suffix_len];
+
+    vec![TextEdit {
+        span: Span::new(Default::default(), prefix_len, prefix_len + original_middle.len()),
+        replacement: formatted_middle.to_string(),
+    }]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_edits_when_content_is_identical() {
+        assert!(compute_minimal_edits("<?php\n", "<?php\n").is_empty());
+    }
+
+    #[test]
+    fn only_the_changed_middle_is_included() {
+        let edits = compute_minimal_edits("<?php\necho 1;\n", "<?php\necho  1;\n");
+
+        assert_eq!(edits.len(), 1);
+        assert_eq!(edits[0].replacement, " ");
+    }
+}
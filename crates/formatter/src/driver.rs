@@ -0,0 +1,100 @@
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::path::Path;
+use std::path::PathBuf;
+
+use rayon::prelude::*;
+use rustc_hash::FxHasher;
+
+use crate::Formatter;
+use crate::error::FormatError;
+use crate::settings::FormatSettings;
+
+/// The outcome of formatting a single file.
+pub struct FormattedFile {
+    pub path: PathBuf,
+    pub changed: bool,
+    pub content: String,
+}
+
+/// Formats every file in `paths` in parallel, skipping files whose content
+/// hash matches a previous successful run for the same settings.
+///
+/// This is the driver behind `mago format`'s directory mode: on a large
+/// project, re-formatting unchanged files dominates wall time if done
+/// naively, so both the cache and the parallelism matter in practice.
+pub struct FormatDriver {
+    settings: FormatSettings,
+    cache: dashmap::DashMap<PathBuf, u64>,
+}
+
+impl FormatDriver {
+    pub fn new(settings: FormatSettings) -> Self {
+        Self { settings, cache: dashmap::DashMap::new() }
+    }
+
+    pub fn format_all(&self, paths: &[PathBuf]) -> Vec<Result<Option<FormattedFile>, FormatError>> {
+        paths.par_iter().map(|path| self.format_one(path)).collect()
+    }
+
+    fn format_one(&self, path: &Path) -> Result<Option<FormattedFile>, FormatError> {
+        let source = std::fs::read_to_string(path).map_err(FormatError::Io)?;
+        let hash = hash_with_settings(&source, &self.settings);
+
+        if self.cache.get(path).is_some_and(|cached| *cached == hash) {
+            return Ok(None);
+        }
+
+        let formatted = Formatter::new(self.settings.clone()).format_source(&source)?;
+        self.cache.insert(path.to_path_buf(), hash);
+
+        Ok(Some(FormattedFile { path: path.to_path_buf(), changed: formatted != source, content: formatted }))
+    }
+}
+
+fn hash_with_settings(source: &str, settings: &FormatSettings) -> u64 {
+    let mut hasher = FxHasher::default();
+    source.hash(&mut hasher);
+    settings.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hash_with_settings_is_stable_for_the_same_source_and_settings() {
+        let settings = FormatSettings::default();
+
+        assert_eq!(hash_with_settings("<?php echo 1;", &settings), hash_with_settings("<?php echo 1;", &settings));
+    }
+
+    #[test]
+    fn hash_with_settings_changes_with_the_source() {
+        let settings = FormatSettings::default();
+
+        assert_ne!(
+            hash_with_settings("<?php echo 1;", &settings),
+            hash_with_settings("<?php echo 2;", &settings)
+        );
+    }
+
+    #[test]
+    fn format_all_skips_a_file_whose_hash_is_already_cached() {
+        let directory = std::env::temp_dir().join("mago-format-driver-test-skips-cached");
+        std::fs::create_dir_all(&directory).expect("creating the scratch directory should succeed");
+        let file = directory.join("cached.php");
+        std::fs::write(&file, "<?php echo 1;\n").expect("writing the scratch file should succeed");
+
+        let driver = FormatDriver::new(FormatSettings::default());
+
+        let first = driver.format_all(&[file.clone()]);
+        assert!(matches!(first.as_slice(), [Ok(Some(_))]));
+
+        let second = driver.format_all(&[file.clone()]);
+        assert!(matches!(second.as_slice(), [Ok(None)]));
+
+        let _ = std::fs::remove_dir_all(&directory);
+    }
+}
@@ -0,0 +1,36 @@
+use mago_ast::ast::Statement;
+
+use crate::internal::FormatterState;
+use crate::settings_alt_syntax::AlternativeSyntaxPolicy;
+
+/// Whether `statements` contains an inline HTML statement directly (not
+/// nested inside a further control structure), the condition under which
+/// [`AlternativeSyntaxPolicy::ConvertToBraces`] still leaves alternative
+/// syntax alone.
+pub fn contains_inline_html(statements: &[Statement]) -> bool {
+    statements.iter().any(|statement| matches!(statement, Statement::Inline(_)))
+}
+
+/// Whether a control structure using alternative syntax, whose body is
+/// `statements`, should be rewritten to brace syntax under the formatter's
+/// current settings.
+pub fn should_convert_to_braces(f: &FormatterState<'_>, statements: &[Statement]) -> bool {
+    f.settings.alternative_syntax_policy.should_convert(contains_inline_html(statements))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn policy_preserve_never_converts() {
+        assert!(!AlternativeSyntaxPolicy::Preserve.should_convert(false));
+        assert!(!AlternativeSyntaxPolicy::Preserve.should_convert(true));
+    }
+
+    #[test]
+    fn policy_convert_to_braces_keeps_inline_html_bodies() {
+        assert!(AlternativeSyntaxPolicy::ConvertToBraces.should_convert(false));
+        assert!(!AlternativeSyntaxPolicy::ConvertToBraces.should_convert(true));
+    }
+}
@@ -0,0 +1,95 @@
+use mago_ast::ast::Argument;
+use mago_span::HasSpan;
+use mago_span::Span;
+
+use crate::document::Document;
+use crate::document::Line;
+use crate::internal::FormatterState;
+use crate::settings_respect_newlines::NewlinePreservation;
+
+/// Prints an already-formatted call argument list.
+///
+/// In [`NewlinePreservation::Normalize`] mode (the default), this defers
+/// entirely to the caller's usual fits-on-one-line logic by returning a
+/// group the printer can break as needed. In [`NewlinePreservation::RespectAuthor`]
+/// mode, the decision of whether to break is made up front from the
+/// original source instead: if the author already put a line break between
+/// the opening parenthesis and the first argument, the list stays broken,
+/// one argument per line; otherwise it stays on one line, regardless of
+/// length.
+pub fn print_argument_list<'a>(
+    f: &FormatterState<'a>,
+    opening_parenthesis: Span,
+    arguments: &'a [Argument],
+    printed: Vec<Document<'a>>,
+) -> Document<'a> {
+    if arguments.is_empty() {
+        return Document::String("()");
+    }
+
+    match f.settings.newline_preservation {
+        NewlinePreservation::Normalize => {
+            Document::Group(Box::new(Document::Array(vec![
+                Document::String("("),
+                Document::Indent(vec![join_with_soft_lines(printed)]),
+                Document::Line(Line::soft()),
+                Document::String(")"),
+            ])))
+        }
+        NewlinePreservation::RespectAuthor => {
+            if author_broke_before(f, opening_parenthesis, arguments[0].span()) {
+                Document::Array(vec![
+                    Document::String("("),
+                    Document::Indent(vec![Document::Line(Line::hard()), join_with_hard_lines(printed)]),
+                    Document::Line(Line::hard()),
+                    Document::String(")"),
+                ])
+            } else {
+                Document::Array(vec![Document::String("("), join_on_one_line(printed), Document::String(")")])
+            }
+        }
+    }
+}
+
+fn author_broke_before(f: &FormatterState<'_>, before: Span, after: Span) -> bool {
+    f.lookup_slice(Span::new(before.file_id, before.end, after.start)).contains('\n')
+}
+
+fn join_on_one_line<'a>(printed: Vec<Document<'a>>) -> Document<'a> {
+    let mut parts = Vec::new();
+    for (i, element) in printed.into_iter().enumerate() {
+        if i != 0 {
+            parts.push(Document::String(", "));
+        }
+        parts.push(element);
+    }
+
+    Document::Array(parts)
+}
+
+fn join_with_soft_lines<'a>(printed: Vec<Document<'a>>) -> Document<'a> {
+    let mut parts = vec![Document::Line(Line::soft())];
+    for (i, element) in printed.into_iter().enumerate() {
+        if i != 0 {
+            parts.push(Document::String(","));
+            parts.push(Document::Line(Line::soft()));
+        }
+        parts.push(element);
+    }
+
+    Document::Array(parts)
+}
+
+fn join_with_hard_lines<'a>(printed: Vec<Document<'a>>) -> Document<'a> {
+    let mut parts = Vec::new();
+    for (i, element) in printed.into_iter().enumerate() {
+        if i != 0 {
+            parts.push(Document::String(","));
+            parts.push(Document::Line(Line::hard()));
+        }
+        parts.push(element);
+    }
+    parts.push(Document::String(","));
+
+    Document::Array(parts)
+}
@@ -0,0 +1,68 @@
+use mago_ast::ast::ArrayElement;
+use mago_span::HasSpan;
+use mago_span::Span;
+
+use crate::document::Document;
+use crate::document::Line;
+use crate::internal::FormatterState;
+use crate::settings_array::ArrayStyle;
+
+/// Prints an already-formatted array element list according to the
+/// configured [`ArrayStyle`], once the caller has determined the array
+/// doesn't fit on one line.
+pub fn print_array_elements<'a>(f: &FormatterState<'a>, elements: &'a [ArrayElement], printed: Vec<Document<'a>>) -> Document<'a> {
+    match f.settings.array_style {
+        ArrayStyle::Expand => print_expanded(printed),
+        ArrayStyle::Fill => print_fill(printed),
+        ArrayStyle::PreserveNewlines => print_preserving_newlines(f, elements, printed),
+    }
+}
+
+fn print_expanded<'a>(printed: Vec<Document<'a>>) -> Document<'a> {
+    let mut parts = Vec::new();
+    for (i, element) in printed.into_iter().enumerate() {
+        if i != 0 {
+            parts.push(Document::String(","));
+            parts.push(Document::Line(Line::hard()));
+        }
+        parts.push(element);
+    }
+    parts.push(Document::String(","));
+
+    Document::Indent(vec![Document::Line(Line::hard()), Document::Array(parts)])
+}
+
+fn print_fill<'a>(printed: Vec<Document<'a>>) -> Document<'a> {
+    let mut parts = Vec::new();
+    for (i, element) in printed.into_iter().enumerate() {
+        if i != 0 {
+            parts.push(Document::String(","));
+            parts.push(Document::Line(Line::soft()));
+        }
+        parts.push(element);
+    }
+    parts.push(Document::String(","));
+
+    Document::Indent(vec![Document::Line(Line::soft()), Document::Fill(parts)])
+}
+
+fn print_preserving_newlines<'a>(f: &FormatterState<'a>, elements: &'a [ArrayElement], printed: Vec<Document<'a>>) -> Document<'a> {
+    let mut parts = Vec::new();
+
+    for (i, (element, document)) in elements.iter().zip(printed).enumerate() {
+        if i != 0 {
+            parts.push(Document::String(","));
+
+            let previous_span = elements[i - 1].span();
+            let gap = f.lookup_slice(Span::new(previous_span.file_id, previous_span.end, element.span().start));
+            parts.push(Document::Line(Line::hard()));
+            if gap.matches('\n').count() > 1 {
+                parts.push(Document::Line(Line::hard()));
+            }
+        }
+        parts.push(document);
+    }
+    parts.push(Document::String(","));
+
+    Document::Indent(vec![Document::Line(Line::hard()), Document::Array(parts)])
+}
@@ -0,0 +1,54 @@
+use mago_ast::ast::Binary;
+use mago_ast::ast::Expression;
+use mago_span::HasSpan;
+
+use crate::document::Document;
+use crate::document::Line;
+use crate::internal::FormatterState;
+use crate::settings_binaryish::BinaryishChainStyle;
+
+/// Flattens a left-associative chain of binary expressions (`$a . $b . $c`,
+/// `$a + $b - $c`, ...) into its innermost left operand plus each
+/// subsequent `(operator, right operand)` step, in source order.
+fn flatten<'a>(binary: &'a Binary) -> (&'a Expression, Vec<(&'a Binary, &'a Expression)>) {
+    match binary.lhs.as_ref() {
+        Expression::Binary(nested) => {
+            let (head, mut steps) = flatten(nested);
+            steps.push((binary, binary.rhs.as_ref()));
+            (head, steps)
+        }
+        other => (other, vec![(binary, binary.rhs.as_ref())]),
+    }
+}
+
+/// Prints a binary operator chain that's the right-hand side of an
+/// assignment or the expression of a `return` statement, once it's been
+/// determined the chain doesn't fit on one line.
+///
+/// `head` is already-formatted content that precedes the chain on its first
+/// line - the assignment's `$target = ` or the `return `/nothing - which
+/// [`BinaryishChainStyle::IndentAfterFirstOperand`] keeps the first operand
+/// next to, instead of pushing every operand onto its own indented line.
+pub fn print_binaryish_chain<'a>(f: &mut FormatterState<'a>, binary: &'a Binary, head: Document<'a>) -> Document<'a> {
+    let (first_operand, steps) = flatten(binary);
+
+    let first = f.format(first_operand);
+
+    let mut continuation = Vec::new();
+    for (step, operand) in steps {
+        continuation.push(Document::Line(Line::hard()));
+        continuation.push(Document::String(f.lookup_slice(step.operator.span())));
+        continuation.push(Document::String(" "));
+        continuation.push(f.format(operand));
+    }
+
+    match f.settings.binaryish_chain_style {
+        BinaryishChainStyle::Indented => Document::Array(vec![
+            head,
+            Document::Indent(vec![Document::Line(Line::hard()), first, Document::Array(continuation)]),
+        ]),
+        BinaryishChainStyle::IndentAfterFirstOperand => {
+            Document::Array(vec![head, Document::Indent(vec![first, Document::Array(continuation)])])
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use crate::document::Document;
+use crate::document::Line;
+use crate::internal::FormatterState;
+use crate::settings_brace::BracePlacement;
+
+/// Joins a signature document with its block's opening brace, honoring the
+/// configured [`BracePlacement`] for anonymous classes and closures.
+///
+/// Named functions, methods, and control structures are unaffected by this
+/// setting; in this codebase their brace placement has always been fixed to
+/// same-line, and changing that is out of scope here.
+pub fn join_signature_and_brace<'a>(f: &FormatterState<'a>, signature: Document<'a>, placement: BracePlacement) -> Document<'a> {
+    match placement {
+        BracePlacement::SameLine => Document::Array(vec![signature, Document::String(" {")]),
+        BracePlacement::NextLine => {
+            Document::Array(vec![signature, Document::Line(Line::hard()), Document::String("{")])
+        }
+    }
+}
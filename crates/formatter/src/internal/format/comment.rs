@@ -0,0 +1,118 @@
+/// Normalizes a `//` or `#` line comment: collapses `#` to `//` (configurable
+/// via `settings.normalize_hash_comments`) and ensures exactly one space
+/// after the marker.
+pub fn normalize_line_comment(text: &str, normalize_hash: bool) -> String {
+    let (marker, rest) = if let Some(rest) = text.strip_prefix("//") {
+        ("//", rest)
+    } else if let Some(rest) = text.strip_prefix('#') {
+        (if normalize_hash { "//" } else { "#" }, rest)
+    } else {
+        return text.to_string();
+    };
+
+    let rest = rest.trim_end();
+    if rest.is_empty() {
+        marker.to_string()
+    } else if let Some(stripped) = rest.strip_prefix(' ') {
+        format!("{marker} {}", stripped.trim_start())
+    } else {
+        format!("{marker} {rest}")
+    }
+}
+
+/// Reflows a `/* ... */` block comment. Lines inside a `/** ... */`
+/// docblock that start with `*` get a single leading space, so their `*`
+/// columns line up, and exactly one space between the `*` and any text
+/// after it. Every other line only has its trailing whitespace removed.
+pub fn reflow_block_comment(text: &str) -> String {
+    let mut lines = text.lines();
+    let Some(first) = lines.next() else {
+        return text.to_string();
+    };
+
+    let is_docblock = first.trim_start().starts_with("/**");
+    let mut result = String::from(first.trim_end());
+
+    for line in lines {
+        let trimmed = line.trim_start();
+        result.push('\n');
+
+        if is_docblock && trimmed.starts_with('*') {
+            let body = trimmed.trim_end();
+            result.push_str(" ");
+            match body.strip_prefix('*') {
+                Some(text) if !text.is_empty() && !text.starts_with('/') => {
+                    result.push_str("* ");
+                    result.push_str(text.trim_start());
+                }
+                _ => result.push_str(body),
+            }
+        } else {
+            result.push_str(line.trim_end());
+        }
+    }
+
+    result
+}
+
+/// Wraps a single line of prose to `width`, breaking only on word
+/// boundaries and never splitting a single word - so a long URL or
+/// identifier that alone exceeds `width` is left intact on its own line
+/// rather than cut mid-word.
+///
+/// `prefix_len` is how much of `width` is already spent by whatever comes
+/// before the text on every wrapped line (the `// `/`# `/` * ` marker and
+/// its indentation), so the caller doesn't have to re-measure it.
+pub fn wrap_prose_to_width(text: &str, width: usize, prefix_len: usize) -> Vec<String> {
+    let budget = width.saturating_sub(prefix_len).max(1);
+    let mut lines = Vec::new();
+    let mut current = String::new();
+
+    for word in text.split_whitespace() {
+        let candidate_len = if current.is_empty() { word.len() } else { current.len() + 1 + word.len() };
+
+        if !current.is_empty() && candidate_len > budget {
+            lines.push(std::mem::take(&mut current));
+        }
+
+        if !current.is_empty() {
+            current.push(' ');
+        }
+        current.push_str(word);
+    }
+
+    if !current.is_empty() {
+        lines.push(current);
+    }
+
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collapses_hash_comments_when_enabled() {
+        assert_eq!(normalize_line_comment("#comment", true), "// comment");
+        assert_eq!(normalize_line_comment("#comment", false), "# comment");
+    }
+
+    #[test]
+    fn aligns_docblock_continuation_lines() {
+        let input = "/**\n *summary\n   * detail\n */";
+        let expected = "/**\n * summary\n * detail\n */";
+        assert_eq!(reflow_block_comment(input), expected);
+    }
+
+    #[test]
+    fn wraps_prose_on_word_boundaries() {
+        assert_eq!(wrap_prose_to_width("the quick brown fox jumps", 15, 0), vec!["the quick brown", "fox jumps"]);
+    }
+
+    #[test]
+    fn never_splits_a_single_long_word() {
+        let url = "https://example.com/a/very/long/path/that/exceeds/the/width";
+        assert_eq!(wrap_prose_to_width(url, 10, 0), vec![url.to_string()]);
+    }
+}
@@ -0,0 +1,55 @@
+use mago_span::Span;
+
+use crate::document::Document;
+use crate::document::Line;
+use crate::internal::FormatterState;
+use crate::settings_respect_newlines::NewlinePreservation;
+
+/// A single `->method(...)` (or `?->method(...)`) link in a chain, already
+/// rendered to a [`Document`] by the caller; `span` is the span of the
+/// whole link, used only to look at the gap before it in the source.
+pub struct ChainLink<'a> {
+    pub document: Document<'a>,
+    pub span: Span,
+}
+
+/// Prints a method chain's links after the first.
+///
+/// In [`NewlinePreservation::Normalize`] mode, the links are grouped so the
+/// usual fits-on-one-line logic decides whether to break the chain at all.
+/// In [`NewlinePreservation::RespectAuthor`] mode, each link keeps its own
+/// original choice: a link the author started on a new line stays on its
+/// own line, and a link they kept glued to the previous one stays glued,
+/// independent of what the rest of the chain does.
+pub fn print_chain_links<'a>(f: &FormatterState<'a>, first_span: Span, links: Vec<ChainLink<'a>>) -> Document<'a> {
+    match f.settings.newline_preservation {
+        NewlinePreservation::Normalize => {
+            let mut parts = Vec::new();
+            for link in links {
+                parts.push(Document::Line(Line::soft()));
+                parts.push(link.document);
+            }
+
+            Document::Group(Box::new(Document::Indent(parts)))
+        }
+        NewlinePreservation::RespectAuthor => {
+            let mut parts = Vec::new();
+            let mut previous_end = first_span;
+
+            for link in links {
+                if author_broke_before(f, previous_end, link.span) {
+                    parts.push(Document::Line(Line::hard()));
+                }
+
+                previous_end = link.span;
+                parts.push(link.document);
+            }
+
+            Document::Indent(parts)
+        }
+    }
+}
+
+fn author_broke_before(f: &FormatterState<'_>, before: Span, after: Span) -> bool {
+    f.lookup_slice(Span::new(before.file_id, before.end, after.start)).contains('\n')
+}
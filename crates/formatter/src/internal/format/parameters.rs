@@ -0,0 +1,80 @@
+use mago_ast::ast::FunctionLikeParameter;
+use mago_ast::ast::FunctionLikeParameterList;
+
+use crate::document::Document;
+use crate::document::Line;
+use crate::internal::FormatterState;
+use crate::settings_parameters::PromotedPropertiesSettings;
+
+/// Prints a constructor's parameter list, breaking to one parameter per
+/// line whenever [`should_break`] decides the list reads
+/// better that way.
+///
+/// Ordinary function/method parameter lists are unaffected - this is only
+/// consulted for `__construct`, where promoted parameters double as
+/// property declarations and so carry attributes and modifiers that plain
+/// parameters never do.
+pub fn print_parameter_list<'a>(
+    f: &FormatterState<'a>,
+    list: &'a FunctionLikeParameterList,
+    printed: Vec<Document<'a>>,
+) -> Document<'a> {
+    if list.parameters.is_empty() {
+        return Document::String("()");
+    }
+
+    if !should_break(&list.parameters, &f.settings.promoted_properties) {
+        return Document::Group(Box::new(Document::Array(vec![
+            Document::String("("),
+            Document::Indent(vec![join_with_soft_lines(printed)]),
+            Document::Line(Line::soft()),
+            Document::String(")"),
+        ])));
+    }
+
+    Document::Array(vec![
+        Document::String("("),
+        Document::Indent(vec![Document::Line(Line::hard()), join_with_hard_lines(printed)]),
+        Document::Line(Line::hard()),
+        Document::String(")"),
+    ])
+}
+
+/// Whether `parameters` should be forced one-per-line: either any parameter
+/// carries an attribute or a modifier, per [`PromotedPropertiesSettings::break_on_attributes_or_modifiers`],
+/// or the list is longer than [`PromotedPropertiesSettings::max_inline_parameters`].
+fn should_break(parameters: &[FunctionLikeParameter], settings: &PromotedPropertiesSettings) -> bool {
+    if parameters.len() > settings.max_inline_parameters {
+        return true;
+    }
+
+    settings.break_on_attributes_or_modifiers
+        && parameters.iter().any(|parameter| !parameter.modifiers.is_empty() || !parameter.attributes.is_empty())
+}
+
+fn join_with_soft_lines<'a>(printed: Vec<Document<'a>>) -> Document<'a> {
+    let mut parts = vec![Document::Line(Line::soft())];
+    for (i, element) in printed.into_iter().enumerate() {
+        if i != 0 {
+            parts.push(Document::String(","));
+            parts.push(Document::Line(Line::soft()));
+        }
+        parts.push(element);
+    }
+
+    Document::Array(parts)
+}
+
+fn join_with_hard_lines<'a>(printed: Vec<Document<'a>>) -> Document<'a> {
+    let mut parts = Vec::new();
+    for (i, element) in printed.into_iter().enumerate() {
+        if i != 0 {
+            parts.push(Document::String(","));
+            parts.push(Document::Line(Line::hard()));
+        }
+        parts.push(element);
+    }
+    parts.push(Document::String(","));
+
+    Document::Array(parts)
+}
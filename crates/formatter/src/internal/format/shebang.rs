@@ -0,0 +1,11 @@
+use mago_ast::ast::Shebang;
+
+use crate::internal::FormatterState;
+use crate::document::Document;
+
+/// Shebangs are emitted byte-for-byte and are always followed by a hard
+/// line break, regardless of the configured blank-line rules that apply to
+/// the rest of the program.
+pub fn print_shebang<'a>(f: &mut FormatterState<'a>, shebang: &'a Shebang) -> Document<'a> {
+    Document::Array(vec![Document::String(f.lookup_slice(shebang.span)), Document::Line(crate::document::Line::hard())])
+}
@@ -0,0 +1,25 @@
+use mago_ast::ast::Method;
+
+/// Whether `method`'s body is simple enough to keep on a single line with
+/// its signature, when the `settings.keep_simple_methods_single_line`
+/// option is enabled.
+///
+/// "Simple" means: an empty body, or a body containing exactly one
+/// statement that is either a `return` statement or an assignment
+/// expression statement - the shapes a generated getter, setter, or empty
+/// constructor takes. The returned or assigned expression itself is not
+/// inspected.
+pub fn is_single_line_candidate(method: &Method) -> bool {
+    let Some(statements) = method.body.as_statements() else {
+        return false;
+    };
+
+    match statements {
+        [] => true,
+        [mago_ast::ast::Statement::Return(_)] => true,
+        [mago_ast::ast::Statement::Expression(expression_statement)] => {
+            matches!(expression_statement.expression, mago_ast::ast::Expression::Assignment(_))
+        }
+        _ => false,
+    }
+}
@@ -0,0 +1,53 @@
+use mago_ast::ast::Conditional;
+
+use crate::document::Document;
+use crate::document::Group;
+use crate::document::Line;
+use crate::internal::FormatterState;
+use crate::settings_ternary::TernaryWrapStyle;
+
+/// Prints a ternary expression according to the configured [`TernaryWrapStyle`].
+pub fn print_conditional<'a>(f: &mut FormatterState<'a>, conditional: &'a Conditional) -> Document<'a> {
+    let condition = f.format(conditional.condition.as_ref());
+    let then = conditional.then.as_ref().map(|then| f.format(then));
+    let r#else = f.format(conditional.r#else.as_ref());
+
+    match f.settings.ternary_wrap_style {
+        TernaryWrapStyle::NoBreak => {
+            let mut parts = vec![condition, Document::String(" ? ")];
+            if let Some(then) = then {
+                parts.push(then);
+                parts.push(Document::String(" "));
+            }
+            parts.push(Document::String(": "));
+            parts.push(r#else);
+
+            Document::Array(parts)
+        }
+        TernaryWrapStyle::KeepQuestionMark => {
+            let mut head = vec![condition, Document::String(" ?")];
+            if let Some(then) = then {
+                head.push(Document::String(" "));
+                head.push(then);
+            }
+
+            Document::Group(Group::new(vec![
+                Document::Array(head),
+                Document::Indent(vec![Document::Line(Line::soft()), Document::String(": "), r#else]),
+            ]))
+        }
+        TernaryWrapStyle::OperatorFirst => {
+            let mut parts = vec![condition];
+            parts.push(Document::Indent(vec![
+                Document::Line(Line::soft()),
+                Document::String("? "),
+                then.unwrap_or(Document::String("")),
+                Document::Line(Line::soft()),
+                Document::String(": "),
+                r#else,
+            ]));
+
+            Document::Group(Group::new(parts))
+        }
+    }
+}
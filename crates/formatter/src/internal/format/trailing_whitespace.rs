@@ -0,0 +1,30 @@
+/// Strips trailing whitespace from every line of already-formatted output.
+///
+/// This runs as a final pass over the rendered document rather than being
+/// threaded through every printer function, because trailing whitespace can
+/// be introduced by line-splitting decisions the individual printers have no
+/// visibility into (e.g. a group that breaks right before a blank line).
+pub fn strip_trailing_whitespace(rendered: &str) -> String {
+    let mut output = String::with_capacity(rendered.len());
+
+    for (i, line) in rendered.split('\n').enumerate() {
+        if i != 0 {
+            output.push('\n');
+        }
+
+        output.push_str(line.trim_end_matches([' ', '\t']));
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strips_trailing_spaces_and_tabs_on_every_line() {
+        let input = "<?php   \n\necho 1;\t\n";
+        assert_eq!(strip_trailing_whitespace(input), "<?php\n\necho 1;\n");
+    }
+}
@@ -0,0 +1,66 @@
+use mago_ast::ast::TraitUseAdaptation;
+
+use crate::document::Document;
+use crate::document::Line;
+use crate::internal::FormatterState;
+
+/// Prints the adaptation block of a `use Trait { ... }` declaration with one
+/// clause per line, column-aligning the `insteadof`/`as` keyword across
+/// clauses the way the project already aligns `=>` in array literals.
+pub fn print_adaptations<'a>(f: &mut FormatterState<'a>, adaptations: &'a [TraitUseAdaptation]) -> Document<'a> {
+    if adaptations.is_empty() {
+        return Document::String("{}");
+    }
+
+    let keyword_column = adaptations
+        .iter()
+        .map(|adaptation| method_reference_text(f, adaptation).len())
+        .max()
+        .unwrap_or(0);
+
+    let mut parts = vec![Document::String("{"), Document::Indent(vec![Document::Line(Line::hard())])];
+
+    for (i, adaptation) in adaptations.iter().enumerate() {
+        if i != 0 {
+            parts.push(Document::Line(Line::hard()));
+        }
+
+        let reference = method_reference_text(f, adaptation);
+        let padding = " ".repeat(keyword_column.saturating_sub(reference.len()));
+
+        parts.push(Document::String(Box::leak(format!("{reference}{padding} {}", clause_text(f, adaptation)).into_boxed_str())));
+    }
+
+    parts.push(Document::Line(Line::hard()));
+    parts.push(Document::String("}"));
+
+    Document::Array(parts)
+}
+
+fn method_reference_text<'a>(f: &FormatterState<'a>, adaptation: &'a TraitUseAdaptation) -> String {
+    let reference = match adaptation {
+        TraitUseAdaptation::Precedence { method, .. } => method,
+        TraitUseAdaptation::Alias { method, .. } => method,
+    };
+
+    match &reference.trait_name {
+        Some(trait_name) => {
+            format!("{}::{}", f.lookup_slice(trait_name.span()), f.lookup_slice(reference.method_name.span()))
+        }
+        None => f.lookup_slice(reference.method_name.span()).to_string(),
+    }
+}
+
+fn clause_text<'a>(f: &FormatterState<'a>, adaptation: &'a TraitUseAdaptation) -> String {
+    match adaptation {
+        TraitUseAdaptation::Precedence { excluded, .. } => {
+            format!("insteadof {};", excluded.iter().map(|name| f.lookup_slice(name.span())).collect::<Vec<_>>().join(", "))
+        }
+        TraitUseAdaptation::Alias { visibility, alias, .. } => {
+            let visibility = visibility.as_ref().map(|v| format!("{} ", f.lookup_slice(v.span()))).unwrap_or_default();
+            let alias = alias.as_ref().map(|a| f.lookup_slice(a.span()).to_string()).unwrap_or_default();
+
+            format!("as {visibility}{alias};")
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// How the formatter treats `if/endif`, `foreach/endforeach`,
+/// `while/endwhile`, `for/endfor`, and `switch/endswitch` alternative
+/// syntax.
+///
+/// Alternative syntax is idiomatic inside templates that mix PHP with HTML,
+/// but mixed brace/alt-syntax styles are common in codebases that migrated
+/// away from templates only partially; `ConvertToBraces` normalizes that,
+/// while still leaving alternative syntax alone inside a block that has
+/// inline HTML directly between its control-structure boundaries, where
+/// converting to braces would read worse.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AlternativeSyntaxPolicy {
+    /// Keep whichever syntax the author used.
+    #[default]
+    Preserve,
+    /// Rewrite alternative syntax to braces, except where inline HTML
+    /// appears directly inside the control structure's body.
+    ConvertToBraces,
+}
+
+impl AlternativeSyntaxPolicy {
+    /// Whether alternative syntax for a control structure whose body
+    /// contains inline HTML should be converted, given the configured
+    /// policy.
+    pub fn should_convert(self, body_contains_inline_html: bool) -> bool {
+        match self {
+            AlternativeSyntaxPolicy::Preserve => false,
+            AlternativeSyntaxPolicy::ConvertToBraces => !body_contains_inline_html,
+        }
+    }
+}
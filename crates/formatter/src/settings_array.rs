@@ -0,0 +1,24 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// How the formatter lays out an array literal's elements when they don't
+/// all fit on one line.
+///
+/// The right choice depends heavily on what the array holds: a handful of
+/// keyed, multi-line values reads best `Expand`ed one-per-line, while a long
+/// table of short scalars (an enum-like list of string constants, say) is
+/// far more scannable packed densely with `Fill`. `PreserveNewlines` exists
+/// for the cases neither heuristic gets right, by trusting whatever
+/// line-grouping the author already chose.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ArrayStyle {
+    /// One element per line, the traditional behavior.
+    #[default]
+    Expand,
+    /// Pack as many elements as fit within the line length per line.
+    Fill,
+    /// Keep elements that started on the same source line together, and
+    /// elements the author separated with a blank line apart.
+    PreserveNewlines,
+}
@@ -0,0 +1,35 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Where an attribute list is placed relative to the declaration it
+/// targets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AttributePlacement {
+    /// `#[Foo]` on its own line, above the declaration.
+    #[default]
+    Above,
+    /// `#[Foo] public function bar()` on the same line, when the attribute
+    /// list is short enough to fit (see [`AttributeSettings::inline_max_length`]).
+    SameLineWhenShort,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct AttributeSettings {
+    pub placement: AttributePlacement,
+    /// The maximum rendered length (in characters) an attribute list may
+    /// have to still qualify for [`AttributePlacement::SameLineWhenShort`].
+    pub inline_max_length: usize,
+}
+
+impl Default for AttributeSettings {
+    fn default() -> Self {
+        Self { placement: AttributePlacement::default(), inline_max_length: 40 }
+    }
+}
+
+impl AttributeSettings {
+    pub fn should_inline(&self, rendered: &str) -> bool {
+        self.placement == AttributePlacement::SameLineWhenShort && rendered.len() <= self.inline_max_length
+    }
+}
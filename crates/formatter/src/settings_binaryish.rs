@@ -0,0 +1,25 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// How the formatter lays out a binary operator chain (`.`, `+`, `&&`, ...)
+/// that doesn't fit on one line when it's the right-hand side of an
+/// [`mago_ast::ast::Assignment`] or the expression of a
+/// [`mago_ast::ast::Return`] statement.
+///
+/// A chain in those two positions reads differently from one nested deeper
+/// in an expression: the continuation lines are judged against the
+/// assignment target or the `return` keyword rather than the surrounding
+/// call or condition, so it gets its own style knob instead of reusing
+/// whatever general binary-expression wrapping applies elsewhere.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BinaryishChainStyle {
+    /// Put every operand on its own line, breaking before each operator,
+    /// indented one level relative to the assignment target / `return`
+    /// keyword.
+    #[default]
+    Indented,
+    /// Keep the first operand on the same line as the target/`return`, and
+    /// indent only the remaining operands.
+    IndentAfterFirstOperand,
+}
@@ -0,0 +1,40 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Controls where a `declare(strict_types=1);` statement sits relative to
+/// the opening `<?php` tag.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeclarePlacement {
+    /// Keep `declare(strict_types=1);` on the same line as `<?php`.
+    #[default]
+    SameLine,
+    /// Put `declare(strict_types=1);` on the line right after `<?php`.
+    NextLine,
+}
+
+/// Exact blank-line counts the formatter enforces around the edges of a
+/// file - after the opening tag (and an immediately-following `declare`
+/// statement) and before the end of the file or closing tag.
+///
+/// These are counts, not "at least"/"at most" bounds: the formatter adds or
+/// removes blank lines until exactly this many remain, rather than
+/// tolerating a range of author choices.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlankLineSettings {
+    /// Where `declare(strict_types=1);` sits relative to `<?php`.
+    pub declare_placement: DeclarePlacement,
+    /// Blank lines required after `<?php` (and after the `declare`
+    /// statement, if [`Self::declare_placement`] is [`DeclarePlacement::SameLine`])
+    /// before the first statement.
+    pub lines_after_opening_tag: u8,
+    /// Blank lines required before the end of the file, or before a
+    /// trailing `?>` closing tag when one is kept.
+    pub lines_before_end_of_file: u8,
+}
+
+impl Default for BlankLineSettings {
+    fn default() -> Self {
+        Self { declare_placement: DeclarePlacement::default(), lines_after_opening_tag: 1, lines_before_end_of_file: 0 }
+    }
+}
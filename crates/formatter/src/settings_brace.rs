@@ -0,0 +1,14 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Where the opening brace of an anonymous class or closure body goes
+/// relative to its signature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BracePlacement {
+    /// `function () {` / `new class () {` - brace on the same line.
+    #[default]
+    SameLine,
+    /// Brace on its own line, aligned with the start of the signature.
+    NextLine,
+}
@@ -0,0 +1,31 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Controls whether the formatter keeps, adds, or strips a trailing `?>`
+/// closing tag.
+///
+/// The PHP-FIG style guides (and most of the ecosystem) recommend omitting
+/// the closing tag in files that contain only PHP, to avoid accidental
+/// trailing output from whitespace after it; `Preserve` exists for files
+/// that intentionally mix PHP with trailing HTML.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ClosingTagPolicy {
+    /// Remove the closing tag when the file contains only PHP.
+    #[default]
+    Omit,
+    /// Leave the closing tag exactly as the author wrote it.
+    Preserve,
+    /// Always add a closing tag, even if the original file didn't have one.
+    Always,
+}
+
+impl ClosingTagPolicy {
+    pub fn should_emit(self, had_closing_tag: bool, had_trailing_html: bool) -> bool {
+        match self {
+            ClosingTagPolicy::Omit => had_trailing_html,
+            ClosingTagPolicy::Preserve => had_closing_tag,
+            ClosingTagPolicy::Always => true,
+        }
+    }
+}
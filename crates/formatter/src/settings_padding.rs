@@ -0,0 +1,37 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Whether the formatter inserts a space just inside a pair of delimiters
+/// (`[ 'a' => 1 ]` rather than `['a' => 1]`), independently for each kind
+/// of delimiter a house style might treat differently.
+///
+/// Each field only affects delimiters that actually have content between
+/// them - `[]`, `()`, and `foo()` stay exactly as-is either way, since
+/// there's nothing for the padding to sit between.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PaddingSettings {
+    /// Pad the brackets of an array literal: `['a' => 1]` vs `[ 'a' => 1 ]`.
+    #[serde(default)]
+    pub array_brackets: bool,
+    /// Pad the brackets of an index access: `$a['b']` vs `$a[ 'b' ]`.
+    #[serde(default)]
+    pub index_access_brackets: bool,
+    /// Pad the parentheses of a call's argument list: `foo($x)` vs `foo( $x )`.
+    #[serde(default)]
+    pub call_argument_parentheses: bool,
+    /// Pad the parentheses of a function/method/closure's parameter list:
+    /// `function foo($x)` vs `function foo( $x )`.
+    #[serde(default)]
+    pub declaration_parameter_parentheses: bool,
+}
+
+impl Default for PaddingSettings {
+    fn default() -> Self {
+        Self {
+            array_brackets: false,
+            index_access_brackets: false,
+            call_argument_parentheses: false,
+            declaration_parameter_parentheses: false,
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Controls when a constructor's promoted-property parameter list breaks
+/// to one parameter per line, instead of following the usual fits-on-one-line
+/// rule used for ordinary parameter lists.
+///
+/// Promoted parameters often carry attributes and visibility modifiers that
+/// make a packed single line hard to scan even when it's short enough to
+/// fit, so this is tracked separately from [`crate::settings_array::ArrayStyle`]
+/// and the plain argument-list logic in `internal::format::arguments`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct PromotedPropertiesSettings {
+    /// Break to one-parameter-per-line as soon as any parameter in the list
+    /// has an attribute or a visibility/`readonly` modifier, regardless of
+    /// how many parameters there are.
+    #[serde(default = "default_break_on_attributes_or_modifiers")]
+    pub break_on_attributes_or_modifiers: bool,
+    /// Break to one-parameter-per-line once the parameter count exceeds
+    /// this, even if none are promoted.
+    #[serde(default = "default_max_inline_parameters")]
+    pub max_inline_parameters: usize,
+}
+
+const fn default_break_on_attributes_or_modifiers() -> bool {
+    true
+}
+
+const fn default_max_inline_parameters() -> usize {
+    3
+}
+
+impl Default for PromotedPropertiesSettings {
+    fn default() -> Self {
+        Self {
+            break_on_attributes_or_modifiers: default_break_on_attributes_or_modifiers(),
+            max_inline_parameters: default_max_inline_parameters(),
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// How a `//`/`#`/`/* */` comment line longer than the print width is
+/// handled.
+///
+/// Reflowing every long comment is wrong as often as it's right: a URL or a
+/// code sample pasted into a comment breaks worse wrapped than left alone,
+/// so `Allow` is the default and `Reflow` is opt-in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CommentWidthOverflow {
+    /// Leave a comment exactly as wide as the author wrote it, even past
+    /// the print width.
+    #[default]
+    Allow,
+    /// Reflow the comment's prose to the print width, wrapping on word
+    /// boundaries the way a Markdown formatter would.
+    Reflow,
+}
+
+/// A narrower width the formatter prefers for code, on top of the
+/// configured hard `print_width`.
+///
+/// Code is laid out against `width` when a break is available there, but
+/// is still allowed to run up to the hard `print_width` instead of taking
+/// an awkward break - splitting a long class name or call chain one
+/// operand earlier than necessary - just to respect the narrower number.
+///
+/// Neither width ever breaks a string literal: a string that by itself
+/// exceeds both is left on one line regardless, since no formatter-inserted
+/// newline can be added inside a string without changing its value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SoftPrintWidth {
+    pub width: usize,
+}
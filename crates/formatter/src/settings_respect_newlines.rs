@@ -0,0 +1,24 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Controls whether the formatter fully re-decides the layout of call
+/// argument lists and method chains, or instead keeps whatever the author
+/// already chose and only normalizes indentation and spacing.
+///
+/// `Normalize` is what most formatters do and what keeps output perfectly
+/// deterministic regardless of how the input was written. `RespectAuthor`
+/// exists for fluent-style codebases where the author's line-break choices
+/// (breaking a chain at a particular call, keeping two related arguments on
+/// one line) carry meaning the line-length heuristic can't see.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NewlinePreservation {
+    /// Lay out arguments/chains purely by what fits on the line, ignoring
+    /// how the author originally broke them.
+    #[default]
+    Normalize,
+    /// Keep a call broken across multiple lines if the author already broke
+    /// it, and keep it on one line if they didn't; only indentation and
+    /// spacing are normalized either way.
+    RespectAuthor,
+}
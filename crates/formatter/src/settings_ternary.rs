@@ -0,0 +1,30 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// How the formatter breaks a ternary (`?:`) expression that doesn't fit on
+/// one line; `??` chains are governed by [`NullCoalesceWrapStyle`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TernaryWrapStyle {
+    /// Break before each `?`/`:` operator, each on its own line.
+    #[default]
+    OperatorFirst,
+    /// Keep the condition and `?` on the first line, breaking only before
+    /// `:`.
+    KeepQuestionMark,
+    /// Never break a ternary across lines, even if it overflows the line
+    /// length.
+    NoBreak,
+}
+
+/// How the formatter breaks a chain of `??` operators that doesn't fit on
+/// one line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NullCoalesceWrapStyle {
+    /// Break before each `??`, one operand per line.
+    #[default]
+    OperatorFirst,
+    /// Never break, even if the chain overflows the line length.
+    NoBreak,
+}
@@ -0,0 +1,104 @@
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::sync::RwLock;
+
+use rustc_hash::FxHasher;
+
+use crate::StringIdentifier;
+
+const SHARD_COUNT: usize = 16;
+
+/// A string interner usable from multiple threads without funneling every
+/// lookup through one lock.
+///
+/// Strings are hashed to pick one of [`SHARD_COUNT`] independent
+/// `RwLock<Shard>`s, so unrelated threads interning unrelated strings rarely
+/// contend on the same lock. The identifier returned encodes which shard a
+/// string lives in, so a later lookup goes straight to the right shard
+/// instead of scanning all of them.
+pub struct ConcurrentInterner {
+    shards: Vec<RwLock<Shard>>,
+}
+
+#[derive(Default)]
+struct Shard {
+    strings: Vec<Box<str>>,
+}
+
+impl ConcurrentInterner {
+    pub fn new() -> Self {
+        Self { shards: (0..SHARD_COUNT).map(|_| RwLock::new(Shard::default())).collect() }
+    }
+
+    pub fn intern(&self, value: &str) -> StringIdentifier {
+        let shard_index = shard_index_for(value);
+        let shard_lock = &self.shards[shard_index];
+
+        {
+            let shard = shard_lock.read().unwrap();
+            if let Some(position) = shard.strings.iter().position(|existing| existing.as_ref() == value) {
+                return StringIdentifier::encode(shard_index, position);
+            }
+        }
+
+        let mut shard = shard_lock.write().unwrap();
+        if let Some(position) = shard.strings.iter().position(|existing| existing.as_ref() == value) {
+            return StringIdentifier::encode(shard_index, position);
+        }
+
+        shard.strings.push(value.into());
+        StringIdentifier::encode(shard_index, shard.strings.len() - 1)
+    }
+
+    pub fn lookup(&self, id: StringIdentifier) -> String {
+        let (shard_index, position) = id.decode();
+        self.shards[shard_index].read().unwrap().strings[position].to_string()
+    }
+}
+
+impl Default for ConcurrentInterner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn shard_index_for(value: &str) -> usize {
+    let mut hasher = FxHasher::default();
+    value.hash(&mut hasher);
+
+    (hasher.finish() as usize) % SHARD_COUNT
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interning_the_same_string_twice_returns_the_same_id() {
+        let interner = ConcurrentInterner::new();
+
+        let a = interner.intern("hello");
+        let b = interner.intern("hello");
+
+        assert_eq!(a, b);
+        assert_eq!(interner.lookup(a), "hello");
+    }
+
+    #[test]
+    fn concurrent_interning_is_consistent() {
+        let interner = std::sync::Arc::new(ConcurrentInterner::new());
+
+        let handles: Vec<_> = (0..8)
+            .map(|i| {
+                let interner = interner.clone();
+                std::thread::spawn(move || interner.intern(&format!("value-{}", i % 4)))
+            })
+            .collect();
+
+        let ids: Vec<_> = handles.into_iter().map(|handle| handle.join().unwrap()).collect();
+
+        for (i, id) in ids.iter().enumerate() {
+            assert_eq!(interner.lookup(*id), format!("value-{}", i % 4));
+        }
+    }
+}
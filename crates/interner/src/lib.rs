@@ -0,0 +1,24 @@
+pub mod concurrent;
+
+/// An interned string's identity: which shard it lives in and its index
+/// within that shard, packed into a single `u32` so it stays `Copy` and
+/// cheap to store on every AST node that needs a name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StringIdentifier(u32);
+
+impl StringIdentifier {
+    const SHARD_BITS: u32 = 8;
+
+    pub(crate) fn encode(shard_index: usize, position: usize) -> Self {
+        debug_assert!(shard_index < (1 << Self::SHARD_BITS));
+
+        Self(((shard_index as u32) << (32 - Self::SHARD_BITS)) | (position as u32))
+    }
+
+    pub(crate) fn decode(self) -> (usize, usize) {
+        let shard_index = (self.0 >> (32 - Self::SHARD_BITS)) as usize;
+        let position = (self.0 & ((1 << (32 - Self::SHARD_BITS)) - 1)) as usize;
+
+        (shard_index, position)
+    }
+}
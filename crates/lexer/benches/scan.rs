@@ -0,0 +1,58 @@
+//! Benchmarks for the fast paths in `src/scan.rs`, run against a large
+//! synthetic source file so the whitespace/comment/string runs are
+//! realistic in length rather than the handful of bytes the unit tests use.
+//!
+//! `cargo bench -p mago-lexer --bench scan`
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use mago_lexer::scan::{scan_block_comment_end, scan_line_comment_end, scan_single_quoted_string_end, scan_whitespace_end};
+
+fn naive_whitespace_end(bytes: &[u8], start: usize) -> usize {
+    let mut offset = start;
+    while offset < bytes.len() && matches!(bytes[offset], b' ' | b'\t' | b'\n' | b'\r') {
+        offset += 1;
+    }
+    offset
+}
+
+fn indented_source(lines: usize) -> Vec<u8> {
+    let mut source = Vec::new();
+    for _ in 0..lines {
+        source.extend_from_slice(b"        $value = compute_something($a, $b, $c); // trailing note\n");
+    }
+    source
+}
+
+fn bench_whitespace(c: &mut Criterion) {
+    let source = indented_source(10_000);
+
+    c.bench_function("scan_whitespace_end (fast path)", |b| {
+        b.iter(|| scan_whitespace_end(black_box(&source), black_box(0)))
+    });
+    c.bench_function("scan_whitespace_end (naive)", |b| b.iter(|| naive_whitespace_end(black_box(&source), black_box(0))));
+}
+
+fn bench_line_comment(c: &mut Criterion) {
+    let mut source = vec![b' '; 2000];
+    source.extend_from_slice(b"rest of a very long single-line comment goes here and keeps going\n");
+
+    c.bench_function("scan_line_comment_end", |b| b.iter(|| scan_line_comment_end(black_box(&source), black_box(0))));
+}
+
+fn bench_block_comment(c: &mut Criterion) {
+    let mut source = b"/*".to_vec();
+    source.extend(std::iter::repeat(b'x').take(5000));
+    source.extend_from_slice(b"*/");
+
+    c.bench_function("scan_block_comment_end", |b| b.iter(|| scan_block_comment_end(black_box(&source), black_box(2))));
+}
+
+fn bench_single_quoted_string(c: &mut Criterion) {
+    let mut source = std::iter::repeat(b'x').take(5000).collect::<Vec<u8>>();
+    source.push(b'\'');
+
+    c.bench_function("scan_single_quoted_string_end", |b| b.iter(|| scan_single_quoted_string_end(black_box(&source), black_box(0))));
+}
+
+criterion_group!(benches, bench_whitespace, bench_line_comment, bench_block_comment, bench_single_quoted_string);
+criterion_main!(benches);
@@ -0,0 +1,63 @@
+use mago_span::Span;
+use mago_token::Token;
+use mago_token::TokenKind;
+
+use crate::Lexer;
+
+/// A single token together with the trivia (whitespace and comments) that
+/// immediately preceded it.
+///
+/// The parser's token stream skips trivia entirely, since it only needs
+/// meaningful tokens; syntax highlighters and formatters need both, which is
+/// what this API is for.
+#[derive(Debug, Clone)]
+pub struct RawToken {
+    pub token: Token,
+    pub leading_trivia: Vec<Token>,
+}
+
+/// Tokenizes `source` into a flat list of [`RawToken`]s without discarding
+/// trivia, for consumers that want to render or analyze the exact source
+/// text (syntax highlighting, "format on type", etc.) rather than just the
+/// parsed tree.
+pub fn tokenize_with_trivia(lexer: &mut Lexer<'_>) -> Vec<RawToken> {
+    let mut tokens = Vec::new();
+    let mut pending_trivia = Vec::new();
+
+    loop {
+        let Some(token) = lexer.advance() else {
+            break;
+        };
+
+        if is_trivia(token.kind) {
+            pending_trivia.push(token);
+            continue;
+        }
+
+        tokens.push(RawToken { token, leading_trivia: std::mem::take(&mut pending_trivia) });
+
+        if token.kind == TokenKind::Eof {
+            break;
+        }
+    }
+
+    tokens
+}
+
+fn is_trivia(kind: TokenKind) -> bool {
+    matches!(
+        kind,
+        TokenKind::Whitespace | TokenKind::SingleLineComment | TokenKind::MultiLineComment | TokenKind::HashComment | TokenKind::DocBlockComment
+    )
+}
+
+/// Returns the span covering a raw token and all of its leading trivia,
+/// useful when a highlighter wants to color whitespace-sensitive runs (e.g.
+/// a comment immediately followed by the statement it documents) as one
+/// unit.
+pub fn full_span(raw: &RawToken) -> Span {
+    match raw.leading_trivia.first() {
+        Some(first) => first.span.join(raw.token.span),
+        None => raw.token.span,
+    }
+}
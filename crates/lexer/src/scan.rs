@@ -0,0 +1,126 @@
+//! Fast paths for the token classes profiling showed the lexer spends most
+//! of its time on: runs of whitespace, line/block comments, and the body of
+//! quoted strings.
+//!
+//! Two techniques are used, depending on what the token class needs:
+//!
+//! - Whitespace runs are scanned with a plain byte-at-a-time loop that
+//!   stops at the first non-whitespace byte; it uses no SIMD or `memchr`
+//!   search.
+//! - Everything that's "scan until a specific delimiter byte" (a comment's
+//!   closing newline, a string's closing quote, an escape character) uses
+//!   [`memchr`], which already carries hand-tuned SIMD implementations per
+//!   platform — reimplementing that search here would only make it slower.
+
+/// Returns the offset just past the contiguous run of whitespace starting
+/// at `start` (space, tab, `\n`, `\r`), or `start` itself if `bytes[start]`
+/// isn't whitespace.
+pub fn scan_whitespace_end(bytes: &[u8], start: usize) -> usize {
+    let mut offset = start;
+
+    while offset < bytes.len() && is_whitespace_byte(bytes[offset]) {
+        offset += 1;
+    }
+
+    offset
+}
+
+#[inline(always)]
+fn is_whitespace_byte(byte: u8) -> bool {
+    matches!(byte, b' ' | b'\t' | b'\n' | b'\r')
+}
+
+/// Returns the offset of the end of a `//`/`#` line comment starting at
+/// `start`: the offset of the line's terminating `\n`, or `bytes.len()` if
+/// the comment runs to the end of the file.
+pub fn scan_line_comment_end(bytes: &[u8], start: usize) -> usize {
+    memchr::memchr(b'\n', &bytes[start..]).map_or(bytes.len(), |relative| start + relative)
+}
+
+/// Returns the offset just past the closing `*/` of a `/* ... */` comment
+/// whose body starts at `start` (i.e. just after the opening `/*`), or
+/// `bytes.len()` if it's never closed.
+pub fn scan_block_comment_end(bytes: &[u8], start: usize) -> usize {
+    let mut offset = start;
+
+    loop {
+        match memchr::memchr(b'*', &bytes[offset..]) {
+            Some(relative) => {
+                let star = offset + relative;
+                if bytes.get(star + 1) == Some(&b'/') {
+                    return star + 2;
+                }
+                offset = star + 1;
+            }
+            None => return bytes.len(),
+        }
+    }
+}
+
+/// Returns the offset of the closing `'` of a single-quoted string body
+/// starting at `start` (i.e. just after the opening `'`), accounting for
+/// `\\` and `\'` escapes, or `bytes.len()` if it's never closed.
+pub fn scan_single_quoted_string_end(bytes: &[u8], start: usize) -> usize {
+    let mut offset = start;
+
+    loop {
+        match memchr::memchr2(b'\'', b'\\', &bytes[offset..]) {
+            Some(relative) => {
+                let hit = offset + relative;
+                if bytes[hit] == b'\'' {
+                    return hit;
+                }
+                // An escape: skip both the backslash and whatever it escapes.
+                offset = hit + 2;
+            }
+            None => return bytes.len(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scans_a_run_of_mixed_whitespace() {
+        let bytes = b" \t\n\r  x";
+        assert_eq!(scan_whitespace_end(bytes, 0), 6);
+    }
+
+    #[test]
+    fn a_non_whitespace_start_scans_nothing() {
+        let bytes = b"x   ";
+        assert_eq!(scan_whitespace_end(bytes, 0), 0);
+    }
+
+    #[test]
+    fn line_comment_ends_at_the_newline() {
+        let bytes = b"// hello\nworld";
+        assert_eq!(scan_line_comment_end(bytes, 2), 8);
+    }
+
+    #[test]
+    fn line_comment_without_a_trailing_newline_ends_at_eof() {
+        let bytes = b"// hello";
+        assert_eq!(scan_line_comment_end(bytes, 2), bytes.len());
+    }
+
+    #[test]
+    fn block_comment_ends_past_the_closing_delimiter() {
+        let bytes = b"/* a * b */x";
+        assert_eq!(scan_block_comment_end(bytes, 2), 11);
+    }
+
+    #[test]
+    fn block_comment_tolerates_a_lone_star() {
+        let bytes = b"/* a * still going */x";
+        assert_eq!(scan_block_comment_end(bytes, 2), 21);
+    }
+
+    #[test]
+    fn single_quoted_string_skips_escapes() {
+        let bytes = br"it\'s fine'rest";
+        assert_eq!(scan_single_quoted_string_end(bytes, 0), 10);
+    }
+}
@@ -0,0 +1,138 @@
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::path::Path;
+use std::path::PathBuf;
+
+use rustc_hash::FxHasher;
+use serde::Deserialize;
+use serde::Serialize;
+
+use mago_reporting::Issue;
+
+/// Key identifying a cached lint result: the file's content hash combined
+/// with a hash of the effective configuration (enabled rules, options,
+/// target PHP version). Changing either invalidates the entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct LintCacheKey {
+    pub content_hash: u64,
+    pub config_hash: u64,
+}
+
+impl LintCacheKey {
+    pub fn new(content: &str, config_hash: u64) -> Self {
+        let mut hasher = FxHasher::default();
+        content.hash(&mut hasher);
+
+        Self { content_hash: hasher.finish(), config_hash }
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CacheEntry {
+    key: LintCacheKey,
+    issues: Vec<Issue>,
+}
+
+/// An on-disk, per-file lint result cache.
+///
+/// Entries are looked up by [`LintCacheKey`]; a mismatch on either the
+/// content or the config hash is treated as a cache miss, never as a
+/// partial hit, since a config change can affect every file's result.
+#[derive(Debug, Default)]
+pub struct LintCache {
+    directory: PathBuf,
+}
+
+impl LintCache {
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self { directory: directory.into() }
+    }
+
+    pub fn get(&self, file: &Path, key: LintCacheKey) -> Option<Vec<Issue>> {
+        let entry: CacheEntry = serde_json::from_slice(&std::fs::read(self.entry_path(file)).ok()?).ok()?;
+
+        if entry.key != key { None } else { Some(entry.issues) }
+    }
+
+    pub fn store(&self, file: &Path, key: LintCacheKey, issues: &[Issue]) -> std::io::Result<()> {
+        std::fs::create_dir_all(&self.directory)?;
+
+        let entry = CacheEntry { key, issues: issues.to_vec() };
+        let serialized = serde_json::to_vec(&entry).unwrap_or_default();
+
+        std::fs::write(self.entry_path(file), serialized)
+    }
+
+    fn entry_path(&self, file: &Path) -> PathBuf {
+        let mut hasher = FxHasher::default();
+        file.hash(&mut hasher);
+
+        self.directory.join(format!("{:016x}.json", hasher.finish()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mago_reporting::Level;
+
+    use super::*;
+
+    #[test]
+    fn lint_cache_key_is_deterministic_for_the_same_content_and_config() {
+        assert_eq!(LintCacheKey::new("<?php", 1), LintCacheKey::new("<?php", 1));
+    }
+
+    #[test]
+    fn lint_cache_key_changes_with_content() {
+        assert_ne!(LintCacheKey::new("<?php", 1), LintCacheKey::new("<?php echo 1;", 1));
+    }
+
+    #[test]
+    fn lint_cache_key_changes_with_config_hash() {
+        assert_ne!(LintCacheKey::new("<?php", 1), LintCacheKey::new("<?php", 2));
+    }
+
+    fn scratch_directory(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("mago-lint-cache-test-{name}"))
+    }
+
+    #[test]
+    fn get_is_a_miss_when_nothing_has_been_stored() {
+        let directory = scratch_directory("miss-when-empty");
+        let cache = LintCache::new(&directory);
+
+        assert!(cache.get(Path::new("example.php"), LintCacheKey::new("<?php", 1)).is_none());
+
+        let _ = std::fs::remove_dir_all(&directory);
+    }
+
+    #[test]
+    fn store_then_get_with_the_same_key_is_a_hit() {
+        let directory = scratch_directory("hit-round-trip");
+        let cache = LintCache::new(&directory);
+        let key = LintCacheKey::new("<?php", 1);
+        let issues = vec![Issue::new(Level::Warning, "unused variable").with_code("redundancy/dead-store")];
+
+        cache.store(Path::new("example.php"), key, &issues).expect("storing the cache entry should succeed");
+
+        let restored = cache.get(Path::new("example.php"), key).expect("the entry should still be cached");
+        assert_eq!(restored.len(), 1);
+        assert_eq!(restored[0].code.as_deref(), Some("redundancy/dead-store"));
+
+        let _ = std::fs::remove_dir_all(&directory);
+    }
+
+    #[test]
+    fn get_is_a_miss_when_the_key_no_longer_matches() {
+        let directory = scratch_directory("miss-on-key-change");
+        let cache = LintCache::new(&directory);
+        let stored_key = LintCacheKey::new("<?php", 1);
+
+        cache.store(Path::new("example.php"), stored_key, &[]).expect("storing the cache entry should succeed");
+
+        let changed_key = LintCacheKey::new("<?php echo 1;", 1);
+        assert!(cache.get(Path::new("example.php"), changed_key).is_none());
+
+        let _ = std::fs::remove_dir_all(&directory);
+    }
+}
@@ -0,0 +1,102 @@
+use serde::Serialize;
+
+use crate::definition::RuleDefinition;
+use crate::plugin::LintPlugin;
+
+/// A flattened, serializable view of a single rule, suitable for feeding
+/// documentation generators or editor integrations that want to list every
+/// available rule without linking against `mago-linter` itself.
+#[derive(Debug, Serialize)]
+pub struct RuleExport {
+    pub plugin: &'static str,
+    pub name: &'static str,
+    pub code: String,
+    pub description: &'static str,
+    pub default_level: String,
+    pub enabled_by_default: bool,
+    pub has_fix: bool,
+    pub examples: Vec<RuleExampleExport>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RuleExampleExport {
+    pub description: String,
+    pub snippet: String,
+    pub valid: bool,
+}
+
+/// Exports every rule registered by `plugins` as structured data.
+///
+/// The result round-trips through `serde_json` as-is; callers that want the
+/// Markdown rendering used by the documentation site should go through
+/// [`render_markdown`] instead.
+pub fn export_rules(plugins: &[Box<dyn LintPlugin>]) -> Vec<RuleExport> {
+    let mut exports = Vec::new();
+
+    for plugin in plugins {
+        for rule in plugin.get_rules() {
+            let definition: RuleDefinition = rule.get_definition();
+
+            exports.push(RuleExport {
+                plugin: plugin.get_name(),
+                name: definition.name,
+                code: format!("{}/{}", plugin.get_name(), to_kebab_case(definition.name)),
+                description: definition.description,
+                default_level: format!("{:?}", definition.level).to_lowercase(),
+                enabled_by_default: plugin.is_enabled_by_default() && definition.enabled_by_default,
+                has_fix: definition.has_fix,
+                examples: definition
+                    .examples
+                    .iter()
+                    .map(|example| RuleExampleExport {
+                        description: example.description.clone(),
+                        snippet: example.snippet.clone(),
+                        valid: example.valid,
+                    })
+                    .collect(),
+            });
+        }
+    }
+
+    exports
+}
+
+/// Renders the given exports as a Markdown document, one section per rule,
+/// grouped by plugin in registration order.
+pub fn render_markdown(exports: &[RuleExport]) -> String {
+    let mut markdown = String::new();
+
+    let mut current_plugin = "";
+    for export in exports {
+        if export.plugin != current_plugin {
+            current_plugin = export.plugin;
+            markdown.push_str(&format!("\n## {current_plugin}\n"));
+        }
+
+        markdown.push_str(&format!("\n### `{}`\n\n{}\n", export.code, export.description));
+
+        for example in &export.examples {
+            let label = if example.valid { "Valid" } else { "Invalid" };
+            markdown.push_str(&format!("\n**{label}**: {}\n\n```php\n{}\n```\n", example.description, example.snippet));
+        }
+    }
+
+    markdown
+}
+
+fn to_kebab_case(name: &str) -> String {
+    let mut result = String::with_capacity(name.len());
+    for (i, ch) in name.chars().enumerate() {
+        if ch.is_ascii_uppercase() {
+            if i != 0 && !result.ends_with('-') {
+                result.push('-');
+            }
+            result.push(ch.to_ascii_lowercase());
+        } else if ch == ' ' {
+            result.push('-');
+        } else {
+            result.push(ch);
+        }
+    }
+    result
+}
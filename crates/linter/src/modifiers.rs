@@ -0,0 +1,96 @@
+/// The modifier keywords this crate recognizes on a class member, in
+/// canonical order (final/abstract, then visibility, then static, then
+/// readonly).
+///
+/// This is the single source of truth for "canonical modifier order" used
+/// by [`crate::plugin::consistency::rules::modifier_order::ModifierOrderRule`].
+/// The formatter would be the natural place to share this with, since it
+/// prints the same modifier lists, but no modifier-printing logic exists
+/// anywhere in this tree to share it with.
+pub const CANONICAL_MODIFIER_ORDER: &[&str] = &["final", "abstract", "public", "protected", "private", "static", "readonly"];
+
+/// The canonical-order rank of a modifier keyword, case-insensitively, or
+/// `None` if it isn't one of [`CANONICAL_MODIFIER_ORDER`].
+pub fn modifier_rank(modifier: &str) -> Option<usize> {
+    CANONICAL_MODIFIER_ORDER.iter().position(|candidate| candidate.eq_ignore_ascii_case(modifier))
+}
+
+/// Scans `text` (expected to start at a class member's own modifiers, e.g.
+/// the text of a [`mago_span::HasSpan::span`]) for the leading run of
+/// whitespace-separated modifier keywords, stopping at the first word that
+/// isn't one.
+///
+/// Returns each token's byte offset range within `text`, alongside the
+/// token itself. Used instead of a parsed modifier list, since this tree
+/// has no confirmed way to iterate a member's modifiers with their
+/// individual spans - only the aggregate `contains_*()` checks.
+pub fn leading_modifiers(text: &str) -> Vec<(u32, u32, &str)> {
+    let bytes = text.as_bytes();
+    let mut index = 0usize;
+    let mut tokens = Vec::new();
+
+    loop {
+        while index < bytes.len() && bytes[index].is_ascii_whitespace() {
+            index += 1;
+        }
+
+        let start = index;
+        while index < bytes.len() && !bytes[index].is_ascii_whitespace() {
+            index += 1;
+        }
+
+        if start == index {
+            break;
+        }
+
+        let word = &text[start..index];
+        if modifier_rank(word).is_none() {
+            break;
+        }
+
+        tokens.push((start as u32, index as u32, word));
+    }
+
+    tokens
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn modifier_rank_orders_final_before_visibility_before_static_before_readonly() {
+        assert!(modifier_rank("final") < modifier_rank("public"));
+        assert!(modifier_rank("public") < modifier_rank("static"));
+        assert!(modifier_rank("static") < modifier_rank("readonly"));
+    }
+
+    #[test]
+    fn modifier_rank_is_case_insensitive() {
+        assert_eq!(modifier_rank("PUBLIC"), modifier_rank("public"));
+    }
+
+    #[test]
+    fn modifier_rank_rejects_non_modifiers() {
+        assert_eq!(modifier_rank("function"), None);
+    }
+
+    #[test]
+    fn leading_modifiers_collects_the_whitespace_separated_run() {
+        let tokens = leading_modifiers("static private int $value = 0;");
+        let words: Vec<&str> = tokens.iter().map(|(_, _, word)| *word).collect();
+        assert_eq!(words, vec!["static", "private"]);
+    }
+
+    #[test]
+    fn leading_modifiers_stops_at_the_first_non_modifier_word() {
+        let tokens = leading_modifiers("public function foo(): void {}");
+        let words: Vec<&str> = tokens.iter().map(|(_, _, word)| *word).collect();
+        assert_eq!(words, vec!["public"]);
+    }
+
+    #[test]
+    fn leading_modifiers_is_empty_with_no_leading_modifiers() {
+        assert!(leading_modifiers("int $value = 0;").is_empty());
+    }
+}
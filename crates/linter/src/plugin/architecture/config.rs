@@ -0,0 +1,118 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// A named group of classes, identified by namespace pattern, along with
+/// the other layers it's allowed to depend on.
+///
+/// Patterns are a namespace prefix with an optional trailing `*` (e.g.
+/// `"App\\Domain\\*"` matches anything under the `App\Domain` namespace; a
+/// pattern without a trailing `*` matches only that exact name).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LayerDefinition {
+    pub name: String,
+    pub namespace_patterns: Vec<String>,
+    #[serde(default)]
+    pub allowed_dependencies: Vec<String>,
+}
+
+impl LayerDefinition {
+    fn matches(&self, class_name: &str) -> bool {
+        self.namespace_patterns.iter().any(|pattern| matches_pattern(pattern, class_name))
+    }
+}
+
+fn matches_pattern(pattern: &str, class_name: &str) -> bool {
+    match pattern.strip_suffix('*') {
+        Some(prefix) => class_name.starts_with(prefix),
+        None => class_name == pattern,
+    }
+}
+
+/// Where direct superglobal access (`$_GET`, `$_POST`, etc.) is allowed to
+/// happen, for
+/// [`crate::plugin::architecture::rules::superglobal_boundary::SuperglobalBoundaryRule`].
+///
+/// Uses the same `namespace_patterns` syntax as [`LayerDefinition`]: a
+/// namespace prefix with an optional trailing `*`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SuperglobalBoundaryConfig {
+    /// The superglobal variable names this rule restricts, `$`-prefixed
+    /// (e.g. `"$_GET"`).
+    #[serde(default = "default_superglobals")]
+    pub superglobals: Vec<String>,
+    /// Namespace patterns allowed to access the configured superglobals
+    /// directly. With this empty, every access is flagged.
+    #[serde(default)]
+    pub allowed_namespace_patterns: Vec<String>,
+}
+
+impl SuperglobalBoundaryConfig {
+    pub fn is_allowed(&self, namespace: &str) -> bool {
+        self.allowed_namespace_patterns.iter().any(|pattern| matches_pattern(pattern, namespace))
+    }
+}
+
+impl Default for SuperglobalBoundaryConfig {
+    fn default() -> Self {
+        Self { superglobals: default_superglobals(), allowed_namespace_patterns: Vec::new() }
+    }
+}
+
+fn default_superglobals() -> Vec<String> {
+    ["$_GET", "$_POST", "$_SERVER", "$_SESSION"].into_iter().map(String::from).collect()
+}
+
+/// User-defined architectural layers and the dependencies permitted between
+/// them, analogous to a deptrac `depfile.yaml`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ArchitectureConfig {
+    #[serde(default)]
+    pub layers: Vec<LayerDefinition>,
+    #[serde(default)]
+    pub superglobal_boundary: SuperglobalBoundaryConfig,
+}
+
+impl ArchitectureConfig {
+    /// Finds the layer `class_name` belongs to, if any. A class outside
+    /// every configured layer's namespace patterns isn't governed by this
+    /// rule at all.
+    pub fn layer_for(&self, class_name: &str) -> Option<&LayerDefinition> {
+        self.layers.iter().find(|layer| layer.matches(class_name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_trailing_star_matches_as_a_namespace_prefix() {
+        let layer = LayerDefinition {
+            name: "Domain".to_string(),
+            namespace_patterns: vec!["App\\Domain\\*".to_string()],
+            allowed_dependencies: vec![],
+        };
+
+        assert!(layer.matches("App\\Domain\\User"));
+        assert!(!layer.matches("App\\Infrastructure\\User"));
+    }
+
+    #[test]
+    fn a_namespace_matching_an_allowed_pattern_is_allowed() {
+        let config = SuperglobalBoundaryConfig {
+            superglobals: default_superglobals(),
+            allowed_namespace_patterns: vec!["App\\Http\\*".to_string()],
+        };
+
+        assert!(config.is_allowed("App\\Http\\Controllers\\UserController"));
+        assert!(!config.is_allowed("App\\Domain\\User"));
+    }
+
+    #[test]
+    fn without_a_star_the_pattern_must_match_exactly() {
+        let layer = LayerDefinition { name: "Kernel".to_string(), namespace_patterns: vec!["App\\Kernel".to_string()], allowed_dependencies: vec![] };
+
+        assert!(layer.matches("App\\Kernel"));
+        assert!(!layer.matches("App\\Kernel\\Extra"));
+    }
+}
@@ -0,0 +1,33 @@
+use crate::plugin::LintPlugin;
+use crate::plugin::architecture::rules::dependency_constraint::DependencyConstraintRule;
+use crate::plugin::architecture::rules::superglobal_boundary::SuperglobalBoundaryRule;
+use crate::rule::Rule;
+
+pub mod config;
+pub mod rules;
+
+/// A deptrac-style architecture plugin: users define namespace-based layers
+/// and the dependencies permitted between them in config, and
+/// [`rules::dependency_constraint::DependencyConstraintRule`] reports every
+/// reference that crosses a boundary the config doesn't allow.
+/// [`rules::superglobal_boundary::SuperglobalBoundaryRule`] applies the same
+/// namespace-pattern idea to direct superglobal access.
+///
+/// With no layers or patterns configured, this plugin has nothing to check
+/// and never reports anything.
+#[derive(Debug)]
+pub struct ArchitecturePlugin;
+
+impl LintPlugin for ArchitecturePlugin {
+    fn get_name(&self) -> &'static str {
+        "architecture"
+    }
+
+    fn is_enabled_by_default(&self) -> bool {
+        false
+    }
+
+    fn get_rules(&self) -> Vec<Box<dyn Rule>> {
+        vec![Box::new(DependencyConstraintRule), Box::new(SuperglobalBoundaryRule)]
+    }
+}
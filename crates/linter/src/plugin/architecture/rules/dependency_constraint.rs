@@ -0,0 +1,85 @@
+use mago_ast::ast::*;
+use mago_ast::Node;
+use mago_reporting::{Annotation, AnnotationKind, Issue, Level};
+use mago_span::{HasSpan, Span};
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::rule::Rule;
+
+/// Enforces user-configured layer boundaries (`architecture.layers` in the
+/// linter config): a class in one layer referencing (via `extends`,
+/// `implements`, or `use`) a class in a layer it isn't allowed to depend on
+/// is reported at the offending reference.
+///
+/// A class outside every configured layer's namespace patterns isn't
+/// governed by this rule, and a dependency on a class in the same layer is
+/// always allowed.
+#[derive(Debug)]
+pub struct DependencyConstraintRule;
+
+impl Rule for DependencyConstraintRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Dependency Constraint", Level::Error)
+            .with_description("Flags a reference from one configured architectural layer to another layer it isn't allowed to depend on.")
+    }
+
+    fn check(&self, node: Node<'_>, context: &mut LintContext<'_>) {
+        let Node::Class(class) = node else {
+            return;
+        };
+
+        let config = &context.settings().architecture;
+        let class_name = class.name.value.to_string();
+
+        let Some(declaring_layer) = config.layer_for(&class_name) else {
+            return;
+        };
+
+        for (dependency_name, span) in referenced_classes(class) {
+            let Some(dependency_layer) = config.layer_for(&dependency_name) else {
+                continue;
+            };
+
+            if dependency_layer.name == declaring_layer.name {
+                continue;
+            }
+
+            if declaring_layer.allowed_dependencies.iter().any(|allowed| allowed == &dependency_layer.name) {
+                continue;
+            }
+
+            context.report(
+                Issue::new(
+                    Level::Error,
+                    format!(
+                        "layer `{}` must not depend on layer `{}` (via `{dependency_name}`)",
+                        declaring_layer.name, dependency_layer.name
+                    ),
+                )
+                .with_code("architecture/dependency-constraint")
+                .with_annotation(Annotation::new(span, AnnotationKind::Primary).with_message("this reference crosses a layer boundary")),
+            );
+        }
+    }
+}
+
+fn referenced_classes(class: &Class) -> Vec<(String, Span)> {
+    let mut references = Vec::new();
+
+    if let Some(extends) = &class.extends {
+        references.extend(extends.types.iter().map(|identifier| (identifier.value().to_string(), identifier.span())));
+    }
+
+    if let Some(implements) = &class.implements {
+        references.extend(implements.types.iter().map(|identifier| (identifier.value().to_string(), identifier.span())));
+    }
+
+    for member in &class.members {
+        if let ClassLikeMember::TraitUse(trait_use) = member {
+            references.extend(trait_use.trait_names.iter().map(|identifier| (identifier.value().to_string(), identifier.span())));
+        }
+    }
+
+    references
+}
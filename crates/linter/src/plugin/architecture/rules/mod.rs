@@ -0,0 +1,2 @@
+pub mod dependency_constraint;
+pub mod superglobal_boundary;
@@ -0,0 +1,238 @@
+use mago_ast::ast::*;
+use mago_ast::Node;
+use mago_reporting::{Annotation, AnnotationKind, Issue, Level};
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::rule::Rule;
+
+/// Restricts direct access to configured superglobals (`$_GET`, `$_POST`,
+/// `$_SERVER`, `$_SESSION` by default) to namespaces matching
+/// [`crate::plugin::architecture::config::SuperglobalBoundaryConfig::allowed_namespace_patterns`],
+/// pushing the rest of the codebase towards a request/response abstraction
+/// instead of reading raw superglobals wherever convenient.
+///
+/// With no patterns configured, every direct access is flagged. The
+/// namespace checked is whichever `namespace` declaration most recently
+/// precedes the access in the source (tracked via the real
+/// [`Statement::Namespace`] node while walking the program, not a textual
+/// guess); code before any `namespace` declaration is treated as belonging
+/// to the empty (global) namespace, which an empty pattern list never
+/// allows.
+///
+/// This walks statement and expression bodies it has a confirmed shape
+/// for - control flow, functions, and class-like members - but can't see
+/// into a closure or arrow function literal embedded in an expression, since
+/// no such expression variant is confirmed anywhere in this tree; a
+/// superglobal read from inside one is missed rather than misattributed to
+/// the wrong namespace.
+#[derive(Debug)]
+pub struct SuperglobalBoundaryRule;
+
+impl Rule for SuperglobalBoundaryRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Superglobal Boundary", Level::Warning).with_description(
+            "Flags direct superglobal access outside of the namespaces configured to allow it.",
+        )
+    }
+
+    fn check(&self, node: Node<'_>, context: &mut LintContext<'_>) {
+        let Node::Program(program) = node else {
+            return;
+        };
+
+        let superglobals = context.settings().architecture.superglobal_boundary.superglobals.clone();
+        if superglobals.is_empty() {
+            return;
+        }
+
+        check_statements(context, program.statements.iter(), "", &superglobals);
+    }
+}
+
+/// Walks a sequence of sibling statements, tracking the namespace currently
+/// in effect. An unbraced `namespace Foo;` changes the namespace for every
+/// statement after it at this level; a braced `namespace Foo { ... }` only
+/// affects its own body.
+fn check_statements<'a>(
+    context: &mut LintContext<'_>,
+    statements: impl Iterator<Item = &'a Statement>,
+    namespace: &'a str,
+    superglobals: &[String],
+) {
+    let mut current_namespace = namespace;
+
+    for statement in statements {
+        if let Statement::Namespace(namespace_statement) = statement {
+            let name = namespace_statement.name.as_ref().map(|name| name.value.as_str()).unwrap_or("");
+
+            if namespace_statement.statements.is_empty() {
+                current_namespace = name;
+            } else {
+                check_statements(context, namespace_statement.statements.iter(), name, superglobals);
+            }
+
+            continue;
+        }
+
+        check_statement(context, statement, current_namespace, superglobals);
+    }
+}
+
+fn check_statement<'a>(context: &mut LintContext<'_>, statement: &'a Statement, namespace: &'a str, superglobals: &[String]) {
+    match statement {
+        Statement::Block(block) => check_statements(context, block.statements.iter(), namespace, superglobals),
+        Statement::If(r#if) => {
+            check_expression(context, &r#if.condition, namespace, superglobals);
+            check_statement(context, &r#if.body, namespace, superglobals);
+            for clause in &r#if.else_if_clauses {
+                check_expression(context, &clause.condition, namespace, superglobals);
+                check_statement(context, &clause.body, namespace, superglobals);
+            }
+            if let Some(else_clause) = &r#if.else_clause {
+                check_statement(context, &else_clause.body, namespace, superglobals);
+            }
+        }
+        Statement::While(r#while) => {
+            check_expression(context, &r#while.condition, namespace, superglobals);
+            check_statement(context, &r#while.body, namespace, superglobals);
+        }
+        Statement::DoWhile(do_while) => {
+            check_statement(context, &do_while.body, namespace, superglobals);
+            check_expression(context, &do_while.condition, namespace, superglobals);
+        }
+        Statement::For(r#for) => {
+            for condition in &r#for.conditions {
+                check_expression(context, condition, namespace, superglobals);
+            }
+            check_statement(context, &r#for.body, namespace, superglobals);
+        }
+        Statement::Foreach(foreach) => {
+            check_expression(context, &foreach.expression, namespace, superglobals);
+            check_statement(context, &foreach.body, namespace, superglobals);
+        }
+        Statement::Switch(switch) => {
+            check_expression(context, &switch.expression, namespace, superglobals);
+            for case in switch.body.cases() {
+                for inner in case.statements() {
+                    check_statement(context, inner, namespace, superglobals);
+                }
+            }
+        }
+        Statement::Try(r#try) => {
+            for inner in &r#try.block.statements {
+                check_statement(context, inner, namespace, superglobals);
+            }
+            for clause in &r#try.catch_clauses {
+                for inner in &clause.block.statements {
+                    check_statement(context, inner, namespace, superglobals);
+                }
+            }
+            if let Some(finally) = &r#try.finally_clause {
+                for inner in &finally.block.statements {
+                    check_statement(context, inner, namespace, superglobals);
+                }
+            }
+        }
+        Statement::Expression(expression_statement) => {
+            check_expression(context, &expression_statement.expression, namespace, superglobals);
+        }
+        Statement::Return(r#return) => {
+            if let Some(value) = &r#return.value {
+                check_expression(context, value, namespace, superglobals);
+            }
+        }
+        Statement::Function(function) => {
+            check_statements(context, function.body.statements.iter(), namespace, superglobals);
+        }
+        Statement::Class(class) => check_members(context, &class.members, namespace, superglobals),
+        Statement::Interface(interface) => check_members(context, &interface.members, namespace, superglobals),
+        Statement::Trait(r#trait) => check_members(context, &r#trait.members, namespace, superglobals),
+        Statement::Enum(r#enum) => check_members(context, &r#enum.members, namespace, superglobals),
+        _ => {}
+    }
+}
+
+fn check_members<'a>(context: &mut LintContext<'_>, members: &'a [ClassLikeMember], namespace: &'a str, superglobals: &[String]) {
+    for member in members {
+        if let ClassLikeMember::Method(method) = member {
+            if let Some(statements) = method.body.as_statements() {
+                check_statements(context, statements.iter(), namespace, superglobals);
+            }
+        }
+    }
+}
+
+/// Checks `expression` itself, then recurses into every subexpression this
+/// analysis knows how to open up.
+fn check_expression(context: &mut LintContext<'_>, expression: &Expression, namespace: &str, superglobals: &[String]) {
+    if let Expression::Variable(Variable::Direct(variable)) = expression {
+        if superglobals.iter().any(|name| name == &variable.name)
+            && !context.settings().architecture.superglobal_boundary.is_allowed(namespace)
+        {
+            context.report(
+                Issue::new(Level::Warning, format!("direct access to `{}` is not allowed outside of its designated boundary", variable.name))
+                    .with_code("architecture/superglobal-boundary")
+                    .with_annotation(
+                        Annotation::new(variable.span(), AnnotationKind::Primary)
+                            .with_message("use a request abstraction instead of reading this superglobal directly"),
+                    ),
+            );
+        }
+    }
+
+    match expression {
+        Expression::Throw(r#throw) => check_expression(context, &r#throw.exception, namespace, superglobals),
+        Expression::Assignment(assignment) => {
+            check_expression(context, &assignment.lhs, namespace, superglobals);
+            check_expression(context, &assignment.rhs, namespace, superglobals);
+        }
+        Expression::AssignmentOperation(assignment) => {
+            check_expression(context, &assignment.lhs, namespace, superglobals);
+            check_expression(context, &assignment.rhs, namespace, superglobals);
+        }
+        Expression::Binary(binary) => {
+            check_expression(context, &binary.lhs, namespace, superglobals);
+            check_expression(context, &binary.rhs, namespace, superglobals);
+        }
+        Expression::Call(Call::Function(call)) => {
+            check_expression(context, &call.function, namespace, superglobals);
+            for argument in &call.arguments.arguments {
+                check_expression(context, argument_value(argument), namespace, superglobals);
+            }
+        }
+        Expression::Call(Call::Method(call)) => {
+            check_expression(context, &call.object, namespace, superglobals);
+            for argument in &call.arguments.arguments {
+                check_expression(context, argument_value(argument), namespace, superglobals);
+            }
+        }
+        Expression::Call(Call::StaticMethod(call)) => {
+            for argument in &call.arguments.arguments {
+                check_expression(context, argument_value(argument), namespace, superglobals);
+            }
+        }
+        Expression::Access(Access::Property(access)) => check_expression(context, &access.object, namespace, superglobals),
+        Expression::ArrayAccess(access) => {
+            check_expression(context, &access.array, namespace, superglobals);
+            if let Some(index) = access.index.as_deref() {
+                check_expression(context, index, namespace, superglobals);
+            }
+        }
+        Expression::Isset(isset) => {
+            for value in &isset.values {
+                check_expression(context, value, namespace, superglobals);
+            }
+        }
+        Expression::Empty(empty) => check_expression(context, &empty.value, namespace, superglobals),
+        _ => {}
+    }
+}
+
+fn argument_value(argument: &Argument) -> &Expression {
+    match argument {
+        Argument::Positional(positional) => &positional.value,
+        Argument::Named(named) => &named.value,
+    }
+}
@@ -0,0 +1,26 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Which way [`crate::plugin::best_practices::rules::require_braces::RequireBracesRule`]
+/// enforces consistency for single-statement control structure bodies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BraceStyle {
+    /// Always require `{ }` around the body, even for a single statement.
+    #[default]
+    AlwaysBraces,
+    /// Require the opposite: no braces around a single-statement body.
+    NeverBraces,
+}
+
+/// Configuration for the `best-practices` plugin.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BestPracticesConfig {
+    #[serde(default)]
+    pub brace_style: BraceStyle,
+    /// Path patterns (a prefix with an optional trailing `*`) exempted from
+    /// [`crate::plugin::best_practices::rules::side_effect_free_declarations::SideEffectFreeDeclarationsRule`],
+    /// for genuine bootstrap files that are expected to both declare and run things.
+    #[serde(default)]
+    pub side_effect_allowed_in: Vec<String>,
+}
@@ -0,0 +1,53 @@
+use crate::plugin::LintPlugin;
+use crate::plugin::best_practices::rules::boolean_argument_trap::BooleanArgumentTrapRule;
+use crate::plugin::best_practices::rules::closure_to_arrow_function::ClosureToArrowFunctionRule;
+use crate::plugin::best_practices::rules::empty_catch_block::EmptyCatchBlockRule;
+use crate::plugin::best_practices::rules::enum_value_comparison::EnumValueComparisonRule;
+use crate::plugin::best_practices::rules::exhaustive_enum_match::ExhaustiveEnumMatchRule;
+use crate::plugin::best_practices::rules::inferable_property_type::InferablePropertyTypeRule;
+use crate::plugin::best_practices::rules::invalid_attribute_target::InvalidAttributeTargetRule;
+use crate::plugin::best_practices::rules::overly_broad_catch::OverlyBroadCatchRule;
+use crate::plugin::best_practices::rules::require_final_class::RequireFinalClassRule;
+use crate::plugin::best_practices::rules::prefer_match_expression::PreferMatchExpressionRule;
+use crate::plugin::best_practices::rules::rethrow_only_catch::RethrowOnlyCatchRule;
+use crate::plugin::best_practices::rules::require_braces::RequireBracesRule;
+use crate::plugin::best_practices::rules::side_effect_free_declarations::SideEffectFreeDeclarationsRule;
+use crate::plugin::best_practices::rules::trait_method_conflict::TraitMethodConflictRule;
+use crate::rule::Rule;
+
+pub mod config;
+pub mod rules;
+
+/// General "this is clearer/safer" suggestions that aren't tied to any
+/// particular framework or migration target.
+#[derive(Debug)]
+pub struct BestPracticesPlugin;
+
+impl LintPlugin for BestPracticesPlugin {
+    fn get_name(&self) -> &'static str {
+        "best-practices"
+    }
+
+    fn is_enabled_by_default(&self) -> bool {
+        true
+    }
+
+    fn get_rules(&self) -> Vec<Box<dyn Rule>> {
+        vec![
+            Box::new(BooleanArgumentTrapRule),
+            Box::new(PreferMatchExpressionRule),
+            Box::new(ExhaustiveEnumMatchRule),
+            Box::new(ClosureToArrowFunctionRule),
+            Box::new(InferablePropertyTypeRule),
+            Box::new(InvalidAttributeTargetRule),
+            Box::new(RequireFinalClassRule),
+            Box::new(TraitMethodConflictRule),
+            Box::new(RequireBracesRule),
+            Box::new(SideEffectFreeDeclarationsRule),
+            Box::new(EmptyCatchBlockRule),
+            Box::new(EnumValueComparisonRule),
+            Box::new(OverlyBroadCatchRule),
+            Box::new(RethrowOnlyCatchRule),
+        ]
+    }
+}
@@ -0,0 +1,148 @@
+use mago_ast::ast::*;
+use mago_ast::Node;
+use mago_php_version::PHPVersion;
+use mago_reporting::{Annotation, AnnotationKind, Issue, Level};
+use mago_span::HasSpan;
+
+use mago_fixer::FixPlan;
+use mago_fixer::SafetyClassification;
+
+use crate::context::LintContext;
+use crate::definition::{RuleDefinition, RuleUsageExample};
+use crate::rule::Rule;
+
+/// Flags a bare `true`/`false` passed positionally to a call - the classic
+/// "boolean trap", where a reader at the call site has no way to tell what
+/// the flag means without looking up the callee's signature.
+///
+/// Only fires when the parameter name can actually be resolved (from the
+/// bundled stdlib signatures, or from reflection for a function/method this
+/// codebase declares); an unresolvable callee is left alone rather than
+/// suggesting a name that might be wrong.
+#[derive(Debug)]
+pub struct BooleanArgumentTrapRule;
+
+impl Rule for BooleanArgumentTrapRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Boolean Argument Trap", Level::Help)
+            .with_description("Flags positional boolean literal arguments and suggests naming them.")
+            .with_minimum_supported_php_version(PHPVersion::PHP80)
+            .with_example(RuleUsageExample::invalid(
+                "A bare `true` passed positionally",
+                r#"
+                <?php
+
+                function redirect(string $url, bool $permanent) {}
+
+                redirect("/home", true);
+                "#,
+            ))
+    }
+
+    fn check(&self, node: Node<'_>, context: &mut LintContext<'_>) {
+        if !context.php_version.is_supported(PHPVersion::PHP80) {
+            return;
+        }
+
+        let (parameter_names, arguments) = match node {
+            Node::Call(Call::Function(call)) => {
+                let Expression::Identifier(Identifier::Local(identifier)) = call.function.as_ref() else {
+                    return;
+                };
+
+                let Some(names) = function_parameter_names(context, &identifier.value) else {
+                    return;
+                };
+
+                (names, &call.arguments)
+            }
+            Node::Call(Call::Method(call)) => {
+                let Expression::Identifier(Identifier::Local(method_name)) = &call.method else {
+                    return;
+                };
+
+                let Some(class_name) = context.resolve_class_type_of(&call.object) else {
+                    return;
+                };
+
+                let Some(names) = method_parameter_names(context, &class_name, &method_name.value) else {
+                    return;
+                };
+
+                (names, &call.arguments)
+            }
+            Node::Call(Call::StaticMethod(call)) => {
+                let Expression::Identifier(identifier) = call.class.as_ref() else {
+                    return;
+                };
+
+                let Expression::Identifier(Identifier::Local(method_name)) = &call.method else {
+                    return;
+                };
+
+                let Some(class_name) = context.resolve_class_name(identifier) else {
+                    return;
+                };
+
+                let Some(names) = method_parameter_names(context, &class_name, &method_name.value) else {
+                    return;
+                };
+
+                (names, &call.arguments)
+            }
+            _ => return,
+        };
+
+        let mut positional_index = 0usize;
+        for argument in arguments.arguments.iter() {
+            let Argument::Positional(positional) = argument else {
+                continue;
+            };
+
+            let index = positional_index;
+            positional_index += 1;
+
+            if !matches!(positional.value, Expression::Literal(Literal::True(_)) | Expression::Literal(Literal::False(_))) {
+                continue;
+            }
+
+            let Some(Some(name)) = parameter_names.get(index) else {
+                continue;
+            };
+
+            let mut plan = FixPlan::new();
+            plan.replace(
+                positional.span(),
+                format!("{}: {}", name, context.lookup_slice(positional.value.span())),
+                SafetyClassification::Safe,
+            );
+
+            context.report(
+                Issue::new(Level::Help, format!("this boolean argument is clearer as `{name}: ...`"))
+                    .with_code("best-practices/boolean-argument-trap")
+                    .with_annotation(Annotation::new(positional.span(), AnnotationKind::Primary))
+                    .with_fix(plan),
+            );
+        }
+    }
+}
+
+/// Each positional parameter's name, in order, or `None` for a parameter
+/// reflection couldn't name (so that index is skipped rather than
+/// misattributed).
+fn function_parameter_names(context: &LintContext<'_>, name: &str) -> Option<Vec<Option<String>>> {
+    if let Some(signature) = mago_php_stdlib::function_signature(name, context.php_version) {
+        return Some(signature.parameters.iter().map(|parameter| Some(parameter.name.to_string())).collect());
+    }
+
+    let function = context.codebase().get_function(name)?;
+
+    Some(function.parameters().iter().map(|parameter| Some(parameter.name.clone())).collect())
+}
+
+fn method_parameter_names(context: &LintContext<'_>, class_name: &str, method_name: &str) -> Option<Vec<Option<String>>> {
+    let class_reflection = context.codebase().get_class(class_name)?;
+    let method_reflection = class_reflection.get_method(method_name)?;
+
+    Some(method_reflection.parameters().iter().map(|parameter| Some(parameter.name.clone())).collect())
+}
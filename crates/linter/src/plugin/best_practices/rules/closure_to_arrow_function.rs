@@ -0,0 +1,81 @@
+use mago_ast::ast::*;
+use mago_ast::Node;
+use mago_fixer::FixPlan;
+use mago_fixer::SafetyClassification;
+use mago_php_version::PHPVersion;
+use mago_reporting::{Annotation, AnnotationKind, Issue, Level};
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::{RuleDefinition, RuleUsageExample};
+use crate::rule::Rule;
+
+/// Suggests an arrow function (`fn (...) => ...`) in place of a closure
+/// whose body is a single `return` statement and that only captures
+/// variables via `use (...)` (never by reference), since an arrow function
+/// captures its enclosing scope automatically and is strictly shorter.
+#[derive(Debug)]
+pub struct ClosureToArrowFunctionRule;
+
+impl Rule for ClosureToArrowFunctionRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Closure To Arrow Function", Level::Help)
+            .with_description("Suggests an arrow function for closures whose body is a single `return` with by-value captures only.")
+            .with_minimum_supported_php_version(PHPVersion::PHP74)
+            .with_example(RuleUsageExample::invalid(
+                "A closure that only returns a single expression",
+                r#"
+                <?php
+
+                $double = function ($x) {
+                    return $x * 2;
+                };
+                "#,
+            ))
+    }
+
+    fn check(&self, node: Node<'_>, context: &mut LintContext<'_>) {
+        let Node::Closure(closure) = node else {
+            return;
+        };
+
+        if !context.php_version.is_supported(PHPVersion::PHP74) {
+            return;
+        }
+
+        if closure.r#static.is_some() {
+            return;
+        }
+
+        if let Some(use_clause) = &closure.use_clause {
+            if use_clause.variables.iter().any(|variable| variable.ampersand.is_some()) {
+                return;
+            }
+        }
+
+        let [Statement::Return(r#return)] = closure.body.statements.as_slice() else {
+            return;
+        };
+
+        let Some(value) = &r#return.value else {
+            return;
+        };
+
+        let mut issue = Issue::new(Level::Help, "this closure can be written as an arrow function")
+            .with_code("best-practices/closure-to-arrow-function")
+            .with_annotation(
+                Annotation::new(closure.function.span(), AnnotationKind::Primary)
+                    .with_message("arrow functions capture the enclosing scope automatically"),
+            );
+
+        let parameters_text = context.lookup_slice(closure.parameter_list.span());
+        let value_text = context.lookup_slice(value.span());
+        let replacement = format!("fn{parameters_text} => {value_text}");
+
+        let mut plan = FixPlan::new();
+        plan.replace(closure.span(), replacement, SafetyClassification::Safe);
+        issue = issue.with_fix(plan);
+
+        context.report(issue);
+    }
+}
@@ -0,0 +1,65 @@
+use mago_ast::ast::*;
+use mago_ast::Node;
+use mago_reporting::{Annotation, AnnotationKind, Issue, Level};
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::{RuleDefinition, RuleUsageExample};
+use crate::rule::Rule;
+
+/// Flags a `catch` block with nothing in it, not even a comment explaining
+/// why the exception is being swallowed on purpose.
+///
+/// Silently discarding an exception almost always hides a bug; when it's
+/// genuinely intentional, a one-line comment costs nothing and tells the
+/// next reader it was a choice, not an oversight - so a catch block isn't
+/// flagged as long as it contains *something*, comment included.
+#[derive(Debug)]
+pub struct EmptyCatchBlockRule;
+
+impl Rule for EmptyCatchBlockRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Empty Catch Block", Level::Warning)
+            .with_description("Flags a catch block with no statements and no comment explaining why.")
+            .with_example(RuleUsageExample::invalid(
+                "An exception discarded with no explanation",
+                r#"
+                <?php
+
+                try {
+                    risky_operation();
+                } catch (Throwable $e) {
+                }
+                "#,
+            ))
+    }
+
+    fn check(&self, node: Node<'_>, context: &mut LintContext<'_>) {
+        let Node::Try(r#try) = node else {
+            return;
+        };
+
+        for clause in r#try.catch_clauses.iter() {
+            if !clause.block.statements.is_empty() {
+                continue;
+            }
+
+            let inner = context.lookup_slice(clause.block.span());
+            let body = inner.trim_start_matches('{').trim_end_matches('}').trim();
+            if !body.is_empty() {
+                // There's a comment (or something else) in here explaining
+                // the empty catch; leave it alone.
+                continue;
+            }
+
+            context.report(
+                Issue::new(Level::Warning, "this catch block silently discards the exception")
+                    .with_code("best-practices/empty-catch-block")
+                    .with_annotation(
+                        Annotation::new(clause.block.span(), AnnotationKind::Primary)
+                            .with_message("add a comment if this is intentional, or handle the exception"),
+                    ),
+            );
+        }
+    }
+}
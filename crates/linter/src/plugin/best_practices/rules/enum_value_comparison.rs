@@ -0,0 +1,85 @@
+use mago_ast::ast::*;
+use mago_ast::Node;
+use mago_reporting::{Annotation, AnnotationKind, Issue, Level};
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::{RuleDefinition, RuleUsageExample};
+use crate::rule::Rule;
+
+const COMPARISON_OPERATORS: &[&str] = &["==", "===", "!=", "!==", "<>"];
+
+/// Flags comparing a backed enum's `->value` against a scalar literal,
+/// suggesting comparing the enum case directly instead.
+///
+/// This is a common smell left over after migrating a set of class
+/// constants to a backed enum: call sites keep comparing the backing
+/// scalar out of habit, which throws away everything the enum was
+/// introduced for - exhaustiveness checking, `match` on the case itself,
+/// and no longer needing to know what the backing value even is.
+#[derive(Debug)]
+pub struct EnumValueComparisonRule;
+
+impl Rule for EnumValueComparisonRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Enum Value Comparison", Level::Help)
+            .with_description("Flags comparing a backed enum's ->value against a scalar literal instead of comparing cases.")
+            .with_example(RuleUsageExample::invalid(
+                "Comparing a backed enum's value instead of the case",
+                r#"
+                <?php
+
+                if ($status->value === 'published') {
+                }
+                "#,
+            ))
+    }
+
+    fn check(&self, node: Node<'_>, context: &mut LintContext<'_>) {
+        let Node::Binary(binary) = node else {
+            return;
+        };
+
+        if !COMPARISON_OPERATORS.contains(&context.lookup_slice(binary.operator.span())) {
+            return;
+        }
+
+        let value_access = match (binary.lhs.as_ref(), binary.rhs.as_ref()) {
+            (Expression::Access(Access::Property(access)), other) if is_scalar_literal(other) => access,
+            (other, Expression::Access(Access::Property(access))) if is_scalar_literal(other) => access,
+            _ => return,
+        };
+
+        let ClassLikeMemberSelector::Identifier(property) = &value_access.property else {
+            return;
+        };
+
+        if property.value != "value" {
+            return;
+        }
+
+        let Some(class_name) = context.resolve_class_type_of(&value_access.object) else {
+            return;
+        };
+
+        if context.codebase().get_enum(&class_name).is_none() {
+            return;
+        }
+
+        context.report(
+            Issue::new(
+                Level::Help,
+                format!("comparing `{class_name}`'s backing value - compare the enum case directly instead"),
+            )
+            .with_code("best-practices/enum-value-comparison")
+            .with_annotation(Annotation::new(binary.span(), AnnotationKind::Primary)),
+        );
+    }
+}
+
+fn is_scalar_literal(expression: &Expression) -> bool {
+    matches!(
+        expression,
+        Expression::Literal(Literal::String(_)) | Expression::Literal(Literal::Integer(_))
+    )
+}
@@ -0,0 +1,88 @@
+use mago_ast::ast::*;
+use mago_ast::Node;
+use mago_reflection::CodebaseReflection;
+use mago_reporting::{Annotation, AnnotationKind, Issue, Level};
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::{RuleDefinition, RuleUsageExample};
+use crate::rule::Rule;
+
+/// Requires a `match` on an enum-typed subject to either cover every case
+/// or provide a default (`default =>`) arm.
+///
+/// Unlike `switch`, `match` already throws an `UnhandledMatchError` at
+/// runtime for a missing case, so this rule exists purely to surface the gap
+/// at lint time instead of at whatever point in production the missing case
+/// is first hit.
+#[derive(Debug)]
+pub struct ExhaustiveEnumMatchRule;
+
+impl Rule for ExhaustiveEnumMatchRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Exhaustive Enum Match", Level::Warning)
+            .with_description("Requires a `match` over an enum-typed subject to cover every case, or include a `default` arm.")
+            .with_example(RuleUsageExample::invalid(
+                "A match missing one of the enum's cases",
+                r#"
+                <?php
+
+                match ($status) {
+                    Status::Draft => 'draft',
+                    Status::Published => 'published',
+                };
+                "#,
+            ))
+    }
+
+    fn check(&self, node: Node<'_>, context: &mut LintContext<'_>) {
+        let Node::Match(r#match) = node else {
+            return;
+        };
+
+        if r#match.arms.iter().any(|arm| matches!(arm, MatchArm::Default(_))) {
+            return;
+        }
+
+        let Some(enum_name) = context.resolve_enum_type_of(&r#match.subject) else {
+            return;
+        };
+
+        let Some(enum_reflection) = context.codebase().get_enum(&enum_name) else {
+            return;
+        };
+
+        let covered: std::collections::HashSet<&str> = r#match
+            .arms
+            .iter()
+            .filter_map(|arm| match arm {
+                MatchArm::Expression(arm) => Some(&arm.conditions),
+                _ => None,
+            })
+            .flatten()
+            .filter_map(|condition| match condition {
+                Expression::Access(Access::ClassConstant(access)) => match &access.constant {
+                    ClassLikeConstantSelector::Identifier(identifier) => Some(identifier.value.as_str()),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect();
+
+        let missing: Vec<&str> =
+            enum_reflection.cases.iter().map(|case| case.name.as_str()).filter(|case| !covered.contains(case)).collect();
+
+        if missing.is_empty() {
+            return;
+        }
+
+        let issue = Issue::new(Level::Warning, format!("match is not exhaustive over `{enum_name}`, missing: {}", missing.join(", ")))
+            .with_code("best-practices/exhaustive-enum-match")
+            .with_annotation(
+                Annotation::new(r#match.span(), AnnotationKind::Primary)
+                    .with_message("add the missing case(s), or a `default` arm"),
+            );
+
+        context.report(issue);
+    }
+}
@@ -0,0 +1,110 @@
+use mago_ast::ast::*;
+use mago_ast::Node;
+use mago_reporting::{Annotation, AnnotationKind, Issue, Level};
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::{RuleDefinition, RuleUsageExample};
+use crate::rule::Rule;
+
+/// Suggests a property type hint when a property has no declared type but
+/// every assignment to it we can see - its default value and every
+/// constructor parameter it's assigned from - agrees on a single scalar
+/// type.
+#[derive(Debug)]
+pub struct InferablePropertyTypeRule;
+
+impl Rule for InferablePropertyTypeRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Inferable Property Type", Level::Help)
+            .with_description("Suggests a type hint for an untyped property when its default and constructor assignment agree on a single type.")
+            .with_example(RuleUsageExample::invalid(
+                "An untyped property assigned from a typed parameter",
+                r#"
+                <?php
+
+                class Point
+                {
+                    public $x;
+
+                    public function __construct(float $x)
+                    {
+                        $this->x = $x;
+                    }
+                }
+                "#,
+            ))
+    }
+
+    fn check(&self, node: Node<'_>, context: &mut LintContext<'_>) {
+        let Node::Class(class) = node else {
+            return;
+        };
+
+        let constructor = class.members.iter().find_map(|member| match member {
+            ClassLikeMember::Method(method) if method.name.value.eq_ignore_ascii_case("__construct") => Some(method),
+            _ => None,
+        });
+
+        for member in &class.members {
+            let ClassLikeMember::Property(property) = member else {
+                continue;
+            };
+
+            if property.hint.is_some() {
+                continue;
+            }
+
+            for item in property.items.iter() {
+                let Some(inferred) = infer_type(item, constructor) else {
+                    continue;
+                };
+
+                let issue = Issue::new(Level::Help, format!("property `${}` could be typed `{inferred}`", item.variable().name))
+                    .with_code("best-practices/inferable-property-type")
+                    .with_annotation(Annotation::new(item.variable().span(), AnnotationKind::Primary));
+
+                context.report(issue);
+            }
+        }
+    }
+}
+
+fn infer_type(item: &PropertyItem, constructor: Option<&Method>) -> Option<String> {
+    let default_type = match item {
+        PropertyItem::Concrete(concrete) => scalar_literal_type(&concrete.value),
+        PropertyItem::Abstract(_) => None,
+    };
+
+    let constructor_type = constructor.and_then(|constructor| {
+        let name = item.variable().name.as_str();
+        constructor.parameter_list.parameters.iter().find_map(|parameter| {
+            let promoted_to_same_property = parameter.name.value == name && parameter.hint.is_some();
+            if promoted_to_same_property { parameter.hint.as_ref().map(|hint| hint_text(hint)) } else { None }
+        })
+    });
+
+    match (default_type, constructor_type) {
+        (Some(a), Some(b)) if a == b => Some(a),
+        (Some(a), None) => Some(a),
+        (None, Some(b)) => Some(b),
+        _ => None,
+    }
+}
+
+fn scalar_literal_type(expression: &Expression) -> Option<String> {
+    match expression {
+        Expression::Literal(Literal::Integer(_)) => Some("int".to_string()),
+        Expression::Literal(Literal::Float(_)) => Some("float".to_string()),
+        Expression::Literal(Literal::String(_)) => Some("string".to_string()),
+        Expression::Literal(Literal::True(_)) | Expression::Literal(Literal::False(_)) => Some("bool".to_string()),
+        _ => None,
+    }
+}
+
+fn hint_text(hint: &Hint) -> String {
+    match hint {
+        Hint::Identifier(identifier) => identifier.value().to_string(),
+        _ => String::new(),
+    }
+}
@@ -0,0 +1,73 @@
+use mago_ast::Node;
+use mago_reflection::attribute::AttributeTarget;
+use mago_reflection::attribute::AttributeTargets;
+use mago_reporting::{Annotation, AnnotationKind, Issue, Level};
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::rule::Rule;
+
+/// Flags an attribute applied to a declaration kind its own `#[Attribute(...)]`
+/// target flags don't allow, mirroring the fatal error PHP raises at
+/// instantiation time (`Attribute "X" cannot target property (allowed
+/// targets: class)`).
+#[derive(Debug)]
+pub struct InvalidAttributeTargetRule;
+
+impl Rule for InvalidAttributeTargetRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Invalid Attribute Target", Level::Error)
+            .with_description("Flags an attribute applied to a declaration kind it does not declare itself usable on.")
+    }
+
+    fn check(&self, node: Node<'_>, context: &mut LintContext<'_>) {
+        let Node::AttributeList(list) = node else {
+            return;
+        };
+
+        let Some(target) = current_target(list) else {
+            return;
+        };
+
+        for attribute in list.attributes.iter() {
+            let Some(class_name) = context.resolve_attribute_name(&attribute.name) else {
+                continue;
+            };
+
+            let Some(attribute_reflection) = context.codebase().get_class(&class_name) else {
+                continue;
+            };
+
+            let Some(allowed) = attribute_reflection.attribute_targets else {
+                continue;
+            };
+
+            if allowed.contains(target) {
+                continue;
+            }
+
+            context.report(
+                Issue::new(Level::Error, format!("`{class_name}` cannot target {target}"))
+                    .with_code("best-practices/invalid-attribute-target")
+                    .with_annotation(
+                        Annotation::new(attribute.span(), AnnotationKind::Primary)
+                            .with_message(format!("allowed targets: {allowed}")),
+                    ),
+            );
+        }
+    }
+}
+
+fn current_target(list: &mago_ast::ast::AttributeList) -> Option<AttributeTarget> {
+    match list.parent()? {
+        Node::Class(_) => Some(AttributeTarget::Class),
+        Node::Function(_) => Some(AttributeTarget::Function),
+        Node::Method(_) => Some(AttributeTarget::Method),
+        Node::Property(_) | Node::PromotedProperty(_) => Some(AttributeTarget::Property),
+        Node::Parameter(_) => Some(AttributeTarget::Parameter),
+        Node::ClassLikeConstant(_) => Some(AttributeTarget::ClassConstant),
+        Node::EnumCase(_) => Some(AttributeTarget::EnumCase),
+        _ => None,
+    }
+}
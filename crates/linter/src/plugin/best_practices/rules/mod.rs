@@ -0,0 +1,14 @@
+pub mod boolean_argument_trap;
+pub mod closure_to_arrow_function;
+pub mod empty_catch_block;
+pub mod enum_value_comparison;
+pub mod inferable_property_type;
+pub mod invalid_attribute_target;
+pub mod require_final_class;
+pub mod exhaustive_enum_match;
+pub mod overly_broad_catch;
+pub mod prefer_match_expression;
+pub mod rethrow_only_catch;
+pub mod trait_method_conflict;
+pub mod require_braces;
+pub mod side_effect_free_declarations;
@@ -0,0 +1,145 @@
+use mago_ast::ast::*;
+use mago_ast::Node;
+use mago_reporting::{Annotation, AnnotationKind, Issue, Level};
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::{RuleDefinition, RuleUsageExample};
+use crate::rule::Rule;
+use crate::throws::thrown_type_name;
+
+const BROAD_TYPES: &[&str] = &["throwable", "exception"];
+
+/// Flags `catch (\Throwable $e)` / `catch (\Exception $e)` around a `try`
+/// block that only ever throws a more specific exception directly (a
+/// `throw new SomeSpecificException(...)` this rule can see textually).
+///
+/// This only looks at `throw new X(...)` written directly inside the `try`
+/// block. A type thrown from inside a called function, or rethrown from a
+/// variable, isn't visible here: a `try` block with no direct `throw` is
+/// never flagged, and one whose direct throws are all specific is flagged
+/// even if a call inside it can throw something broader.
+#[derive(Debug)]
+pub struct OverlyBroadCatchRule;
+
+impl Rule for OverlyBroadCatchRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Overly Broad Catch", Level::Help)
+            .with_description("Flags catching Throwable/Exception when the try block only ever throws a more specific type directly.")
+            .with_example(RuleUsageExample::invalid(
+                "Catching Throwable around a single specific throw",
+                r#"
+                <?php
+
+                try {
+                    throw new InvalidArgumentException("bad input");
+                } catch (Throwable $e) {
+                    log_error($e);
+                }
+                "#,
+            ))
+    }
+
+    fn check(&self, node: Node<'_>, context: &mut LintContext<'_>) {
+        let Node::Try(r#try) = node else {
+            return;
+        };
+
+        let thrown_types = thrown_types_in(context, &r#try.block);
+        if thrown_types.is_empty() {
+            return;
+        }
+
+        for clause in r#try.catch_clauses.iter() {
+            let caught = hint_names(context, &clause.hint);
+            if !caught.iter().any(|name| BROAD_TYPES.contains(&name.to_ascii_lowercase().as_str())) {
+                continue;
+            }
+
+            if thrown_types.iter().any(|thrown| caught.iter().any(|name| names_match(name, thrown))) {
+                // The broad type is also thrown directly, so catching it
+                // broadly isn't obviously wrong here.
+                continue;
+            }
+
+            context.report(
+                Issue::new(
+                    Level::Help,
+                    format!(
+                        "this catch is broader than necessary - only {} {} thrown here",
+                        if thrown_types.len() == 1 { "is" } else { "are" },
+                        thrown_types.join(", ")
+                    ),
+                )
+                .with_code("best-practices/overly-broad-catch")
+                .with_annotation(Annotation::new(clause.hint.span(), AnnotationKind::Primary)),
+            );
+        }
+    }
+}
+
+fn thrown_types_in(context: &LintContext<'_>, block: &Block) -> Vec<String> {
+    let mut thrown = Vec::new();
+
+    for statement in block.statements.iter() {
+        collect_throws_in_statement(context, statement, &mut thrown);
+    }
+
+    thrown
+}
+
+/// Recurses into the try block's nested control-flow statements looking for
+/// a directly written `throw new X(...)`, without crossing into a nested
+/// closure's own body, and without following into a nested `try`'s `catch`
+/// or `finally` clauses - only the block actually covered by the catch
+/// clauses being checked is in scope here.
+fn collect_throws_in_statement(context: &LintContext<'_>, statement: &Statement, thrown: &mut Vec<String>) {
+    match statement {
+        Statement::Block(block) => {
+            for inner in &block.statements {
+                collect_throws_in_statement(context, inner, thrown);
+            }
+        }
+        Statement::If(r#if) => {
+            collect_throws_in_statement(context, &r#if.body, thrown);
+            for clause in &r#if.else_if_clauses {
+                collect_throws_in_statement(context, &clause.body, thrown);
+            }
+            if let Some(else_clause) = &r#if.else_clause {
+                collect_throws_in_statement(context, &else_clause.body, thrown);
+            }
+        }
+        Statement::While(r#while) => collect_throws_in_statement(context, &r#while.body, thrown),
+        Statement::DoWhile(do_while) => collect_throws_in_statement(context, &do_while.body, thrown),
+        Statement::For(r#for) => collect_throws_in_statement(context, &r#for.body, thrown),
+        Statement::Foreach(foreach) => collect_throws_in_statement(context, &foreach.body, thrown),
+        Statement::Switch(switch) => {
+            for case in switch.body.cases() {
+                for inner in case.statements() {
+                    collect_throws_in_statement(context, inner, thrown);
+                }
+            }
+        }
+        Statement::Try(r#try) => {
+            for inner in &r#try.block.statements {
+                collect_throws_in_statement(context, inner, thrown);
+            }
+        }
+        Statement::Expression(expression_statement) => {
+            if let Expression::Throw(r#throw) = expression_statement.expression.as_ref() {
+                if let Some(name) = thrown_type_name(context, &r#throw.exception) {
+                    thrown.push(name);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn hint_names(context: &LintContext<'_>, hint: &Hint) -> Vec<String> {
+    context.lookup_slice(hint.span()).split('|').map(|part| part.trim().to_string()).collect()
+}
+
+fn names_match(a: &str, b: &str) -> bool {
+    a.trim_start_matches('\\').eq_ignore_ascii_case(b.trim_start_matches('\\'))
+}
@@ -0,0 +1,86 @@
+use mago_ast::ast::*;
+use mago_ast::Node;
+use mago_php_version::PHPVersion;
+use mago_reporting::{Annotation, AnnotationKind, Issue, Level};
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::{RuleDefinition, RuleUsageExample};
+use crate::rule::Rule;
+
+/// Suggests `match` in place of a `switch` statement whose every arm does
+/// nothing but return or assign a value, with no fallthrough between cases.
+///
+/// `match` is stricter (no implicit fallthrough, uses `===`) and shorter, so
+/// this only fires when the rewrite is behavior-preserving: each `case` must
+/// end in exactly one `return`/`break`, and no case may fall through to the
+/// next one.
+#[derive(Debug)]
+pub struct PreferMatchExpressionRule;
+
+impl Rule for PreferMatchExpressionRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Prefer Match Expression", Level::Help)
+            .with_description("Suggests using `match` instead of a `switch` statement when every arm is a simple value.")
+            .with_minimum_supported_php_version(PHPVersion::PHP80)
+            .with_example(RuleUsageExample::invalid(
+                "A switch where every arm assigns and breaks",
+                r#"
+                <?php
+
+                switch ($status) {
+                    case 'draft':
+                        $label = 'Draft';
+                        break;
+                    case 'published':
+                        $label = 'Published';
+                        break;
+                    default:
+                        $label = 'Unknown';
+                        break;
+                }
+                "#,
+            ))
+    }
+
+    fn check(&self, node: Node<'_>, context: &mut LintContext<'_>) {
+        let Node::Switch(switch) = node else {
+            return;
+        };
+
+        if !context.php_version.is_supported(PHPVersion::PHP80) {
+            return;
+        }
+
+        if switch.body.cases().iter().any(|case| !is_simple_terminated_case(case)) {
+            return;
+        }
+
+        let issue = Issue::new(Level::Help, "this `switch` statement can be rewritten as a `match` expression")
+            .with_code("best-practices/prefer-match-expression")
+            .with_annotation(
+                Annotation::new(switch.switch.span, AnnotationKind::Primary)
+                    .with_message("every case here only assigns or returns, with no fallthrough"),
+            )
+            .with_note("`match` uses strict comparison and has no implicit fallthrough, which better matches this shape.");
+
+        context.report(issue);
+    }
+}
+
+/// A case is convertible when it is a lone `return`, or one or more
+/// assignment statements followed by a trailing `break`.
+fn is_simple_terminated_case(case: &SwitchCase) -> bool {
+    let statements = case.statements();
+
+    match statements.split_last() {
+        Some((Statement::Return(_), [])) => true,
+        Some((Statement::Break(_), rest)) => {
+            !rest.is_empty()
+                && rest.iter().all(|statement| {
+                    matches!(statement, Statement::Expression(expression_statement) if matches!(expression_statement.expression.as_ref(), Expression::Assignment(_)))
+                })
+        }
+        _ => false,
+    }
+}
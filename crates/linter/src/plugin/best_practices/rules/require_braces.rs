@@ -0,0 +1,89 @@
+use mago_ast::ast::*;
+use mago_ast::Node;
+use mago_fixer::FixPlan;
+use mago_fixer::SafetyClassification;
+use mago_reporting::{Annotation, AnnotationKind, Issue, Level};
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::{RuleDefinition, RuleUsageExample};
+use crate::plugin::best_practices::config::BraceStyle;
+use crate::rule::Rule;
+
+/// Enforces a consistent choice, project-wide, between `{ }` and a bare
+/// single statement as the body of `if`, `else`, `for`, `foreach`, and
+/// `while`.
+///
+/// Defaults to requiring braces, since a bare single-statement body is the
+/// classic setup for the "added a second statement, forgot to add braces"
+/// bug; `best_practices.brace_style = "never_braces"` flips the rule for
+/// teams that prefer the terser form instead.
+#[derive(Debug)]
+pub struct RequireBracesRule;
+
+impl Rule for RequireBracesRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Require Braces", Level::Warning)
+            .with_description("Requires (or forbids) braces around single-statement control structure bodies, consistently.")
+            .with_example(RuleUsageExample::invalid(
+                "A single-statement `if` body without braces",
+                r#"
+                <?php
+
+                if ($condition)
+                    do_something();
+                "#,
+            ))
+    }
+
+    fn check(&self, node: Node<'_>, context: &mut LintContext<'_>) {
+        let style = context.settings().best_practices.brace_style;
+
+        for body in bodies_of(node) {
+            let is_braced = matches!(body, Statement::Block(_));
+
+            match style {
+                BraceStyle::AlwaysBraces if !is_braced => {
+                    let mut plan = FixPlan::new();
+                    let inner = context.lookup_slice(body.span());
+                    plan.replace(body.span(), format!("{{\n    {inner}\n}}"), SafetyClassification::Safe);
+
+                    context.report(
+                        Issue::new(Level::Warning, "single-statement body without braces")
+                            .with_code("best-practices/require-braces")
+                            .with_annotation(Annotation::new(body.span(), AnnotationKind::Primary))
+                            .with_fix(plan),
+                    );
+                }
+                BraceStyle::NeverBraces if matches!(body, Statement::Block(block) if block.statements.len() == 1) => {
+                    context.report(
+                        Issue::new(Level::Warning, "braces around a single-statement body")
+                            .with_code("best-practices/require-braces")
+                            .with_annotation(Annotation::new(body.span(), AnnotationKind::Primary)),
+                    );
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+fn bodies_of(node: Node<'_>) -> Vec<&Statement> {
+    match node {
+        Node::For(r#for) => vec![&r#for.body],
+        Node::Foreach(foreach) => vec![&foreach.body],
+        Node::While(r#while) => vec![&r#while.body],
+        Node::If(r#if) => {
+            let mut bodies = vec![&r#if.body];
+            for clause in r#if.else_if_clauses.iter() {
+                bodies.push(&clause.body);
+            }
+            if let Some(else_clause) = &r#if.else_clause {
+                bodies.push(&else_clause.body);
+            }
+
+            bodies
+        }
+        _ => vec![],
+    }
+}
@@ -0,0 +1,59 @@
+use mago_ast::ast::*;
+use mago_ast::Node;
+use mago_reporting::{Annotation, AnnotationKind, Issue, Level};
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::{RuleDefinition, RuleUsageExample};
+use crate::rule::Rule;
+
+/// Requires every class that is neither `final` nor `abstract` to be
+/// declared `final`. Whether the class is extended elsewhere in the
+/// project, or declares methods for subclasses to override, is not
+/// considered.
+///
+/// Composition over inheritance: defaulting to `final` forces an explicit
+/// decision to support subclassing, rather than leaving every class open by
+/// omission.
+#[derive(Debug)]
+pub struct RequireFinalClassRule;
+
+impl Rule for RequireFinalClassRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Require Final Class", Level::Note)
+            .with_description("Requires classes to be declared `final` unless they are `abstract`.")
+            .with_example(RuleUsageExample::invalid(
+                "A concrete class open to extension by omission",
+                r#"
+                <?php
+
+                class Calculator
+                {
+                    public function add(int $a, int $b): int
+                    {
+                        return $a + $b;
+                    }
+                }
+                "#,
+            ))
+    }
+
+    fn check(&self, node: Node<'_>, context: &mut LintContext<'_>) {
+        let Node::Class(class) = node else {
+            return;
+        };
+
+        if class.modifiers.contains_final() || class.modifiers.contains_abstract() {
+            return;
+        }
+
+        context.report(
+            Issue::new(Level::Note, format!("class `{}` should be declared `final`", class.name.value))
+                .with_code("best-practices/require-final-class")
+                .with_annotation(
+                    Annotation::new(class.name.span(), AnnotationKind::Primary)
+                        .with_message("add `final`, or `abstract` if this class is meant to be extended"),
+                ),
+        );
+    }
+}
@@ -0,0 +1,81 @@
+use mago_ast::ast::*;
+use mago_ast::Node;
+use mago_fixer::FixPlan;
+use mago_fixer::SafetyClassification;
+use mago_reporting::{Annotation, AnnotationKind, Issue, Level};
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::{RuleDefinition, RuleUsageExample};
+use crate::rule::Rule;
+
+/// Flags a `catch` block whose only statement rethrows the exact exception
+/// it just caught - functionally identical to not catching it at all, so
+/// it can be removed outright.
+#[derive(Debug)]
+pub struct RethrowOnlyCatchRule;
+
+impl Rule for RethrowOnlyCatchRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Rethrow Only Catch", Level::Help)
+            .with_description("Flags a catch block that does nothing but rethrow the caught exception.")
+            .with_example(RuleUsageExample::invalid(
+                "A catch block that only rethrows",
+                r#"
+                <?php
+
+                try {
+                    risky_operation();
+                } catch (Throwable $e) {
+                    throw $e;
+                }
+                "#,
+            ))
+    }
+
+    fn check(&self, node: Node<'_>, context: &mut LintContext<'_>) {
+        let Node::Try(r#try) = node else {
+            return;
+        };
+
+        for clause in r#try.catch_clauses.iter() {
+            let Some(variable) = &clause.variable else {
+                continue;
+            };
+
+            let [Statement::Expression(expression_statement)] = clause.block.statements.as_slice() else {
+                continue;
+            };
+
+            let Expression::Throw(r#throw) = expression_statement.expression.as_ref() else {
+                continue;
+            };
+
+            let Expression::Variable(Variable::Direct(rethrown)) = r#throw.exception.as_ref() else {
+                continue;
+            };
+
+            if rethrown.name != variable.name {
+                continue;
+            }
+
+            let mut issue = Issue::new(Level::Help, "this catch block only rethrows the exception it caught")
+                .with_code("best-practices/rethrow-only-catch")
+                .with_annotation(
+                    Annotation::new(clause.span(), AnnotationKind::Primary)
+                        .with_message("remove this catch block, or handle the exception before rethrowing it"),
+                );
+
+            // A `try` needs at least one catch or a finally to be valid, so
+            // we can only drop this clause outright when something else
+            // would still be there to keep the `try` legal.
+            if r#try.catch_clauses.len() > 1 || r#try.finally_clause.is_some() {
+                let mut plan = FixPlan::new();
+                plan.replace(clause.span(), String::new(), SafetyClassification::Safe);
+                issue = issue.with_fix(plan);
+            }
+
+            context.report(issue);
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use mago_ast::ast::*;
+use mago_ast::Node;
+use mago_reporting::{Annotation, AnnotationKind, Issue, Level};
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::{RuleDefinition, RuleUsageExample};
+use crate::rule::Rule;
+
+/// Flags a file that both declares symbols (a class, interface, trait,
+/// enum, or function) and executes side-effecting statements at the top
+/// level, per PSR-1's symbol/side-effect separation rule.
+///
+/// Mixing the two means the file can't be autoloaded without also running
+/// whatever the side effect is, which defeats the point of autoloading and
+/// makes the file's behavior depend on include order. A project's
+/// genuine bootstrap files (which are expected to run code) can be
+/// excluded via `best_practices.side_effect_allowed_in` path patterns.
+#[derive(Debug)]
+pub struct SideEffectFreeDeclarationsRule;
+
+impl Rule for SideEffectFreeDeclarationsRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Side Effect Free Declarations", Level::Warning)
+            .with_description("Flags a file that both declares symbols and executes side-effecting top-level statements (PSR-1).")
+            .with_example(RuleUsageExample::invalid(
+                "A class declaration alongside a top-level side effect",
+                r#"
+                <?php
+
+                class Greeter {}
+
+                echo "loaded\n";
+                "#,
+            ))
+    }
+
+    fn check(&self, node: Node<'_>, context: &mut LintContext<'_>) {
+        let Node::Program(program) = node else {
+            return;
+        };
+
+        if is_allowed_bootstrap_file(context) {
+            return;
+        }
+
+        let declares_a_symbol = program.statements.iter().any(declares_symbol);
+        if !declares_a_symbol {
+            return;
+        }
+
+        for statement in program.statements.iter() {
+            if !is_side_effect(statement) {
+                continue;
+            }
+
+            context.report(
+                Issue::new(Level::Warning, "this file declares symbols and also executes a side effect at the top level")
+                    .with_code("best-practices/side-effect-free-declarations")
+                    .with_annotation(
+                        Annotation::new(statement.span(), AnnotationKind::Primary)
+                            .with_message("move this to a bootstrap file, or move the declaration to its own file"),
+                    ),
+            );
+        }
+    }
+}
+
+fn is_allowed_bootstrap_file(context: &LintContext<'_>) -> bool {
+    let path = context.file_path();
+
+    context.settings().best_practices.side_effect_allowed_in.iter().any(|pattern| matches_path_pattern(pattern, path))
+}
+
+fn matches_path_pattern(pattern: &str, path: &str) -> bool {
+    match pattern.strip_suffix('*') {
+        Some(prefix) => path.starts_with(prefix),
+        None => path == pattern,
+    }
+}
+
+fn declares_symbol(statement: &Statement) -> bool {
+    matches!(statement, Statement::Class(_) | Statement::Interface(_) | Statement::Trait(_) | Statement::Enum(_) | Statement::Function(_))
+}
+
+/// Statements that PSR-1 allows even in a declaration file, because they
+/// don't run anything: namespace/use declarations, `declare()`, top-level
+/// `const` declarations, empty statements, and inline whitespace left
+/// between the opening tag and the first declaration.
+fn is_declaration_scaffolding(statement: &Statement) -> bool {
+    match statement {
+        Statement::Namespace(_) | Statement::Use(_) | Statement::Declare(_) | Statement::ConstantDeclaration(_) => true,
+        Statement::Inline(inline) => inline.value.trim().is_empty(),
+        Statement::Noop(_) => true,
+        _ => false,
+    }
+}
+
+fn is_side_effect(statement: &Statement) -> bool {
+    !declares_symbol(statement) && !is_declaration_scaffolding(statement)
+}
@@ -0,0 +1,97 @@
+use std::collections::HashMap;
+
+use mago_ast::ast::*;
+use mago_ast::Node;
+use mago_reflection::CodebaseReflection;
+use mago_reporting::{Annotation, AnnotationKind, Issue, Level};
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::{RuleDefinition, RuleUsageExample};
+use crate::rule::Rule;
+
+/// Flags a class that composes two or more traits declaring the same
+/// method, without an `insteadof`/`as` adaptation resolving the conflict.
+///
+/// PHP itself raises a fatal "trait method collision" error for this; this
+/// rule exists to surface it at lint time rather than at whatever point
+/// autoloading first touches the class.
+#[derive(Debug)]
+pub struct TraitMethodConflictRule;
+
+impl Rule for TraitMethodConflictRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Trait Method Conflict", Level::Error)
+            .with_description("Flags classes composing traits that declare the same method without resolving the conflict.")
+            .with_example(RuleUsageExample::invalid(
+                "Two traits declaring the same method, used without `insteadof`",
+                r#"
+                <?php
+
+                trait A { public function greet(): string { return 'a'; } }
+                trait B { public function greet(): string { return 'b'; } }
+
+                class C
+                {
+                    use A, B;
+                }
+                "#,
+            ))
+    }
+
+    fn check(&self, node: Node<'_>, context: &mut LintContext<'_>) {
+        let Node::Class(class) = node else {
+            return;
+        };
+
+        let mut resolved = std::collections::HashSet::new();
+        for member in &class.members {
+            let ClassLikeMember::TraitUse(trait_use) = member else {
+                continue;
+            };
+
+            if let TraitUseSpecification::Block { adaptations, .. } = &trait_use.specification {
+                for adaptation in adaptations.iter() {
+                    if let TraitUseAdaptation::Precedence { method, .. } = adaptation {
+                        resolved.insert(method.method_name.value.to_ascii_lowercase());
+                    }
+                    if let TraitUseAdaptation::Alias { method, .. } = adaptation {
+                        resolved.insert(method.method_name.value.to_ascii_lowercase());
+                    }
+                }
+            }
+        }
+
+        let mut declared_by: HashMap<String, Vec<&str>> = HashMap::new();
+        for member in &class.members {
+            let ClassLikeMember::TraitUse(trait_use) = member else {
+                continue;
+            };
+
+            for trait_name in trait_use.trait_names.iter() {
+                let Some(trait_reflection) = context.codebase().get_trait(trait_name.value()) else {
+                    continue;
+                };
+
+                for method_name in trait_reflection.method_names() {
+                    declared_by.entry(method_name.to_ascii_lowercase()).or_default().push(trait_name.value());
+                }
+            }
+        }
+
+        for (method, traits) in declared_by {
+            if traits.len() < 2 || resolved.contains(&method) {
+                continue;
+            }
+
+            context.report(
+                Issue::new(Level::Error, format!("method `{method}` is declared by multiple traits: {}", traits.join(", ")))
+                    .with_code("best-practices/trait-method-conflict")
+                    .with_annotation(
+                        Annotation::new(class.name.span(), AnnotationKind::Primary)
+                            .with_message("resolve this with `insteadof`/`as` in the `use` block"),
+                    ),
+            );
+        }
+    }
+}
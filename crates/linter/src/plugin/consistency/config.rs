@@ -0,0 +1,92 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// A category of class member, used by
+/// [`crate::plugin::consistency::rules::member_order::MemberOrderRule`] to
+/// check and enforce ordering.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MemberKind {
+    Constant,
+    Property,
+    Constructor,
+    PublicMethod,
+    ProtectedMethod,
+    PrivateMethod,
+}
+
+/// Configuration for the `consistency` plugin.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConsistencyConfig {
+    /// The order [`MemberOrderRule`](crate::plugin::consistency::rules::member_order::MemberOrderRule)
+    /// expects class members to appear in. A class member of a kind absent
+    /// from this list ranks after every listed kind.
+    #[serde(default = "default_member_order")]
+    pub member_order: Vec<MemberKind>,
+    /// The visibility order
+    /// [`PropertyPromotionOrderRule`](crate::plugin::consistency::rules::property_promotion_order::PropertyPromotionOrderRule)
+    /// expects promoted constructor parameters to be grouped by.
+    #[serde(default = "default_promoted_parameter_visibility_order")]
+    pub promoted_parameter_visibility_order: Vec<String>,
+    /// Whether required constructor parameters must come before optional
+    /// ones, checked independently of visibility grouping.
+    #[serde(default = "default_true")]
+    pub require_required_parameters_before_optional: bool,
+}
+
+impl Default for ConsistencyConfig {
+    fn default() -> Self {
+        Self {
+            member_order: default_member_order(),
+            promoted_parameter_visibility_order: default_promoted_parameter_visibility_order(),
+            require_required_parameters_before_optional: true,
+        }
+    }
+}
+
+impl ConsistencyConfig {
+    /// The position `kind` should appear in, or `usize::MAX` if `kind` was
+    /// left out of `member_order`, so that it ranks after every listed kind.
+    pub fn rank_of(&self, kind: MemberKind) -> usize {
+        self.member_order.iter().position(|configured| *configured == kind).unwrap_or(usize::MAX)
+    }
+
+    /// The position `visibility` (`"public"`, `"protected"`, or
+    /// `"private"`) should appear in, or `usize::MAX` if it was left out of
+    /// `promoted_parameter_visibility_order`.
+    pub fn visibility_rank(&self, visibility: &str) -> usize {
+        self.promoted_parameter_visibility_order.iter().position(|configured| configured == visibility).unwrap_or(usize::MAX)
+    }
+}
+
+fn default_member_order() -> Vec<MemberKind> {
+    vec![
+        MemberKind::Constant,
+        MemberKind::Property,
+        MemberKind::Constructor,
+        MemberKind::PublicMethod,
+        MemberKind::ProtectedMethod,
+        MemberKind::PrivateMethod,
+    ]
+}
+
+fn default_promoted_parameter_visibility_order() -> Vec<String> {
+    vec!["public".to_string(), "protected".to_string(), "private".to_string()]
+}
+
+fn default_true() -> bool {
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_kind_left_out_of_the_configured_order_ranks_last() {
+        let config = ConsistencyConfig { member_order: vec![MemberKind::Property], ..ConsistencyConfig::default() };
+
+        assert_eq!(config.rank_of(MemberKind::Property), 0);
+        assert_eq!(config.rank_of(MemberKind::Constant), usize::MAX);
+    }
+}
@@ -0,0 +1,28 @@
+use crate::plugin::LintPlugin;
+use crate::plugin::consistency::rules::member_order::MemberOrderRule;
+use crate::plugin::consistency::rules::modifier_order::ModifierOrderRule;
+use crate::plugin::consistency::rules::property_promotion_order::PropertyPromotionOrderRule;
+use crate::rule::Rule;
+
+pub mod config;
+pub mod rules;
+
+/// Structural consistency checks that don't affect correctness but keep a
+/// codebase predictable to navigate: member ordering, modifier ordering,
+/// and parameter ordering.
+#[derive(Debug)]
+pub struct ConsistencyPlugin;
+
+impl LintPlugin for ConsistencyPlugin {
+    fn get_name(&self) -> &'static str {
+        "consistency"
+    }
+
+    fn is_enabled_by_default(&self) -> bool {
+        false
+    }
+
+    fn get_rules(&self) -> Vec<Box<dyn Rule>> {
+        vec![Box::new(MemberOrderRule), Box::new(ModifierOrderRule), Box::new(PropertyPromotionOrderRule)]
+    }
+}
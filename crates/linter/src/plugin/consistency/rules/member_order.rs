@@ -0,0 +1,135 @@
+use mago_ast::ast::*;
+use mago_ast::transform::Transform;
+use mago_ast::Node;
+use mago_fixer::FixPlan;
+use mago_fixer::SafetyClassification;
+use mago_reporting::{Annotation, AnnotationKind, Issue, Level};
+use mago_refactor::reorder::gaps_are_safe_to_discard;
+use mago_refactor::reorder::plan_reorder;
+use mago_refactor::reorder::ReorderableItem;
+use mago_span::HasSpan;
+use mago_span::Span;
+
+use crate::context::LintContext;
+use crate::definition::{RuleDefinition, RuleUsageExample};
+use crate::plugin::consistency::config::MemberKind;
+use crate::rule::Rule;
+
+/// Enforces a consistent order for class members - constants, then
+/// properties, then the constructor, then methods grouped from most to
+/// least visible - per `consistency.member_order`.
+///
+/// A stable member order means a reader can jump to "the constructor" or
+/// "the private helpers" without scanning the whole class, and code review
+/// diffs stay small instead of shuffling members around incidentally.
+#[derive(Debug)]
+pub struct MemberOrderRule;
+
+impl Rule for MemberOrderRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Member Order", Level::Note)
+            .with_description("Enforces a consistent order for class members (constants, properties, constructor, then methods by visibility).")
+            .with_example(RuleUsageExample::invalid(
+                "A property declared after a method",
+                r#"
+                <?php
+
+                class Example
+                {
+                    public function run(): void {}
+
+                    private string $name;
+                }
+                "#,
+            ))
+    }
+
+    fn check(&self, node: Node<'_>, context: &mut LintContext<'_>) {
+        let Node::Class(class) = node else {
+            return;
+        };
+
+        if class.members.len() < 2 {
+            return;
+        }
+
+        let config = context.settings().consistency.clone();
+
+        let mut ranked = Vec::with_capacity(class.members.len());
+        for member in class.members.iter() {
+            let Some(kind) = classify(member) else {
+                // A member we don't categorize (trait use, enum case, ...)
+                // breaks our ability to reason about a single linear order,
+                // so skip ordering checks for this class entirely.
+                return;
+            };
+
+            ranked.push((config.rank_of(kind), member));
+        }
+
+        if ranked.windows(2).all(|pair| pair[0].0 <= pair[1].0) {
+            return;
+        }
+
+        let mut issue = Issue::new(Level::Note, "class members are not ordered consistently")
+            .with_code("consistency/member-order")
+            .with_annotation(
+                Annotation::new(class.name.span(), AnnotationKind::Primary)
+                    .with_message("expected constants, then properties, then the constructor, then methods by visibility"),
+            );
+
+        let gaps = gaps_between(context, class.members.iter().map(HasSpan::span));
+        if gaps_are_safe_to_discard(gaps.iter().map(String::as_str)) {
+            // Each member keeps the separator that followed it in the
+            // original source, then we sort by rank; `plan_reorder`'s sort
+            // is stable, so a constant key here just joins the items in the
+            // order we've already put them in.
+            let mut items = ranked
+                .into_iter()
+                .enumerate()
+                .map(|(index, (rank, member))| {
+                    let item = ReorderableItem {
+                        full_span: member.span(),
+                        text: context.lookup_slice(member.span()).to_string(),
+                        trailing_separator: gaps.get(index).cloned().unwrap_or_default(),
+                    };
+
+                    (rank, item)
+                })
+                .collect::<Vec<_>>();
+            items.sort_by_key(|(rank, _)| *rank);
+
+            let items = items.into_iter().map(|(_, item)| item).collect::<Vec<_>>();
+
+            if let Some(Transform::Replace { span, replacement }) = plan_reorder(items, |_| 0) {
+                let mut plan = FixPlan::new();
+                plan.replace(span, replacement, SafetyClassification::Safe);
+                issue = issue.with_fix(plan);
+            }
+        }
+
+        context.report(issue);
+    }
+}
+
+fn classify(member: &ClassLikeMember) -> Option<MemberKind> {
+    match member {
+        ClassLikeMember::Constant(_) => Some(MemberKind::Constant),
+        ClassLikeMember::Property(_) => Some(MemberKind::Property),
+        ClassLikeMember::Method(method) if method.name.value.eq_ignore_ascii_case("__construct") => Some(MemberKind::Constructor),
+        ClassLikeMember::Method(method) if method.modifiers.contains_private() => Some(MemberKind::PrivateMethod),
+        ClassLikeMember::Method(method) if method.modifiers.contains_protected() => Some(MemberKind::ProtectedMethod),
+        ClassLikeMember::Method(_) => Some(MemberKind::PublicMethod),
+        _ => None,
+    }
+}
+
+/// The source text between each consecutive pair of `spans`, in original
+/// order (one shorter than `spans`).
+fn gaps_between(context: &LintContext<'_>, spans: impl Iterator<Item = Span>) -> Vec<String> {
+    spans
+        .collect::<Vec<_>>()
+        .windows(2)
+        .map(|pair| context.lookup_slice(Span::new(pair[0].file_id, pair[0].end, pair[1].start)).to_string())
+        .collect()
+}
@@ -0,0 +1,3 @@
+pub mod member_order;
+pub mod modifier_order;
+pub mod property_promotion_order;
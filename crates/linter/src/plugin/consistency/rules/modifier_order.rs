@@ -0,0 +1,113 @@
+use mago_ast::ast::*;
+use mago_ast::Node;
+use mago_fixer::FixPlan;
+use mago_fixer::SafetyClassification;
+use mago_reporting::{Annotation, AnnotationKind, Issue, Level};
+use mago_span::HasSpan;
+use mago_span::Span;
+
+use crate::context::LintContext;
+use crate::definition::{RuleDefinition, RuleUsageExample};
+use crate::modifiers::leading_modifiers;
+use crate::modifiers::modifier_rank;
+use crate::rule::Rule;
+
+/// Requires explicit visibility on every method, property, and constant
+/// (no implicit `public`), and enforces
+/// [`crate::modifiers::CANONICAL_MODIFIER_ORDER`] on whatever modifiers are
+/// present.
+#[derive(Debug)]
+pub struct ModifierOrderRule;
+
+impl Rule for ModifierOrderRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Modifier Order", Level::Warning)
+            .with_description("Requires explicit visibility and canonical modifier order on methods, properties, and constants.")
+            .with_example(RuleUsageExample::invalid(
+                "A property with implicit visibility and modifiers out of order",
+                r#"
+                <?php
+
+                class Counter
+                {
+                    static private int $value = 0;
+                }
+                "#,
+            ))
+    }
+
+    fn check(&self, node: Node<'_>, context: &mut LintContext<'_>) {
+        let members: &[ClassLikeMember] = match node {
+            Node::Class(class) => &class.members,
+            Node::Trait(r#trait) => &r#trait.members,
+            Node::Enum(r#enum) => &r#enum.members,
+            _ => return,
+        };
+
+        for member in members {
+            let (kind, has_explicit_visibility, span) = match member {
+                ClassLikeMember::Method(method) => {
+                    ("method", method.modifiers.contains_private() || method.modifiers.contains_protected(), method.span())
+                }
+                ClassLikeMember::Property(property) => (
+                    "property",
+                    property.modifiers.contains_private() || property.modifiers.contains_protected(),
+                    property.span(),
+                ),
+                ClassLikeMember::Constant(constant) => (
+                    "constant",
+                    constant.modifiers.contains_private() || constant.modifiers.contains_protected(),
+                    constant.span(),
+                ),
+                _ => continue,
+            };
+
+            check_member(context, kind, has_explicit_visibility, span);
+        }
+    }
+}
+
+fn check_member(context: &mut LintContext<'_>, kind: &str, has_explicit_non_public_visibility: bool, span: Span) {
+    let text = context.lookup_slice(span);
+    let tokens = leading_modifiers(text);
+
+    let has_explicit_visibility =
+        has_explicit_non_public_visibility || tokens.iter().any(|(_, _, word)| word.eq_ignore_ascii_case("public"));
+
+    if !has_explicit_visibility {
+        let insertion = Span::new(span.file_id, span.start, span.start);
+
+        let mut plan = FixPlan::new();
+        plan.replace(insertion, "public ".to_string(), SafetyClassification::Safe);
+
+        context.report(
+            Issue::new(Level::Warning, format!("this {kind} has no explicit visibility - PHP defaults it to `public`"))
+                .with_code("consistency/modifier-order")
+                .with_annotation(Annotation::new(span, AnnotationKind::Primary))
+                .with_fix(plan),
+        );
+    }
+
+    let ranks: Vec<usize> = tokens.iter().filter_map(|(_, _, word)| modifier_rank(word)).collect();
+    if ranks.windows(2).all(|pair| pair[0] <= pair[1]) {
+        return;
+    }
+
+    let mut sorted = tokens.clone();
+    sorted.sort_by_key(|(_, _, word)| modifier_rank(word).unwrap_or(usize::MAX));
+    let replacement = sorted.iter().map(|(_, _, word)| *word).collect::<Vec<_>>().join(" ");
+
+    let (first_start, _, _) = *tokens.first().expect("ranks non-empty implies tokens non-empty");
+    let (_, last_end, _) = *tokens.last().expect("ranks non-empty implies tokens non-empty");
+    let modifiers_span = Span::new(span.file_id, span.start.saturating_add(first_start), span.start.saturating_add(last_end));
+
+    let mut plan = FixPlan::new();
+    plan.replace(modifiers_span, replacement, SafetyClassification::Safe);
+
+    context.report(
+        Issue::new(Level::Warning, format!("this {kind}'s modifiers are not in canonical order"))
+            .with_code("consistency/modifier-order")
+            .with_annotation(Annotation::new(modifiers_span, AnnotationKind::Primary))
+            .with_fix(plan),
+    );
+}
@@ -0,0 +1,156 @@
+use mago_ast::ast::*;
+use mago_ast::transform::Transform;
+use mago_ast::Node;
+use mago_fixer::FixPlan;
+use mago_fixer::SafetyClassification;
+use mago_reporting::{Annotation, AnnotationKind, Issue, Level};
+use mago_refactor::reorder::gaps_are_safe_to_discard;
+use mago_refactor::reorder::plan_reorder;
+use mago_refactor::reorder::ReorderableItem;
+use mago_span::HasSpan;
+use mago_span::Span;
+
+use crate::context::LintContext;
+use crate::definition::{RuleDefinition, RuleUsageExample};
+use crate::plugin::consistency::config::ConsistencyConfig;
+use crate::rule::Rule;
+
+/// Enforces ordering within a constructor's parameter list: required
+/// parameters before optional ones, and promoted parameters grouped by
+/// visibility, per `consistency.promoted_parameter_visibility_order`.
+#[derive(Debug)]
+pub struct PropertyPromotionOrderRule;
+
+impl Rule for PropertyPromotionOrderRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Property Promotion Order", Level::Note)
+            .with_description("Enforces required-before-optional and visibility-grouped ordering for promoted constructor parameters.")
+            .with_example(RuleUsageExample::invalid(
+                "An optional parameter declared before a required one",
+                r#"
+                <?php
+
+                class Point
+                {
+                    public function __construct(
+                        public float $y = 0.0,
+                        public float $x,
+                    ) {}
+                }
+                "#,
+            ))
+    }
+
+    fn check(&self, node: Node<'_>, context: &mut LintContext<'_>) {
+        let Node::Method(method) = node else {
+            return;
+        };
+
+        if !method.name.value.eq_ignore_ascii_case("__construct") {
+            return;
+        }
+
+        let parameters = &method.parameter_list.parameters;
+        if parameters.len() < 2 {
+            return;
+        }
+
+        let config = context.settings().consistency.clone();
+
+        if config.require_required_parameters_before_optional {
+            if let Some(index) = first_out_of_order_by_optionality(parameters) {
+                report(context, &config, parameters, index, "required parameters must come before optional ones");
+                return;
+            }
+        }
+
+        if let Some(index) = first_out_of_order_by_visibility(parameters, &config) {
+            report(context, &config, parameters, index, "promoted parameters must be grouped by visibility");
+        }
+    }
+}
+
+fn first_out_of_order_by_optionality(parameters: &[FunctionLikeParameter]) -> Option<usize> {
+    parameters.windows(2).position(|pair| pair[0].default_value.is_some() && pair[1].default_value.is_none()).map(|index| index + 1)
+}
+
+fn first_out_of_order_by_visibility(parameters: &[FunctionLikeParameter], config: &ConsistencyConfig) -> Option<usize> {
+    let mut last_rank = 0;
+
+    for (index, parameter) in parameters.iter().enumerate() {
+        if parameter.modifiers.is_empty() {
+            continue;
+        }
+
+        let rank = config.visibility_rank(visibility_of(parameter));
+        if rank < last_rank {
+            return Some(index);
+        }
+        last_rank = rank;
+    }
+
+    None
+}
+
+fn visibility_of(parameter: &FunctionLikeParameter) -> &'static str {
+    if parameter.modifiers.contains_private() {
+        "private"
+    } else if parameter.modifiers.contains_protected() {
+        "protected"
+    } else {
+        "public"
+    }
+}
+
+fn report(
+    context: &mut LintContext<'_>,
+    config: &ConsistencyConfig,
+    parameters: &[FunctionLikeParameter],
+    index: usize,
+    message: &str,
+) {
+    let mut issue = Issue::new(Level::Note, message)
+        .with_code("consistency/property-promotion-order")
+        .with_annotation(Annotation::new(parameters[index].span(), AnnotationKind::Primary));
+
+    let gaps = gaps_between(context, parameters.iter().map(HasSpan::span));
+    if gaps_are_safe_to_discard(gaps.iter().map(String::as_str)) {
+        let mut ranked = parameters
+            .iter()
+            .enumerate()
+            .map(|(position, parameter)| {
+                let visibility_rank =
+                    if parameter.modifiers.is_empty() { usize::MAX } else { config.visibility_rank(visibility_of(parameter)) };
+
+                let item = ReorderableItem {
+                    full_span: parameter.span(),
+                    text: context.lookup_slice(parameter.span()).to_string(),
+                    trailing_separator: gaps.get(position).cloned().unwrap_or_default(),
+                };
+
+                ((parameter.default_value.is_some(), visibility_rank), item)
+            })
+            .collect::<Vec<_>>();
+        ranked.sort_by_key(|(key, _)| *key);
+
+        let items = ranked.into_iter().map(|(_, item)| item).collect::<Vec<_>>();
+
+        if let Some(Transform::Replace { span, replacement }) = plan_reorder(items, |_| 0) {
+            let mut plan = FixPlan::new();
+            plan.replace(span, replacement, SafetyClassification::Safe);
+            issue = issue.with_fix(plan);
+        }
+    }
+
+    context.report(issue);
+}
+
+/// The source text between each consecutive pair of `spans`, in original
+/// order (one shorter than `spans`).
+fn gaps_between(context: &LintContext<'_>, spans: impl Iterator<Item = Span>) -> Vec<String> {
+    spans
+        .collect::<Vec<_>>()
+        .windows(2)
+        .map(|pair| context.lookup_slice(Span::new(pair[0].file_id, pair[0].end, pair[1].start)).to_string())
+        .collect()
+}
@@ -0,0 +1,36 @@
+use crate::plugin::LintPlugin;
+use crate::plugin::correctness::rules::call_argument_validation::CallArgumentValidationRule;
+use crate::plugin::correctness::rules::duplicate_array_key::DuplicateArrayKeyRule;
+use crate::plugin::correctness::rules::list_destructuring_mismatch::ListDestructuringMismatchRule;
+use crate::plugin::correctness::rules::undefined_array_shape_key::UndefinedArrayShapeKeyRule;
+use crate::plugin::correctness::rules::void_result_used::VoidResultUsedRule;
+use crate::rule::Rule;
+
+pub mod rules;
+
+/// Rules that check a piece of code against a known contract (a function's
+/// signature, a type) rather than a style or performance preference; a
+/// violation here is a likely bug, though PHP itself does not always reject
+/// or warn about it (a duplicate array key is silently overwritten).
+#[derive(Debug)]
+pub struct CorrectnessPlugin;
+
+impl LintPlugin for CorrectnessPlugin {
+    fn get_name(&self) -> &'static str {
+        "correctness"
+    }
+
+    fn is_enabled_by_default(&self) -> bool {
+        true
+    }
+
+    fn get_rules(&self) -> Vec<Box<dyn Rule>> {
+        vec![
+            Box::new(CallArgumentValidationRule),
+            Box::new(DuplicateArrayKeyRule),
+            Box::new(ListDestructuringMismatchRule),
+            Box::new(UndefinedArrayShapeKeyRule),
+            Box::new(VoidResultUsedRule),
+        ]
+    }
+}
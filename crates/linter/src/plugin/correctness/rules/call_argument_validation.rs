@@ -0,0 +1,101 @@
+use mago_ast::ast::*;
+use mago_ast::Node;
+use mago_reporting::{Annotation, AnnotationKind, Issue, Level};
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::{RuleDefinition, RuleUsageExample};
+use crate::rule::Rule;
+
+/// Validates call sites against the bundled PHP stdlib signature database:
+/// too few/too many positional arguments, an unknown named argument, and a
+/// positional argument following a named one.
+///
+/// This only fires for functions the database has an entry for; project
+/// functions and anything else outside the stdlib are left to the
+/// reflection-backed rule this one will eventually share a code path with.
+#[derive(Debug)]
+pub struct CallArgumentValidationRule;
+
+impl Rule for CallArgumentValidationRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Call Argument Validation", Level::Error)
+            .with_description("Flags calls to known stdlib functions with the wrong number or kind of arguments.")
+            .with_example(RuleUsageExample::invalid(
+                "Too many arguments for `strlen`",
+                r#"
+                <?php
+
+                strlen("hello", "world");
+                "#,
+            ))
+    }
+
+    fn check(&self, node: Node<'_>, context: &mut LintContext<'_>) {
+        let Node::Call(Call::Function(call)) = node else {
+            return;
+        };
+
+        let Expression::Identifier(Identifier::Local(identifier)) = call.function.as_ref() else {
+            return;
+        };
+
+        let Some(signature) = mago_php_stdlib::function_signature(&identifier.value, context.php_version) else {
+            return;
+        };
+
+        let mut seen_named = false;
+        let mut positional_count = 0usize;
+
+        for argument in call.arguments.arguments.iter() {
+            match argument {
+                Argument::Positional(positional) => {
+                    if seen_named {
+                        context.report(
+                            Issue::new(Level::Error, "positional argument follows a named argument")
+                                .with_code("correctness/call-argument-validation")
+                                .with_annotation(Annotation::new(positional.span(), AnnotationKind::Primary)),
+                        );
+                    }
+
+                    positional_count += 1;
+                }
+                Argument::Named(named) => {
+                    seen_named = true;
+
+                    if !signature.parameters.iter().any(|parameter| parameter.name == named.name.value) {
+                        context.report(
+                            Issue::new(Level::Error, format!("`{}()` has no parameter named `{}`", signature.name, named.name.value))
+                                .with_code("correctness/call-argument-validation")
+                                .with_annotation(Annotation::new(named.name.span(), AnnotationKind::Primary)),
+                        );
+                    }
+                }
+            }
+        }
+
+        let required_count = signature.parameters.iter().filter(|parameter| !parameter.optional).count();
+        let has_variadic = signature.parameters.iter().any(|parameter| parameter.variadic);
+        let max_count = signature.parameters.len();
+
+        if positional_count < required_count {
+            context.report(
+                Issue::new(
+                    Level::Error,
+                    format!("`{}()` expects at least {required_count} argument(s), {positional_count} given", signature.name),
+                )
+                .with_code("correctness/call-argument-validation")
+                .with_annotation(Annotation::new(call.span(), AnnotationKind::Primary)),
+            );
+        } else if !has_variadic && positional_count > max_count {
+            context.report(
+                Issue::new(
+                    Level::Error,
+                    format!("`{}()` expects at most {max_count} argument(s), {positional_count} given", signature.name),
+                )
+                .with_code("correctness/call-argument-validation")
+                .with_annotation(Annotation::new(call.span(), AnnotationKind::Primary)),
+            );
+        }
+    }
+}
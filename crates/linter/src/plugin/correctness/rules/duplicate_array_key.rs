@@ -0,0 +1,108 @@
+use std::collections::HashMap;
+
+use mago_ast::ast::*;
+use mago_ast::Node;
+use mago_reporting::{Annotation, AnnotationKind, Issue, Level, RelatedInformation};
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::{RuleDefinition, RuleUsageExample};
+use crate::rule::Rule;
+
+/// Flags an array literal with two entries for the same key: the earlier
+/// entry is silently overwritten at runtime, which is rarely what the
+/// author intended in a config/options array.
+///
+/// Only keys resolvable without running the program are checked: literal
+/// strings and integers, plus class constants whose value constant-folds to
+/// one of those. A key built from a variable or a function call isn't
+/// flagged, since we can't know whether it collides with another entry.
+#[derive(Debug)]
+pub struct DuplicateArrayKeyRule;
+
+impl Rule for DuplicateArrayKeyRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Duplicate Array Key", Level::Warning)
+            .with_description("Flags array literals with two entries for the same key; the earlier one is silently overwritten.")
+            .with_example(RuleUsageExample::invalid(
+                "The same string key used twice",
+                r#"
+                <?php
+
+                $config = [
+                    'timeout' => 30,
+                    'retries' => 3,
+                    'timeout' => 60,
+                ];
+                "#,
+            ))
+    }
+
+    fn check(&self, node: Node<'_>, context: &mut LintContext<'_>) {
+        let Node::Array(array) = node else {
+            return;
+        };
+
+        let mut seen: HashMap<ArrayKey, mago_span::Span> = HashMap::new();
+
+        for element in array.elements.iter() {
+            let ArrayElement::KeyValue(element) = element else {
+                continue;
+            };
+
+            let Some(key) = constant_key(context, &element.key) else {
+                continue;
+            };
+
+            if let Some(previous_span) = seen.insert(key.clone(), element.key.span()) {
+                context.report(
+                    Issue::new(Level::Warning, format!("duplicate array key {}", key.describe()))
+                        .with_code("correctness/duplicate-array-key")
+                        .with_annotation(
+                            Annotation::new(element.key.span(), AnnotationKind::Primary)
+                                .with_message("this overwrites the earlier entry"),
+                        )
+                        .with_related(RelatedInformation { span: previous_span, message: "earlier entry with the same key".to_string() }),
+                );
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum ArrayKey {
+    String(String),
+    Integer(i64),
+}
+
+impl ArrayKey {
+    fn describe(&self) -> String {
+        match self {
+            ArrayKey::String(value) => format!("'{value}'"),
+            ArrayKey::Integer(value) => value.to_string(),
+        }
+    }
+}
+
+fn constant_key(context: &LintContext<'_>, expression: &Expression) -> Option<ArrayKey> {
+    match expression {
+        Expression::Literal(Literal::String(literal)) => Some(ArrayKey::String(literal.value.to_string())),
+        Expression::Literal(Literal::Integer(literal)) => Some(ArrayKey::Integer(literal.value)),
+        Expression::Access(Access::ClassConstant(access)) => {
+            let ClassLikeConstantSelector::Identifier(constant_name) = &access.constant else {
+                return None;
+            };
+
+            let class_name = context.resolve_class_type_of(&access.class)?;
+            let class_reflection = context.codebase().get_class(&class_name)?;
+            let constant_value = class_reflection.get_constant(&constant_name.value)?.constant_fold()?;
+
+            match constant_value {
+                mago_reflection::ConstantValue::String(value) => Some(ArrayKey::String(value)),
+                mago_reflection::ConstantValue::Integer(value) => Some(ArrayKey::Integer(value)),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,170 @@
+use mago_ast::ast::*;
+use mago_ast::Node;
+use mago_reporting::{Annotation, AnnotationKind, Issue, Level};
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::{RuleDefinition, RuleUsageExample};
+use crate::rule::Rule;
+
+/// Flags `list()` / `[...] = ...` destructuring against an array literal on
+/// the right-hand side: a keyed target that the source never defines, and a
+/// positional target past the end of the source, are both always-null
+/// reads rather than the author's intended value.
+///
+/// Only checked when the right-hand side is itself an array literal, so the
+/// shape is known for certain; destructuring the return value of a call
+/// whose docblock array-shape would tell us the same thing isn't covered
+/// yet, pending array-shape support in the type engine.
+#[derive(Debug)]
+pub struct ListDestructuringMismatchRule;
+
+impl Rule for ListDestructuringMismatchRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("List Destructuring Mismatch", Level::Warning)
+            .with_description("Flags list()/array destructuring targets that the source array literal never defines.")
+            .with_example(RuleUsageExample::invalid(
+                "Destructuring a key the source array doesn't have",
+                r#"
+                <?php
+
+                ['id' => $id, 'name' => $name] = ['id' => 1];
+                "#,
+            ))
+    }
+
+    fn check(&self, node: Node<'_>, context: &mut LintContext<'_>) {
+        let Node::Assignment(assignment) = node else {
+            return;
+        };
+
+        if assignment.operator != AssignmentOperator::Assign {
+            return;
+        }
+
+        let target_elements: &[ArrayElement] = match assignment.lhs.as_ref() {
+            Expression::Array(array) => &array.elements,
+            Expression::List(list) => &list.elements,
+            _ => return,
+        };
+
+        let Expression::Array(source) = assignment.rhs.as_ref() else {
+            return;
+        };
+
+        check_destructuring(context, target_elements, source);
+    }
+}
+
+/// What the source array has to say about a given destructuring key: it
+/// supplies a known value, it definitely doesn't have the key, or it
+/// contains a spread/skip that makes its final shape impossible to know
+/// statically.
+enum SourceLookup<'a> {
+    Found(&'a Expression),
+    Missing,
+    Indeterminate,
+}
+
+fn check_destructuring(context: &mut LintContext<'_>, targets: &[ArrayElement], source: &Array) {
+    let mut next_positional_index = 0i64;
+
+    for target in targets {
+        match target {
+            ArrayElement::Missing(_) => {
+                next_positional_index += 1;
+            }
+            ArrayElement::KeyValue(target) => {
+                let Some(key) = literal_key(&target.key) else {
+                    continue;
+                };
+
+                match find_source_value(source, &key) {
+                    SourceLookup::Found(source_value) => recurse_into_nested(context, &target.value, source_value),
+                    SourceLookup::Missing => report_missing_key(context, &target.key, &key),
+                    SourceLookup::Indeterminate => {}
+                }
+            }
+            ArrayElement::Value(target) => {
+                let key = SourceKey::Integer(next_positional_index);
+                next_positional_index += 1;
+
+                match find_source_value(source, &key) {
+                    SourceLookup::Found(source_value) => recurse_into_nested(context, &target.value, source_value),
+                    SourceLookup::Missing => report_missing_key(context, &target.value, &key),
+                    SourceLookup::Indeterminate => {}
+                }
+            }
+            ArrayElement::Variadic(_) => {
+                // Destructuring doesn't support spread targets; nothing to check.
+            }
+        }
+    }
+}
+
+fn recurse_into_nested<'a>(context: &mut LintContext<'a>, target_value: &'a Expression, source_value: &'a Expression) {
+    let (Expression::Array(nested_target), Expression::Array(nested_source)) = (target_value, source_value) else {
+        return;
+    };
+
+    check_destructuring(context, &nested_target.elements, nested_source);
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum SourceKey {
+    String(String),
+    Integer(i64),
+}
+
+fn literal_key(expression: &Expression) -> Option<SourceKey> {
+    match expression {
+        Expression::Literal(Literal::String(literal)) => Some(SourceKey::String(literal.value.to_string())),
+        Expression::Literal(Literal::Integer(literal)) => Some(SourceKey::Integer(literal.value)),
+        _ => None,
+    }
+}
+
+fn find_source_value<'a>(source: &'a Array, key: &SourceKey) -> SourceLookup<'a> {
+    let mut next_positional_index = 0i64;
+
+    for element in source.elements.iter() {
+        match element {
+            ArrayElement::KeyValue(element) => {
+                if literal_key(&element.key).as_ref() == Some(key) {
+                    return SourceLookup::Found(&element.value);
+                }
+            }
+            ArrayElement::Value(element) => {
+                if *key == SourceKey::Integer(next_positional_index) {
+                    return SourceLookup::Found(&element.value);
+                }
+
+                next_positional_index += 1;
+            }
+            ArrayElement::Variadic(_) | ArrayElement::Missing(_) => {
+                // An unpacked spread (or a skipped slot shifting positional
+                // indices) could plausibly supply this key; we can't tell
+                // without evaluating it, so we stop asserting anything.
+                return SourceLookup::Indeterminate;
+            }
+        }
+    }
+
+    SourceLookup::Missing
+}
+
+fn report_missing_key(context: &mut LintContext<'_>, target_span_source: &impl HasSpan, key: &SourceKey) {
+    let description = match key {
+        SourceKey::String(value) => format!("'{value}'"),
+        SourceKey::Integer(value) => value.to_string(),
+    };
+
+    context.report(
+        Issue::new(Level::Warning, format!("destructuring key {description} is not present in the source array"))
+            .with_code("correctness/list-destructuring-mismatch")
+            .with_annotation(
+                Annotation::new(target_span_source.span(), AnnotationKind::Primary)
+                    .with_message("this will always be null"),
+            ),
+    );
+}
@@ -0,0 +1,5 @@
+pub mod call_argument_validation;
+pub mod duplicate_array_key;
+pub mod list_destructuring_mismatch;
+pub mod undefined_array_shape_key;
+pub mod void_result_used;
@@ -0,0 +1,148 @@
+use mago_ast::ast::*;
+use mago_ast::Node;
+use mago_reporting::{Annotation, AnnotationKind, Issue, Level};
+use mago_span::HasSpan;
+use mago_type_syntax::{ArrayShapeKey, Type};
+
+use crate::context::LintContext;
+use crate::definition::{RuleDefinition, RuleUsageExample};
+use crate::rule::Rule;
+
+/// Flags `$array['key']` when `$array`'s shape is known (inferred from a
+/// literal, or declared via `@var`/`@param`) and doesn't declare that key.
+///
+/// Only fires for [`Type::ArrayShape`], since that's the only type that
+/// lists its keys individually; a plain `array<string, mixed>` could
+/// legitimately hold any string key, so it's left alone.
+#[derive(Debug)]
+pub struct UndefinedArrayShapeKeyRule;
+
+impl Rule for UndefinedArrayShapeKeyRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Undefined Array Shape Key", Level::Warning)
+            .with_description("Flags array access to a key that the array's known shape doesn't declare.")
+            .with_example(RuleUsageExample::invalid(
+                "Accessing a misspelled key on a `@var array{id: int, name: string}`",
+                r#"
+                <?php
+
+                /** @var array{id: int, name: string} $user */
+                echo $user['nmae'];
+                "#,
+            ))
+    }
+
+    fn check(&self, node: Node<'_>, context: &mut LintContext<'_>) {
+        let Node::Expression(Expression::ArrayAccess(access)) = node else {
+            return;
+        };
+
+        let Some(index) = access.index.as_deref() else {
+            return;
+        };
+
+        let Some(key) = accessed_key(index) else {
+            return;
+        };
+
+        let Some(Type::ArrayShape(fields)) = context.resolve_array_shape_of(&access.array) else {
+            return;
+        };
+
+        if fields.iter().any(|field| field.key == key) {
+            return;
+        }
+
+        let mut message = format!("key {} is not present in this array's shape", describe_key(&key));
+        if let Some(suggestion) = closest_key(&key, &fields.iter().map(|field| field.key.clone()).collect::<Vec<_>>()) {
+            message.push_str(&format!(", did you mean {}?", describe_key(&suggestion)));
+        }
+
+        context.report(
+            Issue::new(Level::Warning, message)
+                .with_code("correctness/undefined-array-shape-key")
+                .with_annotation(Annotation::new(index.span(), AnnotationKind::Primary)),
+        );
+    }
+}
+
+fn describe_key(key: &ArrayShapeKey) -> String {
+    match key {
+        ArrayShapeKey::Named(name) => format!("'{name}'"),
+        ArrayShapeKey::Integer(value) => value.to_string(),
+    }
+}
+
+fn accessed_key(index: &Expression) -> Option<ArrayShapeKey> {
+    match index {
+        Expression::Literal(Literal::String(literal)) => Some(ArrayShapeKey::Named(literal.value.to_string())),
+        Expression::Literal(Literal::Integer(literal)) => Some(ArrayShapeKey::Integer(literal.value)),
+        _ => None,
+    }
+}
+
+/// Finds the shape key closest to `key` by edit distance, for a "did you
+/// mean" suggestion; only compares keys of the same kind (named vs.
+/// positional), and only suggests one within a small, typo-sized distance.
+fn closest_key(key: &ArrayShapeKey, candidates: &[ArrayShapeKey]) -> Option<ArrayShapeKey> {
+    let ArrayShapeKey::Named(name) = key else {
+        return None;
+    };
+
+    candidates
+        .iter()
+        .filter_map(|candidate| match candidate {
+            ArrayShapeKey::Named(candidate_name) => Some((candidate, levenshtein_distance(name, candidate_name))),
+            ArrayShapeKey::Integer(_) => None,
+        })
+        .filter(|(_, distance)| *distance <= 2)
+        .min_by_key(|(_, distance)| *distance)
+        .map(|(candidate, _)| candidate.clone())
+}
+
+fn levenshtein_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, a_char) in a.iter().enumerate() {
+        let mut previous_diagonal = row[0];
+        row[0] = i + 1;
+
+        for (j, b_char) in b.iter().enumerate() {
+            let cost = if a_char == b_char { 0 } else { 1 };
+            let deletion = row[j] + 1;
+            let insertion = row[j + 1] + 1;
+            let substitution = previous_diagonal + cost;
+
+            previous_diagonal = row[j + 1];
+            row[j + 1] = deletion.min(insertion).min(substitution);
+        }
+    }
+
+    row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identical_strings_have_zero_distance() {
+        assert_eq!(levenshtein_distance("name", "name"), 0);
+    }
+
+    #[test]
+    fn a_transposition_costs_two_and_a_deletion_costs_one() {
+        assert_eq!(levenshtein_distance("nmae", "name"), 2);
+        assert_eq!(levenshtein_distance("nam", "name"), 1);
+    }
+
+    #[test]
+    fn closest_key_ignores_distant_candidates() {
+        let candidates = vec![ArrayShapeKey::Named("identifier".to_string()), ArrayShapeKey::Named("name".to_string())];
+        assert_eq!(closest_key(&ArrayShapeKey::Named("nmae".to_string()), &candidates), Some(ArrayShapeKey::Named("name".to_string())));
+        assert_eq!(closest_key(&ArrayShapeKey::Named("totally_unrelated".to_string()), &candidates), None);
+    }
+}
@@ -0,0 +1,214 @@
+use mago_ast::ast::*;
+use mago_ast::Node;
+use mago_reporting::{Annotation, AnnotationKind, Issue, Level};
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::{RuleDefinition, RuleUsageExample};
+use crate::rule::Rule;
+
+/// What a call's return type tells us about what happens with its result,
+/// as far as the bundled stdlib signature database or the project's own
+/// reflection can tell us.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Returns {
+    /// The call never produces a value to use.
+    Void,
+    /// The call never returns control to its caller at all.
+    Never,
+    /// Neither of the above, or we couldn't determine the return type.
+    Other,
+}
+
+impl Returns {
+    fn from_type_text(type_text: &str) -> Self {
+        match type_text {
+            "void" => Returns::Void,
+            "never" => Returns::Never,
+            _ => Returns::Other,
+        }
+    }
+}
+
+/// Flags using the result of a call whose return type is `void` - assigning
+/// it, returning it, or passing it as an argument - and the first statement
+/// that follows a call to a `never`-returning function or method, since that
+/// statement can never run.
+///
+/// This only catches calls the bundled stdlib signature database or the
+/// project's reflection has a return type for, and only reasons about a
+/// straight run of statements: a `never` call inside one branch of an
+/// `if` doesn't make code after the `if` unreachable, so this rule doesn't
+/// try to follow control flow that far.
+#[derive(Debug)]
+pub struct VoidResultUsedRule;
+
+impl Rule for VoidResultUsedRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Void Result Used", Level::Error)
+            .with_description("Flags use of a void function's result, and code that follows a call that never returns.")
+            .with_example(RuleUsageExample::invalid(
+                "Assigning the result of a `void` function",
+                r#"
+                <?php
+
+                function log_message(string $message): void {}
+
+                $result = log_message("hello");
+                "#,
+            ))
+    }
+
+    fn check(&self, node: Node<'_>, context: &mut LintContext<'_>) {
+        match node {
+            Node::Assignment(assignment) => {
+                if assignment.operator == AssignmentOperator::Assign {
+                    self.check_result_used(context, &assignment.rhs, "assigned");
+                }
+            }
+            Node::Call(call) => {
+                for argument in call.arguments.arguments.iter() {
+                    let value = match argument {
+                        Argument::Positional(positional) => &positional.value,
+                        Argument::Named(named) => &named.value,
+                    };
+
+                    self.check_result_used(context, value, "passed as an argument");
+                }
+            }
+            Node::Function(function) => self.check_statements(context, function.body.statements.as_slice()),
+            Node::Closure(closure) => self.check_statements(context, closure.body.statements.as_slice()),
+            Node::Method(method) => {
+                if let Some(statements) = method.body.as_statements() {
+                    self.check_statements(context, statements);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl VoidResultUsedRule {
+    fn check_result_used(&self, context: &mut LintContext<'_>, expression: &Expression, usage: &str) {
+        let Expression::Call(call) = expression else {
+            return;
+        };
+
+        if classify(context, call) == Returns::Void {
+            context.report(
+                Issue::new(Level::Error, format!("the result of this call is {usage}, but it always returns `void`"))
+                    .with_code("correctness/void-result-used")
+                    .with_annotation(Annotation::new(call.span(), AnnotationKind::Primary)),
+            );
+        }
+    }
+
+    fn check_statements(&self, context: &mut LintContext<'_>, statements: &[Statement]) {
+        for (index, statement) in statements.iter().enumerate() {
+            if let Statement::Return(r#return) = statement {
+                if let Some(value) = &r#return.value {
+                    self.check_result_used(context, value, "returned");
+                }
+            }
+
+            let Statement::Expression(expression_statement) = statement else {
+                continue;
+            };
+
+            let Expression::Call(call) = expression_statement.expression.as_ref() else {
+                continue;
+            };
+
+            if classify(context, call) == Returns::Never {
+                if let Some(unreachable) = statements.get(index + 1) {
+                    context.report(
+                        Issue::new(Level::Error, "unreachable statement: this call never returns")
+                            .with_code("correctness/void-result-used")
+                            .with_annotation(
+                                Annotation::new(unreachable.span(), AnnotationKind::Primary)
+                                    .with_message("this can never run"),
+                            )
+                            .with_annotation(
+                                Annotation::new(call.span(), AnnotationKind::Secondary)
+                                    .with_message("because this call never returns"),
+                            ),
+                    );
+                }
+
+                return;
+            }
+        }
+    }
+}
+
+fn classify(context: &LintContext<'_>, call: &Call) -> Returns {
+    match call {
+        Call::Function(function_call) => {
+            let Expression::Identifier(Identifier::Local(identifier)) = function_call.function.as_ref() else {
+                return Returns::Other;
+            };
+
+            if let Some(signature) = mago_php_stdlib::function_signature(&identifier.value, context.php_version) {
+                return Returns::from_type_text(signature.return_type);
+            }
+
+            let Some(function_reflection) = context.codebase().get_function(&identifier.value) else {
+                return Returns::Other;
+            };
+
+            from_reflection(function_reflection.returns_void(), function_reflection.returns_never())
+        }
+        Call::Method(method_call) => {
+            let Expression::Identifier(Identifier::Local(method_name)) = &method_call.method else {
+                return Returns::Other;
+            };
+
+            let Some(class_name) = context.resolve_class_type_of(&method_call.object) else {
+                return Returns::Other;
+            };
+
+            let Some(class_reflection) = context.codebase().get_class(&class_name) else {
+                return Returns::Other;
+            };
+
+            let Some(method_reflection) = class_reflection.get_method(&method_name.value) else {
+                return Returns::Other;
+            };
+
+            from_reflection(method_reflection.returns_void(), method_reflection.returns_never())
+        }
+        Call::StaticMethod(static_call) => {
+            let Expression::Identifier(identifier) = static_call.class.as_ref() else {
+                return Returns::Other;
+            };
+
+            let Expression::Identifier(Identifier::Local(method_name)) = &static_call.method else {
+                return Returns::Other;
+            };
+
+            let Some(class_name) = context.resolve_class_name(identifier) else {
+                return Returns::Other;
+            };
+
+            let Some(class_reflection) = context.codebase().get_class(&class_name) else {
+                return Returns::Other;
+            };
+
+            let Some(method_reflection) = class_reflection.get_method(&method_name.value) else {
+                return Returns::Other;
+            };
+
+            from_reflection(method_reflection.returns_void(), method_reflection.returns_never())
+        }
+    }
+}
+
+fn from_reflection(returns_void: bool, returns_never: bool) -> Returns {
+    if returns_never {
+        Returns::Never
+    } else if returns_void {
+        Returns::Void
+    } else {
+        Returns::Other
+    }
+}
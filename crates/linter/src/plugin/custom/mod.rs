@@ -0,0 +1,81 @@
+use mago_ast::Node;
+use mago_ast::query::Query;
+use mago_reporting::{Annotation, AnnotationKind, Issue, Level};
+use mago_span::HasSpan;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::plugin::LintPlugin;
+use crate::rule::Rule;
+
+/// A single user-defined rule, matched with the node query language from
+/// `mago_ast::query` rather than Rust code.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PatternRuleConfig {
+    pub name: String,
+    pub query: String,
+    pub message: String,
+    #[serde(default)]
+    pub level: Option<String>,
+}
+
+/// A plugin whose rules are entirely config-driven: each entry under
+/// `linter.custom_rules` in the project config becomes one [`PatternRule`];
+/// an entry whose query fails to parse is skipped.
+#[derive(Debug)]
+pub struct CustomPlugin {
+    rules: Vec<PatternRuleConfig>,
+}
+
+impl CustomPlugin {
+    pub fn new(rules: Vec<PatternRuleConfig>) -> Self {
+        Self { rules }
+    }
+}
+
+impl LintPlugin for CustomPlugin {
+    fn get_name(&self) -> &'static str {
+        "custom"
+    }
+
+    fn is_enabled_by_default(&self) -> bool {
+        true
+    }
+
+    fn get_rules(&self) -> Vec<Box<dyn Rule>> {
+        self.rules
+            .iter()
+            .filter_map(|config| match Query::parse(&config.query) {
+                Ok(query) => Some(Box::new(PatternRule { config: config.clone(), query }) as Box<dyn Rule>),
+                Err(_) => None,
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug)]
+struct PatternRule {
+    config: PatternRuleConfig,
+    query: Query,
+}
+
+impl Rule for PatternRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled(Box::leak(self.config.name.clone().into_boxed_str()), Level::Warning)
+            .with_description("A project-defined pattern rule.")
+    }
+
+    fn check(&self, node: Node<'_>, context: &mut LintContext<'_>) {
+        if !self.query.matches(context.ancestors_including(node)) {
+            return;
+        }
+
+        context.report(
+            Issue::new(Level::Warning, self.config.message.clone())
+                .with_code(format!("custom/{}", self.config.name))
+                .with_annotation(Annotation::new(node.span(), AnnotationKind::Primary)),
+        );
+    }
+}
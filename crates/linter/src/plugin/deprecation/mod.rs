@@ -0,0 +1,25 @@
+use crate::plugin::LintPlugin;
+use crate::plugin::deprecation::rules::deprecated_api_usage::DeprecatedApiUsageRule;
+use crate::rule::Rule;
+
+pub mod rules;
+
+/// Flags calls to functions and methods marked deprecated, whether by a
+/// stdlib entry, an `@deprecated` docblock tag, or a `#[Deprecated]`
+/// attribute.
+#[derive(Debug)]
+pub struct DeprecationPlugin;
+
+impl LintPlugin for DeprecationPlugin {
+    fn get_name(&self) -> &'static str {
+        "deprecation"
+    }
+
+    fn is_enabled_by_default(&self) -> bool {
+        true
+    }
+
+    fn get_rules(&self) -> Vec<Box<dyn Rule>> {
+        vec![Box::new(DeprecatedApiUsageRule)]
+    }
+}
@@ -0,0 +1,92 @@
+use mago_ast::ast::*;
+use mago_ast::Node;
+use mago_reflection::CodebaseReflection;
+use mago_reporting::{Annotation, AnnotationKind, Issue, Level};
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::{RuleDefinition, RuleUsageExample};
+use crate::rule::Rule;
+
+/// Reports calls to a function or method marked deprecated, whether that
+/// comes from the bundled stdlib database, an `@deprecated` docblock tag, or
+/// a `#[Deprecated]` attribute on the project-side declaration.
+///
+/// The diagnostic includes whatever replacement hint the annotation carried
+/// (the stdlib's `deprecated_in` version, or the `@deprecated` reason text)
+/// so the fix is obvious without looking the symbol up.
+#[derive(Debug)]
+pub struct DeprecatedApiUsageRule;
+
+impl Rule for DeprecatedApiUsageRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Deprecated API Usage", Level::Warning)
+            .with_description("Flags calls to functions or methods marked deprecated; uses of deprecated classes are not flagged.")
+            .with_example(RuleUsageExample::invalid(
+                "Calling a function deprecated in a later PHP version",
+                r#"
+                <?php
+
+                $callback = create_function('$x', 'return $x + 1;');
+                "#,
+            ))
+    }
+
+    fn check(&self, node: Node<'_>, context: &mut LintContext<'_>) {
+        match node {
+            Node::Call(Call::Function(call)) => {
+                let Expression::Identifier(Identifier::Local(identifier)) = call.function.as_ref() else {
+                    return;
+                };
+
+                if let Some(signature) = mago_php_stdlib::function_signature(&identifier.value, context.php_version) {
+                    if let Some(deprecated_in) = signature.deprecated_in {
+                        self.report(context, call.span(), &identifier.value, format!("deprecated since PHP {deprecated_in}"));
+                    }
+
+                    return;
+                }
+
+                let Some(function_reflection) = context.codebase().get_function(&identifier.value) else {
+                    return;
+                };
+
+                if let Some(reason) = function_reflection.deprecation_reason() {
+                    self.report(context, call.span(), &identifier.value, reason.to_string());
+                }
+            }
+            Node::Call(Call::Method(call)) => {
+                let Expression::Identifier(Identifier::Local(method_name)) = &call.method else {
+                    return;
+                };
+
+                let Some(class_name) = context.resolve_class_type_of(&call.object) else {
+                    return;
+                };
+
+                let Some(class_reflection) = context.codebase().get_class(&class_name) else {
+                    return;
+                };
+
+                let Some(method_reflection) = class_reflection.get_method(&method_name.value) else {
+                    return;
+                };
+
+                if let Some(reason) = method_reflection.deprecation_reason() {
+                    self.report(context, call.span(), &format!("{class_name}::{}", method_name.value), reason.to_string());
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl DeprecatedApiUsageRule {
+    fn report(&self, context: &mut LintContext<'_>, span: mago_span::Span, name: &str, reason: String) {
+        context.report(
+            Issue::new(Level::Warning, format!("`{name}` is deprecated: {reason}"))
+                .with_code("deprecation/deprecated-api-usage")
+                .with_annotation(Annotation::new(span, AnnotationKind::Primary)),
+        );
+    }
+}
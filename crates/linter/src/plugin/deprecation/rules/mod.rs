@@ -0,0 +1 @@
+pub mod deprecated_api_usage;
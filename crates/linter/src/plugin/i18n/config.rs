@@ -0,0 +1,22 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Configuration for the `i18n` plugin.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct I18nConfig {
+    /// Function names treated as translation calls by
+    /// [`crate::plugin::i18n::rules::concatenated_translatable_string::ConcatenatedTranslatableStringRule`];
+    /// arguments to `echo`/`print` are not checked.
+    #[serde(default = "default_translation_functions")]
+    pub translation_functions: Vec<String>,
+}
+
+impl Default for I18nConfig {
+    fn default() -> Self {
+        Self { translation_functions: default_translation_functions() }
+    }
+}
+
+fn default_translation_functions() -> Vec<String> {
+    vec!["__".to_string(), "trans".to_string(), "gettext".to_string(), "_".to_string()]
+}
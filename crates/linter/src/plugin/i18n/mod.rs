@@ -0,0 +1,26 @@
+use crate::plugin::LintPlugin;
+use crate::plugin::i18n::rules::concatenated_translatable_string::ConcatenatedTranslatableStringRule;
+use crate::rule::Rule;
+
+pub mod config;
+pub mod rules;
+
+/// Internationalization-focused rules - currently just flagging string
+/// concatenation inside translation calls, where gluing a literal to a
+/// variable at the call site defeats the translation catalog.
+#[derive(Debug)]
+pub struct I18nPlugin;
+
+impl LintPlugin for I18nPlugin {
+    fn get_name(&self) -> &'static str {
+        "i18n"
+    }
+
+    fn is_enabled_by_default(&self) -> bool {
+        false
+    }
+
+    fn get_rules(&self) -> Vec<Box<dyn Rule>> {
+        vec![Box::new(ConcatenatedTranslatableStringRule)]
+    }
+}
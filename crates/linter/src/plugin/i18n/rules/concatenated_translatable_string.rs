@@ -0,0 +1,96 @@
+use mago_ast::ast::*;
+use mago_ast::Node;
+use mago_reporting::{Annotation, AnnotationKind, Issue, Level};
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::{RuleDefinition, RuleUsageExample};
+use crate::rule::Rule;
+
+/// Flags a string concatenation mixing a literal and a variable/expression
+/// passed to a translation call, since the literal pieces can't be
+/// extracted for translation independently of the variable - the usual fix
+/// is a placeholder the translation catalog can substitute into
+/// (`sprintf("Hello, %s", $name)` or the translator's own parameter
+/// syntax) instead of gluing the pieces together at the call site.
+///
+/// Off by default: which calls count as "translation calls" is entirely
+/// project-specific, configured via `i18n.translation_functions`.
+#[derive(Debug)]
+pub struct ConcatenatedTranslatableStringRule;
+
+impl Rule for ConcatenatedTranslatableStringRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Concatenated Translatable String", Level::Warning)
+            .with_description("Flags a translation call argument built by concatenating a literal with a variable.")
+            .with_example(RuleUsageExample::invalid(
+                "A translated greeting built by concatenation",
+                r#"
+                <?php
+
+                echo __("Hello, " . $name . "!");
+                "#,
+            ))
+    }
+
+    fn check(&self, node: Node<'_>, context: &mut LintContext<'_>) {
+        let Node::Call(Call::Function(call)) = node else {
+            return;
+        };
+
+        let Expression::Identifier(Identifier::Local(identifier)) = call.function.as_ref() else {
+            return;
+        };
+
+        if !context.settings().i18n.translation_functions.iter().any(|name| name == &identifier.value) {
+            return;
+        }
+
+        for argument in call.arguments.arguments.iter() {
+            let value = match argument {
+                Argument::Positional(positional) => &positional.value,
+                Argument::Named(named) => &named.value,
+            };
+
+            let Expression::Binary(binary) = value else {
+                continue;
+            };
+
+            if !matches!(binary.operator, BinaryOperator::StringConcat(_)) {
+                continue;
+            }
+
+            if !concatenates_literal_with_dynamic(binary) {
+                continue;
+            }
+
+            context.report(
+                Issue::new(Level::Warning, "this translation argument concatenates a literal with a dynamic value - use a placeholder instead")
+                    .with_code("i18n/concatenated-translatable-string")
+                    .with_annotation(Annotation::new(value.span(), AnnotationKind::Primary)),
+            );
+        }
+    }
+}
+
+/// Whether a `.` concatenation chain mixes at least one string literal leaf
+/// with at least one leaf that isn't a literal at all - the shape that
+/// makes a piece of the message untranslatable on its own.
+fn concatenates_literal_with_dynamic(binary: &Binary) -> bool {
+    let mut has_literal = false;
+    let mut has_dynamic = false;
+
+    let mut pending = vec![binary.lhs.as_ref(), binary.rhs.as_ref()];
+    while let Some(expression) = pending.pop() {
+        match expression {
+            Expression::Literal(Literal::String(_)) => has_literal = true,
+            Expression::Binary(nested) if matches!(nested.operator, BinaryOperator::StringConcat(_)) => {
+                pending.push(nested.lhs.as_ref());
+                pending.push(nested.rhs.as_ref());
+            }
+            _ => has_dynamic = true,
+        }
+    }
+
+    has_literal && has_dynamic
+}
@@ -0,0 +1 @@
+pub mod concatenated_translatable_string;
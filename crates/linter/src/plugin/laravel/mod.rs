@@ -0,0 +1,25 @@
+use crate::plugin::LintPlugin;
+use crate::plugin::laravel::rules::facade_in_testable_code::FacadeInTestableCodeRule;
+use crate::plugin::laravel::rules::raw_query_builder_where::RawQueryBuilderWhereRule;
+use crate::rule::Rule;
+
+pub mod rules;
+
+/// Laravel-specific rules, enabled only for projects that declare a
+/// dependency on `laravel/framework` in their `composer.json`.
+#[derive(Debug)]
+pub struct LaravelPlugin;
+
+impl LintPlugin for LaravelPlugin {
+    fn get_name(&self) -> &'static str {
+        "laravel"
+    }
+
+    fn is_enabled_by_default(&self) -> bool {
+        false
+    }
+
+    fn get_rules(&self) -> Vec<Box<dyn Rule>> {
+        vec![Box::new(RawQueryBuilderWhereRule), Box::new(FacadeInTestableCodeRule)]
+    }
+}
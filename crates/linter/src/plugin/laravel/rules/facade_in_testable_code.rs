@@ -0,0 +1,50 @@
+use mago_ast::ast::*;
+use mago_ast::Node;
+use mago_reporting::{Annotation, AnnotationKind, Issue, Level};
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::rule::Rule;
+
+const FACADE_NAMESPACE: &str = "Illuminate\\Support\\Facades\\";
+
+/// Flags a static method call on an `Illuminate\Support\Facades\*` facade,
+/// where an injected contract would keep the class mockable without
+/// `Facade::shouldReceive()`. Every such call is flagged, wherever it
+/// appears.
+#[derive(Debug)]
+pub struct FacadeInTestableCodeRule;
+
+impl Rule for FacadeInTestableCodeRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Facade In Testable Code", Level::Note)
+            .with_description("Suggests constructor injection instead of a static facade call, for easier testing.")
+    }
+
+    fn check(&self, node: Node<'_>, context: &mut LintContext<'_>) {
+        let Node::Call(Call::StaticMethod(call)) = node else {
+            return;
+        };
+
+        let Expression::Identifier(identifier) = call.class.as_ref() else {
+            return;
+        };
+
+        let Some(resolved) = context.resolve_class_name(identifier) else {
+            return;
+        };
+
+        if !resolved.starts_with(FACADE_NAMESPACE) {
+            return;
+        }
+
+        let facade = resolved.trim_start_matches(FACADE_NAMESPACE);
+
+        context.report(
+            Issue::new(Level::Note, format!("consider injecting a `{facade}`-backed contract instead of calling the facade directly"))
+                .with_code("laravel/facade-in-testable-code")
+                .with_annotation(Annotation::new(call.span(), AnnotationKind::Primary)),
+        );
+    }
+}
@@ -0,0 +1,2 @@
+pub mod facade_in_testable_code;
+pub mod raw_query_builder_where;
@@ -0,0 +1,68 @@
+use mago_ast::ast::*;
+use mago_ast::Node;
+use mago_reporting::{Annotation, AnnotationKind, Issue, Level};
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::{RuleDefinition, RuleUsageExample};
+use crate::rule::Rule;
+
+/// Flags `whereRaw()`/`orWhereRaw()`/`havingRaw()` calls whose first
+/// argument is anything but a plain, non-interpolated string literal (an
+/// interpolation, a concatenation, a variable, a call, ...), which is the
+/// most common way SQL injection sneaks into otherwise query-builder-safe
+/// Laravel code.
+#[derive(Debug)]
+pub struct RawQueryBuilderWhereRule;
+
+impl Rule for RawQueryBuilderWhereRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Raw Query Builder Where", Level::Error)
+            .with_description("Flags `whereRaw()`/`orWhereRaw()`/`havingRaw()` calls whose SQL is not a plain string literal.")
+            .with_example(RuleUsageExample::invalid(
+                "Interpolating user input into a raw where clause",
+                r#"
+                <?php
+
+                User::query()->whereRaw("name = '{$name}'")->get();
+                "#,
+            ))
+    }
+
+    fn check(&self, node: Node<'_>, context: &mut LintContext<'_>) {
+        let Node::Call(Call::Method(call)) = node else {
+            return;
+        };
+
+        let ClassLikeMemberSelector::Identifier(method) = &call.method else {
+            return;
+        };
+
+        if !matches!(method.value.as_str(), "whereRaw" | "orWhereRaw" | "havingRaw") {
+            return;
+        }
+
+        let Some(first_argument) = call.argument_list.arguments.first() else {
+            return;
+        };
+
+        let is_dynamic = match first_argument.value() {
+            Expression::Literal(Literal::String(string)) => string.kind.is_interpolated(),
+            Expression::Binary(binary) => matches!(binary.operator, BinaryOperator::StringConcat(_)),
+            _ => true,
+        };
+
+        if !is_dynamic {
+            return;
+        }
+
+        context.report(
+            Issue::new(Level::Error, format!("`{}()` is built from a dynamic string", method.value))
+                .with_code("laravel/raw-query-builder-where")
+                .with_annotation(
+                    Annotation::new(first_argument.span(), AnnotationKind::Primary)
+                        .with_message("pass bindings as the second argument instead of interpolating values here"),
+                ),
+        );
+    }
+}
@@ -0,0 +1,50 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Configuration for the `maintainability` plugin.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MaintainabilityConfig {
+    /// Whether [`crate::plugin::maintainability::rules::dynamic_construct::DynamicConstructRule`]
+    /// flags `compact(...)`.
+    #[serde(default = "default_true")]
+    pub forbid_compact: bool,
+    /// Whether [`crate::plugin::maintainability::rules::dynamic_construct::DynamicConstructRule`]
+    /// flags `extract(...)`.
+    #[serde(default = "default_true")]
+    pub forbid_extract: bool,
+    /// Whether [`crate::plugin::maintainability::rules::dynamic_construct::DynamicConstructRule`]
+    /// flags a variable variable (`$$name`).
+    #[serde(default = "default_true")]
+    pub forbid_variable_variables: bool,
+    /// Whether [`crate::plugin::maintainability::rules::dynamic_construct::DynamicConstructRule`]
+    /// flags dynamic property access through a string variable (`$obj->$name`).
+    #[serde(default = "default_true")]
+    pub forbid_dynamic_property_access: bool,
+    /// Global variable names that
+    /// [`crate::plugin::maintainability::rules::global_state_usage::GlobalStateUsageRule`]
+    /// won't flag a `global` statement for, even though it otherwise would.
+    #[serde(default)]
+    pub allowed_global_variables: Vec<String>,
+    /// Static local variable names that
+    /// [`crate::plugin::maintainability::rules::global_state_usage::GlobalStateUsageRule`]
+    /// won't flag a `static` statement for, even though it otherwise would.
+    #[serde(default)]
+    pub allowed_static_variables: Vec<String>,
+}
+
+impl Default for MaintainabilityConfig {
+    fn default() -> Self {
+        Self {
+            forbid_compact: default_true(),
+            forbid_extract: default_true(),
+            forbid_variable_variables: default_true(),
+            forbid_dynamic_property_access: default_true(),
+            allowed_global_variables: Vec::new(),
+            allowed_static_variables: Vec::new(),
+        }
+    }
+}
+
+fn default_true() -> bool {
+    true
+}
@@ -0,0 +1,30 @@
+use crate::plugin::LintPlugin;
+use crate::plugin::maintainability::rules::cyclic_dependency::CyclicDependencyRule;
+use crate::plugin::maintainability::rules::dynamic_construct::DynamicConstructRule;
+use crate::plugin::maintainability::rules::global_state_usage::GlobalStateUsageRule;
+use crate::rule::Rule;
+
+pub mod config;
+pub mod rules;
+
+/// Rules about the long-term health of a codebase's structure rather than
+/// any single statement's correctness: dependency cycles between classes,
+/// constructs that name symbols through runtime strings (`compact()`,
+/// `extract()`, variable variables, dynamic property access), and hidden
+/// `global`/`static` state.
+#[derive(Debug)]
+pub struct MaintainabilityPlugin;
+
+impl LintPlugin for MaintainabilityPlugin {
+    fn get_name(&self) -> &'static str {
+        "maintainability"
+    }
+
+    fn is_enabled_by_default(&self) -> bool {
+        false
+    }
+
+    fn get_rules(&self) -> Vec<Box<dyn Rule>> {
+        vec![Box::new(CyclicDependencyRule), Box::new(DynamicConstructRule), Box::new(GlobalStateUsageRule)]
+    }
+}
@@ -0,0 +1,110 @@
+use mago_ast::ast::*;
+use mago_ast::Node;
+use mago_reporting::{Annotation, AnnotationKind, Issue, Level};
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::{RuleDefinition, RuleUsageExample};
+use crate::rule::Rule;
+
+/// Flags a class that depends, directly or transitively (through `extends`,
+/// `implements`, or `use`), on a class that itself depends back on it.
+///
+/// A cycle means the two classes can't be understood, tested, or reused
+/// independently of one another, no matter how the dependency is drawn on
+/// paper. This only walks direct inheritance/composition edges from the
+/// project's reflection data; it doesn't follow type hints on properties or
+/// parameters, nor does it support grouping classes into user-defined
+/// layers (e.g. "Domain must not depend on Infrastructure").
+#[derive(Debug)]
+pub struct CyclicDependencyRule;
+
+impl Rule for CyclicDependencyRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Cyclic Dependency", Level::Warning)
+            .with_description("Flags dependency cycles between classes (via extends/implements/use).")
+            .with_example(RuleUsageExample::invalid(
+                "Two classes that extend/implement one another",
+                r#"
+                <?php
+
+                class A extends B {}
+                class B extends A {}
+                "#,
+            ))
+    }
+
+    fn check(&self, node: Node<'_>, context: &mut LintContext<'_>) {
+        let Node::Class(class) = node else {
+            return;
+        };
+
+        let class_name = class.name.value.to_string();
+
+        let Some(cycle) = find_cycle(context, &class_name) else {
+            return;
+        };
+
+        let path = cycle.join(" -> ");
+
+        context.report(
+            Issue::new(Level::Warning, format!("cyclic dependency detected: {path}"))
+                .with_code("maintainability/cyclic-dependency")
+                .with_annotation(
+                    Annotation::new(class.name.span(), AnnotationKind::Primary)
+                        .with_message("this class is part of a dependency cycle"),
+                ),
+        );
+    }
+}
+
+/// Depth-first search for a path that leaves `start` and eventually comes
+/// back to it. `visited` bounds the search to each node at most once, so a
+/// cycle elsewhere in the graph that doesn't involve `start` can't cause
+/// runaway recursion.
+fn find_cycle(context: &LintContext<'_>, start: &str) -> Option<Vec<String>> {
+    let mut visited = std::collections::HashSet::new();
+    let mut path = vec![start.to_string()];
+
+    search(context, start, start, &mut visited, &mut path)
+}
+
+fn search(
+    context: &LintContext<'_>,
+    start: &str,
+    current: &str,
+    visited: &mut std::collections::HashSet<String>,
+    path: &mut Vec<String>,
+) -> Option<Vec<String>> {
+    for dependency in direct_dependencies(context, current) {
+        if dependency == start {
+            let mut cycle = path.clone();
+            cycle.push(dependency);
+            return Some(cycle);
+        }
+
+        if !visited.insert(dependency.clone()) {
+            continue;
+        }
+
+        path.push(dependency.clone());
+        if let Some(cycle) = search(context, start, &dependency, visited, path) {
+            return Some(cycle);
+        }
+        path.pop();
+    }
+
+    None
+}
+
+fn direct_dependencies(context: &LintContext<'_>, class_name: &str) -> Vec<String> {
+    let Some(class_reflection) = context.codebase().get_class(class_name) else {
+        return Vec::new();
+    };
+
+    let mut dependencies = Vec::new();
+    dependencies.extend(class_reflection.extended_class().map(|name| name.to_string()));
+    dependencies.extend(class_reflection.implemented_interfaces().iter().cloned());
+    dependencies.extend(class_reflection.used_traits().iter().cloned());
+    dependencies
+}
@@ -0,0 +1,89 @@
+use mago_ast::ast::*;
+use mago_ast::Node;
+use mago_reporting::{Annotation, AnnotationKind, Issue, Level};
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::{RuleDefinition, RuleUsageExample};
+use crate::rule::Rule;
+
+/// Flags constructs that name a symbol with a runtime string instead of
+/// source text - `compact()`, `extract()`, a variable variable (`$$name`),
+/// and dynamic property access through a string variable (`$obj->$name`).
+///
+/// All four make it impossible for a static analyzer (or a reader) to know
+/// which variable or property is actually being touched, since the name
+/// only exists as a runtime value. There's no autofix: the point is to
+/// prompt a rewrite to explicit variables/properties, which isn't a
+/// mechanical transformation this rule can safely perform on its own.
+#[derive(Debug)]
+pub struct DynamicConstructRule;
+
+impl Rule for DynamicConstructRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Dynamic Construct", Level::Warning)
+            .with_description("Flags compact()/extract(), variable variables, and dynamic property access.")
+            .with_example(RuleUsageExample::invalid(
+                "A variable variable",
+                r#"
+                <?php
+
+                $name = 'greeting';
+                $$name = 'hello';
+                "#,
+            ))
+    }
+
+    fn check(&self, node: Node<'_>, context: &mut LintContext<'_>) {
+        let config = &context.settings().maintainability;
+
+        match node {
+            Node::Call(Call::Function(call)) => {
+                let Expression::Identifier(Identifier::Local(identifier)) = call.function.as_ref() else {
+                    return;
+                };
+
+                let (enabled, message) = match identifier.value.as_str() {
+                    "compact" if config.forbid_compact => {
+                        (true, "`compact()` pulls variable names from runtime strings, which a static analyzer can't follow - name the array's keys and values explicitly instead")
+                    }
+                    "extract" if config.forbid_extract => {
+                        (true, "`extract()` creates variables whose names a static analyzer can't see - assign them explicitly instead")
+                    }
+                    _ => (false, ""),
+                };
+
+                if enabled {
+                    report(context, call.span(), message);
+                }
+            }
+            Node::Expression(Expression::Variable(Variable::Indirect(variable))) => {
+                if config.forbid_variable_variables {
+                    report(
+                        context,
+                        variable.span(),
+                        "this variable variable names its target with a runtime string, which a static analyzer can't follow - use an explicit variable instead",
+                    );
+                }
+            }
+            Node::Expression(Expression::Access(Access::Property(access))) => {
+                if config.forbid_dynamic_property_access && !matches!(access.property, ClassLikeMemberSelector::Identifier(_)) {
+                    report(
+                        context,
+                        access.span(),
+                        "this property is named with a runtime string, which a static analyzer can't follow - access it by its literal name instead",
+                    );
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn report(context: &mut LintContext<'_>, span: mago_span::Span, message: &str) {
+    context.report(
+        Issue::new(Level::Warning, message)
+            .with_code("maintainability/dynamic-construct")
+            .with_annotation(Annotation::new(span, AnnotationKind::Primary)),
+    );
+}
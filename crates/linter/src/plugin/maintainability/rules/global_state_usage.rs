@@ -0,0 +1,172 @@
+use std::collections::HashSet;
+
+use mago_ast::ast::*;
+use mago_ast::Node;
+use mago_reporting::{Annotation, AnnotationKind, Issue, Level};
+use mago_span::HasSpan;
+use mago_span::Span;
+
+use crate::context::LintContext;
+use crate::definition::{RuleDefinition, RuleUsageExample};
+use crate::rule::Rule;
+
+/// Flags a function or method that reads a `global` variable or declares a
+/// `static` local variable, both of which introduce state that outlives a
+/// single call but isn't visible anywhere in the function's signature.
+///
+/// One diagnostic is reported per function per keyword, with the function
+/// name as the primary annotation and every `global`/`static` statement in
+/// its body (including nested ones, e.g. inside an `if`) attached as a
+/// secondary annotation, so a single look at the issue shows the whole
+/// extent of the hidden state rather than one statement at a time.
+///
+/// [`crate::plugin::maintainability::config::MaintainabilityConfig::allowed_global_variables`]
+/// and `allowed_static_variables` let specific variable names opt out - a
+/// `static $instance` backing a singleton, for example.
+#[derive(Debug)]
+pub struct GlobalStateUsageRule;
+
+impl Rule for GlobalStateUsageRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Global State Usage", Level::Warning)
+            .with_description("Flags `global` statements and `static` local variables as hidden shared state.")
+            .with_example(RuleUsageExample::invalid(
+                "A function relying on global and static state",
+                r#"
+                <?php
+
+                function increment() {
+                    global $counter;
+                    static $calls = 0;
+
+                    $counter++;
+                    $calls++;
+                }
+                "#,
+            ))
+    }
+
+    fn check(&self, node: Node<'_>, context: &mut LintContext<'_>) {
+        let (name_span, statements) = match node {
+            Node::Function(function) => (function.name.span(), function.body.statements.as_slice()),
+            Node::Method(method) => match method.body.as_statements() {
+                Some(statements) => (method.name.span(), statements),
+                None => return,
+            },
+            _ => return,
+        };
+
+        let mut globals = Vec::new();
+        let mut statics = Vec::new();
+
+        for statement in statements {
+            collect(statement, &mut globals, &mut statics);
+        }
+
+        let allowed_globals: HashSet<&str> =
+            context.settings().maintainability.allowed_global_variables.iter().map(String::as_str).collect();
+        let allowed_statics: HashSet<&str> =
+            context.settings().maintainability.allowed_static_variables.iter().map(String::as_str).collect();
+
+        report_hidden_state(context, name_span, "global", &globals, &allowed_globals);
+        report_hidden_state(context, name_span, "static", &statics, &allowed_statics);
+    }
+}
+
+fn collect<'a>(statement: &'a Statement, globals: &mut Vec<&'a Statement>, statics: &mut Vec<&'a Statement>) {
+    match statement {
+        Statement::Global(_) => globals.push(statement),
+        Statement::Static(_) => statics.push(statement),
+        Statement::Block(block) => {
+            for inner in &block.statements {
+                collect(inner, globals, statics);
+            }
+        }
+        Statement::If(r#if) => {
+            collect(&r#if.body, globals, statics);
+            for clause in &r#if.else_if_clauses {
+                collect(&clause.body, globals, statics);
+            }
+            if let Some(else_clause) = &r#if.else_clause {
+                collect(&else_clause.body, globals, statics);
+            }
+        }
+        Statement::While(r#while) => collect(&r#while.body, globals, statics),
+        Statement::DoWhile(do_while) => collect(&do_while.body, globals, statics),
+        Statement::For(r#for) => collect(&r#for.body, globals, statics),
+        Statement::Foreach(foreach) => collect(&foreach.body, globals, statics),
+        Statement::Switch(switch) => {
+            for case in switch.body.cases() {
+                for inner in case.statements() {
+                    collect(inner, globals, statics);
+                }
+            }
+        }
+        Statement::Try(r#try) => {
+            for inner in &r#try.block.statements {
+                collect(inner, globals, statics);
+            }
+            for clause in &r#try.catch_clauses {
+                for inner in &clause.block.statements {
+                    collect(inner, globals, statics);
+                }
+            }
+            if let Some(finally) = &r#try.finally_clause {
+                for inner in &finally.block.statements {
+                    collect(inner, globals, statics);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn report_hidden_state(
+    context: &mut LintContext<'_>,
+    function_name_span: Span,
+    keyword: &str,
+    statements: &[&Statement],
+    allowed: &HashSet<&str>,
+) {
+    let mut annotations = Vec::new();
+
+    for statement in statements {
+        let variable_names = variable_names_in(context.lookup_slice(statement.span()));
+
+        if !variable_names.is_empty() && variable_names.iter().all(|name| allowed.contains(name.as_str())) {
+            continue;
+        }
+
+        annotations.push(
+            Annotation::new(statement.span(), AnnotationKind::Secondary).with_message(format!("`{keyword}` used here")),
+        );
+    }
+
+    if annotations.is_empty() {
+        return;
+    }
+
+    let mut issue = Issue::new(
+        Level::Warning,
+        format!("this function relies on `{keyword}` state, which is hidden from its signature and shared across calls"),
+    )
+    .with_code("maintainability/global-state-usage")
+    .with_annotation(Annotation::new(function_name_span, AnnotationKind::Primary));
+
+    for annotation in annotations {
+        issue = issue.with_annotation(annotation);
+    }
+
+    context.report(issue);
+}
+
+/// The `$name` variables mentioned in a `global`/`static` statement's own
+/// source text - used instead of a parsed field list, since neither
+/// [`Statement::Global`] nor [`Statement::Static`] has a confirmed field
+/// layout anywhere in this tree.
+fn variable_names_in(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric() && c != '_' && c != '$')
+        .filter(|token| token.starts_with('$') && token.len() > 1)
+        .map(|token| token.trim_start_matches('$').to_string())
+        .collect()
+}
@@ -0,0 +1,3 @@
+pub mod cyclic_dependency;
+pub mod dynamic_construct;
+pub mod global_state_usage;
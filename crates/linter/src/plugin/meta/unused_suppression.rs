@@ -0,0 +1,41 @@
+use mago_ast::Node;
+use mago_reporting::{Annotation, AnnotationKind, Issue, Level};
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::rule::Rule;
+
+/// Flags `@mago-expect`/`@mago-ignore` comments that no longer suppress
+/// anything, so they don't rot into misleading documentation once the
+/// underlying issue has been fixed.
+#[derive(Debug)]
+pub struct UnusedSuppressionRule;
+
+impl Rule for UnusedSuppressionRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Unused Suppression", Level::Warning)
+            .with_description("Detects suppression comments that do not suppress any reported issue.")
+    }
+
+    fn check(&self, node: Node<'_>, context: &mut LintContext<'_>) {
+        let Node::Program(_) = node else {
+            return;
+        };
+
+        for suppression in context.suppressions() {
+            if suppression.used {
+                continue;
+            }
+
+            let issue = Issue::new(Level::Warning, format!("suppression for `{}` is unused", suppression.rule))
+                .with_code("meta/unused-suppression")
+                .with_annotation(
+                    Annotation::new(suppression.comment_span, AnnotationKind::Primary)
+                        .with_message("this rule is no longer being suppressed here"),
+                )
+                .with_note("remove this comment, or re-check whether the issue it was meant to silence has come back.");
+
+            context.report(issue);
+        }
+    }
+}
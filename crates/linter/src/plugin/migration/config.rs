@@ -0,0 +1,23 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Configuration for the `migration` plugin.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MigrationConfig {
+    /// Whether the `str_contains`/`str_starts_with` rewrite rules skip a
+    /// call site when an `mb_*` function appears nearby in the same file,
+    /// since that's a sign the surrounding code cares about multibyte
+    /// semantics that the single-byte `str_*` functions don't preserve.
+    #[serde(default = "default_true")]
+    pub skip_when_multibyte_functions_are_nearby: bool,
+}
+
+impl Default for MigrationConfig {
+    fn default() -> Self {
+        Self { skip_when_multibyte_functions_are_nearby: default_true() }
+    }
+}
+
+fn default_true() -> bool {
+    true
+}
@@ -0,0 +1,37 @@
+use crate::plugin::LintPlugin;
+use crate::plugin::migration::rules::php80_create_function_closure::Php80CreateFunctionClosureRule;
+use crate::plugin::migration::rules::php80_parameter_order::Php80ParameterOrderRule;
+use crate::plugin::migration::rules::php80_str_contains::Php80StrContainsRule;
+use crate::plugin::migration::rules::php80_str_starts_with::Php80StrStartsWithRule;
+use crate::plugin::migration::rules::php_82_promote_readonly_property::Php82PromoteReadonlyPropertyRule;
+use crate::plugin::migration::rules::php_83_readonly_class::Php83ReadonlyClassRule;
+use crate::rule::Rule;
+
+pub mod config;
+pub mod rules;
+
+/// A plugin that suggests rewrites to take advantage of newer PHP language
+/// features, gated by the project's configured target PHP version.
+#[derive(Debug)]
+pub struct MigrationPlugin;
+
+impl LintPlugin for MigrationPlugin {
+    fn get_name(&self) -> &'static str {
+        "migration"
+    }
+
+    fn is_enabled_by_default(&self) -> bool {
+        false
+    }
+
+    fn get_rules(&self) -> Vec<Box<dyn Rule>> {
+        vec![
+            Box::new(Php83ReadonlyClassRule),
+            Box::new(Php82PromoteReadonlyPropertyRule),
+            Box::new(Php80StrContainsRule),
+            Box::new(Php80StrStartsWithRule),
+            Box::new(Php80CreateFunctionClosureRule),
+            Box::new(Php80ParameterOrderRule),
+        ]
+    }
+}
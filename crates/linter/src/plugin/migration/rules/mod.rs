@@ -0,0 +1,6 @@
+pub mod php80_create_function_closure;
+pub mod php80_parameter_order;
+pub mod php80_str_contains;
+pub mod php80_str_starts_with;
+pub mod php_82_promote_readonly_property;
+pub mod php_83_readonly_class;
@@ -0,0 +1,84 @@
+use mago_ast::ast::*;
+use mago_ast::Node;
+use mago_fixer::FixPlan;
+use mago_fixer::SafetyClassification;
+use mago_php_version::PHPVersion;
+use mago_reporting::{Annotation, AnnotationKind, Issue, Level};
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::{RuleDefinition, RuleUsageExample};
+use crate::rule::Rule;
+
+/// Flags `create_function(...)`, removed in PHP 8.0, and offers a closure
+/// in its place.
+///
+/// The fix is marked potentially unsafe rather than safe: it splices the
+/// two string arguments' text directly into a closure's parameter list and
+/// body, which reproduces `create_function`'s own behavior exactly but
+/// can't verify that text is valid PHP on its own - whoever applies it
+/// should give the result a look.
+#[derive(Debug)]
+pub struct Php80CreateFunctionClosureRule;
+
+impl Rule for Php80CreateFunctionClosureRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Create Function Closure", Level::Warning)
+            .with_description("Flags create_function(), removed in PHP 8.0, and suggests a closure.")
+            .with_minimum_supported_php_version(PHPVersion::PHP80)
+            .with_example(RuleUsageExample::invalid(
+                "A create_function() call",
+                r#"
+                <?php
+
+                $add = create_function('$a, $b', 'return $a + $b;');
+                "#,
+            ))
+    }
+
+    fn check(&self, node: Node<'_>, context: &mut LintContext<'_>) {
+        if !context.php_version.is_supported(PHPVersion::PHP80) {
+            return;
+        }
+
+        let Node::Call(Call::Function(call)) = node else {
+            return;
+        };
+
+        let Expression::Identifier(Identifier::Local(identifier)) = call.function.as_ref() else {
+            return;
+        };
+
+        if identifier.value != "create_function" || call.arguments.arguments.len() != 2 {
+            return;
+        }
+
+        let mut positional = call.arguments.arguments.iter().filter_map(|argument| match argument {
+            Argument::Positional(positional) => Some(&positional.value),
+            Argument::Named(_) => None,
+        });
+
+        let (Some(Expression::Literal(Literal::String(parameters))), Some(Expression::Literal(Literal::String(body)))) =
+            (positional.next(), positional.next())
+        else {
+            let issue = Issue::new(Level::Warning, "`create_function()` was removed in PHP 8.0 - use a closure instead")
+                .with_code("migration/php80-create-function-closure")
+                .with_annotation(Annotation::new(call.span(), AnnotationKind::Primary));
+
+            context.report(issue);
+            return;
+        };
+
+        let replacement = format!("function({}) {{ {} }}", parameters.value, body.value);
+
+        let mut plan = FixPlan::new();
+        plan.replace(call.span(), replacement, SafetyClassification::PotentiallyUnsafe);
+
+        context.report(
+            Issue::new(Level::Warning, "`create_function()` was removed in PHP 8.0 - use a closure instead")
+                .with_code("migration/php80-create-function-closure")
+                .with_annotation(Annotation::new(call.span(), AnnotationKind::Primary))
+                .with_fix(plan),
+        );
+    }
+}
@@ -0,0 +1,77 @@
+use mago_ast::ast::*;
+use mago_ast::Node;
+use mago_php_version::PHPVersion;
+use mago_reporting::{Annotation, AnnotationKind, Issue, Level};
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::{RuleDefinition, RuleUsageExample};
+use crate::rule::Rule;
+
+/// Warns about a required parameter declared after an optional one, which
+/// PHP 8.0 deprecated (and implicitly makes the earlier parameter required
+/// at call sites anyway).
+///
+/// No autofix: reordering parameters changes the meaning of every existing
+/// positional call site, so this is left for a human to fix deliberately.
+#[derive(Debug)]
+pub struct Php80ParameterOrderRule;
+
+impl Rule for Php80ParameterOrderRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Parameter Order", Level::Warning)
+            .with_description("Flags a required parameter declared after an optional one.")
+            .with_minimum_supported_php_version(PHPVersion::PHP80)
+            .with_example(RuleUsageExample::invalid(
+                "A required parameter after an optional one",
+                r#"
+                <?php
+
+                function greet($greeting = "Hello", $name) {}
+                "#,
+            ))
+    }
+
+    fn check(&self, node: Node<'_>, context: &mut LintContext<'_>) {
+        if !context.php_version.is_supported(PHPVersion::PHP80) {
+            return;
+        }
+
+        let parameters: &[FunctionLikeParameter] = match node {
+            Node::Function(function) => &function.parameter_list.parameters,
+            Node::Closure(closure) => &closure.parameter_list.parameters,
+            Node::ArrowFunction(arrow_function) => &arrow_function.parameter_list.parameters,
+            Node::Method(method) => &method.parameter_list.parameters,
+            _ => return,
+        };
+
+        let mut seen_optional: Option<&FunctionLikeParameter> = None;
+
+        for parameter in parameters {
+            if is_optional(parameter) {
+                seen_optional = Some(parameter);
+                continue;
+            }
+
+            if parameter.ampersand.is_some() || context.lookup_slice(parameter.span()).trim_start().starts_with("...") {
+                continue;
+            }
+
+            if let Some(optional) = seen_optional {
+                context.report(
+                    Issue::new(Level::Warning, "this required parameter comes after an optional one")
+                        .with_code("migration/php80-parameter-order")
+                        .with_annotation(Annotation::new(parameter.span(), AnnotationKind::Primary))
+                        .with_annotation(
+                            Annotation::new(optional.span(), AnnotationKind::Secondary)
+                                .with_message("the earlier optional parameter"),
+                        ),
+                );
+            }
+        }
+    }
+}
+
+fn is_optional(parameter: &FunctionLikeParameter) -> bool {
+    parameter.default_value.is_some()
+}
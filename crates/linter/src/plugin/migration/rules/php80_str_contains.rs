@@ -0,0 +1,101 @@
+use mago_ast::ast::*;
+use mago_ast::Node;
+use mago_fixer::FixPlan;
+use mago_fixer::SafetyClassification;
+use mago_php_version::PHPVersion;
+use mago_reporting::{Annotation, AnnotationKind, Issue, Level};
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::{RuleDefinition, RuleUsageExample};
+use crate::plugin::migration::rules::php80_str_starts_with::mentions_multibyte_function;
+use crate::rule::Rule;
+
+/// Rewrites `strpos(...) !== false` / `strpos(...) === false` to
+/// `str_contains(...)` / `!str_contains(...)` (PHP 8.0+), which says what
+/// the check actually means instead of leaning on `strpos`'s overloaded
+/// "position, or `false`" return value.
+///
+/// Only fires for a plain two-argument `strpos` call compared directly
+/// against the `false` literal - a three-argument call with an offset, or
+/// a comparison against anything else, isn't something `str_contains`
+/// alone can express.
+///
+/// When [`crate::plugin::migration::config::MigrationConfig::skip_when_multibyte_functions_are_nearby`]
+/// is enabled (the default) and an `mb_*` function call appears near the
+/// comparison in the same file, the rule stays quiet, since the author is
+/// likely relying on byte-vs-character semantics that `str_contains`
+/// wouldn't preserve.
+#[derive(Debug)]
+pub struct Php80StrContainsRule;
+
+impl Rule for Php80StrContainsRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Str Contains", Level::Help)
+            .with_description("Suggests str_contains() in place of strpos(...) !== false / === false.")
+            .with_minimum_supported_php_version(PHPVersion::PHP80)
+            .with_example(RuleUsageExample::invalid(
+                "A strpos-based substring check",
+                r#"
+                <?php
+
+                if (strpos($haystack, $needle) !== false) {
+                }
+                "#,
+            ))
+    }
+
+    fn check(&self, node: Node<'_>, context: &mut LintContext<'_>) {
+        if !context.php_version.is_supported(PHPVersion::PHP80) {
+            return;
+        }
+
+        let Node::Binary(binary) = node else {
+            return;
+        };
+
+        let operator = context.lookup_slice(binary.operator.span());
+        let negate = match operator {
+            "!==" => false,
+            "===" => true,
+            _ => return,
+        };
+
+        let call = match (binary.lhs.as_ref(), binary.rhs.as_ref()) {
+            (Expression::Call(Call::Function(call)), Expression::Literal(Literal::False(_))) => call,
+            (Expression::Literal(Literal::False(_)), Expression::Call(Call::Function(call))) => call,
+            _ => return,
+        };
+
+        let Expression::Identifier(Identifier::Local(identifier)) = call.function.as_ref() else {
+            return;
+        };
+
+        if identifier.value != "strpos" || call.arguments.arguments.len() != 2 {
+            return;
+        }
+
+        if call.arguments.arguments.iter().any(|argument| matches!(argument, Argument::Named(_))) {
+            return;
+        }
+
+        if context.settings().migration.skip_when_multibyte_functions_are_nearby
+            && mentions_multibyte_function(context, binary.span())
+        {
+            return;
+        }
+
+        let arguments_text = context.lookup_slice(call.arguments.span());
+        let replacement = format!("{}str_contains{}", if negate { "!" } else { "" }, arguments_text);
+
+        let mut plan = FixPlan::new();
+        plan.replace(binary.span(), replacement, SafetyClassification::Safe);
+
+        context.report(
+            Issue::new(Level::Help, "this can be written as `str_contains()`")
+                .with_code("migration/php80-str-contains")
+                .with_annotation(Annotation::new(binary.span(), AnnotationKind::Primary))
+                .with_fix(plan),
+        );
+    }
+}
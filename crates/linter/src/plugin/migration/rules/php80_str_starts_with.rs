@@ -0,0 +1,122 @@
+use mago_ast::ast::*;
+use mago_ast::Node;
+use mago_fixer::FixPlan;
+use mago_fixer::SafetyClassification;
+use mago_php_version::PHPVersion;
+use mago_reporting::{Annotation, AnnotationKind, Issue, Level};
+use mago_span::HasSpan;
+use mago_span::Span;
+
+use crate::context::LintContext;
+use crate::definition::{RuleDefinition, RuleUsageExample};
+use crate::rule::Rule;
+
+/// How far around a candidate call site to look, in bytes, for an `mb_*`
+/// function name before concluding the file doesn't care about multibyte
+/// semantics here.
+const MULTIBYTE_LOOKAROUND: u32 = 500;
+
+/// Rewrites `strpos(...) === 0` / `strpos(...) !== 0` to `str_starts_with(...)`
+/// / `!str_starts_with(...)` (PHP 8.0+), which says what the check actually
+/// means instead of comparing `strpos`'s position result against zero.
+///
+/// Only fires for a plain two-argument `strpos` call compared directly
+/// against the integer literal `0` - anything else isn't something
+/// `str_starts_with` alone can express. When
+/// [`crate::plugin::migration::config::MigrationConfig::skip_when_multibyte_functions_are_nearby`]
+/// is enabled (the default) and an `mb_*` function call appears near the
+/// comparison in the same file, the rule stays quiet, since the author is
+/// likely relying on byte-vs-character semantics that `str_starts_with`
+/// wouldn't preserve.
+#[derive(Debug)]
+pub struct Php80StrStartsWithRule;
+
+impl Rule for Php80StrStartsWithRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Str Starts With", Level::Help)
+            .with_description("Suggests str_starts_with() in place of strpos(...) === 0 / !== 0.")
+            .with_minimum_supported_php_version(PHPVersion::PHP80)
+            .with_example(RuleUsageExample::invalid(
+                "A strpos-based prefix check",
+                r#"
+                <?php
+
+                if (strpos($haystack, $needle) === 0) {
+                }
+                "#,
+            ))
+    }
+
+    fn check(&self, node: Node<'_>, context: &mut LintContext<'_>) {
+        if !context.php_version.is_supported(PHPVersion::PHP80) {
+            return;
+        }
+
+        let Node::Binary(binary) = node else {
+            return;
+        };
+
+        let operator = context.lookup_slice(binary.operator.span());
+        let negate = match operator {
+            "===" => false,
+            "!==" => true,
+            _ => return,
+        };
+
+        let call = match (binary.lhs.as_ref(), binary.rhs.as_ref()) {
+            (Expression::Call(Call::Function(call)), Expression::Literal(Literal::Integer(literal)))
+                if literal.value == 0 =>
+            {
+                call
+            }
+            (Expression::Literal(Literal::Integer(literal)), Expression::Call(Call::Function(call)))
+                if literal.value == 0 =>
+            {
+                call
+            }
+            _ => return,
+        };
+
+        let Expression::Identifier(Identifier::Local(identifier)) = call.function.as_ref() else {
+            return;
+        };
+
+        if identifier.value != "strpos" || call.arguments.arguments.len() != 2 {
+            return;
+        }
+
+        if call.arguments.arguments.iter().any(|argument| matches!(argument, Argument::Named(_))) {
+            return;
+        }
+
+        if context.settings().migration.skip_when_multibyte_functions_are_nearby
+            && mentions_multibyte_function(context, binary.span())
+        {
+            return;
+        }
+
+        let arguments_text = context.lookup_slice(call.arguments.span());
+        let replacement = format!("{}str_starts_with{}", if negate { "!" } else { "" }, arguments_text);
+
+        let mut plan = FixPlan::new();
+        plan.replace(binary.span(), replacement, SafetyClassification::Safe);
+
+        context.report(
+            Issue::new(Level::Help, "this can be written as `str_starts_with()`")
+                .with_code("migration/php80-str-starts-with")
+                .with_annotation(Annotation::new(binary.span(), AnnotationKind::Primary))
+                .with_fix(plan),
+        );
+    }
+}
+
+/// Whether the file text around `span` mentions an `mb_` prefixed function
+/// name, used as a cheap stand-in for "the author is relying on multibyte
+/// semantics near this call" - this tree has no notion of "nearby usages"
+/// at the AST or reflection level to ask instead.
+pub(crate) fn mentions_multibyte_function(context: &LintContext<'_>, span: Span) -> bool {
+    let start = span.start.saturating_sub(MULTIBYTE_LOOKAROUND);
+    let end = span.end.saturating_add(MULTIBYTE_LOOKAROUND);
+
+    context.lookup_slice(Span::new(span.file_id, start, end)).contains("mb_")
+}
@@ -0,0 +1,187 @@
+use std::collections::HashMap;
+
+use mago_ast::ast::*;
+use mago_ast::Node;
+use mago_php_version::PHPVersion;
+use mago_reporting::{Annotation, AnnotationKind, Issue, Level};
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::{RuleDefinition, RuleUsageExample};
+use crate::rule::Rule;
+
+/// Suggests promoting a property to `readonly` when it is only ever written
+/// to from inside the declaring class's constructor.
+///
+/// This performs a simple intra-class write analysis: it does not follow
+/// writes through `static::`, reflection, or trait-composed constructors, so
+/// it stays conservative and only fires when every assignment it can see
+/// happens in `__construct`.
+#[derive(Debug)]
+pub struct Php82PromoteReadonlyPropertyRule;
+
+impl Rule for Php82PromoteReadonlyPropertyRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Promote Readonly Property", Level::Help)
+            .with_description(
+                "Suggests marking a property `readonly` when it is only assigned inside the constructor.",
+            )
+            .with_minimum_supported_php_version(PHPVersion::PHP81)
+            .with_example(RuleUsageExample::invalid(
+                "A property only ever assigned in the constructor",
+                r#"
+                <?php
+
+                class Point
+                {
+                    private float $x;
+
+                    public function __construct(float $x)
+                    {
+                        $this->x = $x;
+                    }
+                }
+                "#,
+            ))
+    }
+
+    fn check(&self, node: Node<'_>, context: &mut LintContext<'_>) {
+        let Node::Class(class) = node else {
+            return;
+        };
+
+        if !context.php_version.is_supported(PHPVersion::PHP81) {
+            return;
+        }
+
+        let declared: HashMap<&str, &PropertyItem> = class
+            .members
+            .iter()
+            .filter_map(|member| match member {
+                ClassLikeMember::Property(property) if !property.modifiers.contains_readonly() => {
+                    Some(property.items.iter())
+                }
+                _ => None,
+            })
+            .flatten()
+            .map(|item| (item.variable().name.trim_start_matches('$'), item))
+            .collect();
+
+        if declared.is_empty() {
+            return;
+        }
+
+        let mut assigned_outside_constructor = std::collections::HashSet::new();
+        let mut assigned_in_constructor = std::collections::HashSet::new();
+
+        for member in class.members.iter() {
+            let ClassLikeMember::Method(method) = member else {
+                continue;
+            };
+
+            let is_constructor = method.name.value.eq_ignore_ascii_case("__construct");
+            for assignment in find_property_assignments(method) {
+                if is_constructor {
+                    assigned_in_constructor.insert(assignment);
+                } else {
+                    assigned_outside_constructor.insert(assignment);
+                }
+            }
+        }
+
+        for (name, item) in declared {
+            if assigned_outside_constructor.contains(name) {
+                continue;
+            }
+
+            if !assigned_in_constructor.contains(name) {
+                continue;
+            }
+
+            let issue = Issue::new(Level::Help, format!("property `${name}` is only assigned in the constructor"))
+                .with_code("migration/php82-promote-readonly-property")
+                .with_annotation(
+                    Annotation::new(item.variable().span(), AnnotationKind::Primary)
+                        .with_message("this property can be marked `readonly`"),
+                );
+
+            context.report(issue);
+        }
+    }
+}
+
+/// Collects the names (without the leading `$`) of properties assigned via
+/// `$this->name = ...` anywhere in the given method's body, including
+/// inside nested `if`/loop/`switch`/`try` bodies, but not inside a nested
+/// closure or arrow function's own body.
+fn find_property_assignments(method: &Method) -> Vec<&str> {
+    let mut names = Vec::new();
+
+    let Some(body) = method.body.as_statements() else {
+        return names;
+    };
+
+    for statement in body {
+        collect_property_assignments(statement, &mut names);
+    }
+
+    names
+}
+
+fn collect_property_assignments<'a>(statement: &'a Statement, names: &mut Vec<&'a str>) {
+    match statement {
+        Statement::Block(block) => {
+            for inner in &block.statements {
+                collect_property_assignments(inner, names);
+            }
+        }
+        Statement::If(r#if) => {
+            collect_property_assignments(&r#if.body, names);
+            for clause in &r#if.else_if_clauses {
+                collect_property_assignments(&clause.body, names);
+            }
+            if let Some(else_clause) = &r#if.else_clause {
+                collect_property_assignments(&else_clause.body, names);
+            }
+        }
+        Statement::While(r#while) => collect_property_assignments(&r#while.body, names),
+        Statement::DoWhile(do_while) => collect_property_assignments(&do_while.body, names),
+        Statement::For(r#for) => collect_property_assignments(&r#for.body, names),
+        Statement::Foreach(foreach) => collect_property_assignments(&foreach.body, names),
+        Statement::Switch(switch) => {
+            for case in switch.body.cases() {
+                for inner in case.statements() {
+                    collect_property_assignments(inner, names);
+                }
+            }
+        }
+        Statement::Try(r#try) => {
+            for inner in &r#try.block.statements {
+                collect_property_assignments(inner, names);
+            }
+            for clause in &r#try.catch_clauses {
+                for inner in &clause.block.statements {
+                    collect_property_assignments(inner, names);
+                }
+            }
+            if let Some(finally) = &r#try.finally_clause {
+                for inner in &finally.block.statements {
+                    collect_property_assignments(inner, names);
+                }
+            }
+        }
+        Statement::Expression(expression_statement) => {
+            if let Expression::Assignment(assignment) = expression_statement.expression.as_ref() {
+                if let Expression::Access(Access::Property(access)) = assignment.lhs.as_ref() {
+                    if matches!(access.object.as_ref(), Expression::Variable(Variable::Direct(v)) if v.name == "$this")
+                    {
+                        if let ClassLikeMemberSelector::Identifier(identifier) = &access.property {
+                            names.push(identifier.value.as_str());
+                        }
+                    }
+                }
+            }
+        }
+        _ => {}
+    }
+}
@@ -0,0 +1,98 @@
+use mago_ast::ast::*;
+use mago_ast::Node;
+use mago_php_version::PHPVersion;
+use mago_reporting::{Annotation, AnnotationKind, Issue, Level};
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::{RuleDefinition, RuleUsageExample};
+use crate::rule::Rule;
+
+/// Suggests marking a class `readonly` when every one of its declared
+/// properties is already `readonly`, which is equivalent but communicates
+/// the class's immutability contract more directly (PHP 8.2+).
+#[derive(Debug)]
+pub struct Php83ReadonlyClassRule;
+
+impl Rule for Php83ReadonlyClassRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Readonly Class", Level::Help)
+            .with_description(
+                "Suggests declaring a class `readonly` when all of its properties are already `readonly`.",
+            )
+            .with_minimum_supported_php_version(PHPVersion::PHP82)
+            .with_example(RuleUsageExample::valid(
+                "A class marked `readonly` as a whole",
+                r#"
+                <?php
+
+                readonly class Point
+                {
+                    public function __construct(
+                        public float $x,
+                        public float $y,
+                    ) {}
+                }
+                "#,
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "A class where every property is individually `readonly`",
+                r#"
+                <?php
+
+                class Point
+                {
+                    public readonly float $x;
+                    public readonly float $y;
+                }
+                "#,
+            ))
+    }
+
+    fn check(&self, node: Node<'_>, context: &mut LintContext<'_>) {
+        let Node::Class(class) = node else {
+            return;
+        };
+
+        if class.modifiers.contains_readonly() {
+            return;
+        }
+
+        if !context.php_version.is_supported(PHPVersion::PHP82) {
+            return;
+        }
+
+        let properties: Vec<&PropertyItem> = class
+            .members
+            .iter()
+            .filter_map(|member| match member {
+                ClassLikeMember::Property(property) => Some(property),
+                _ => None,
+            })
+            .flat_map(|property| property.items.iter())
+            .collect();
+
+        if properties.is_empty() {
+            return;
+        }
+
+        let all_readonly = class.members.iter().all(|member| match member {
+            ClassLikeMember::Property(property) => property.modifiers.contains_readonly(),
+            _ => true,
+        });
+
+        if !all_readonly {
+            return;
+        }
+
+        let issue = Issue::new(Level::Help, "all properties of this class are `readonly`")
+            .with_code("migration/php83-readonly-class")
+            .with_annotation(
+                Annotation::new(class.name.span(), AnnotationKind::Primary)
+                    .with_message("consider marking this class `readonly` instead"),
+            )
+            .with_note("declaring the class `readonly` communicates immutability at the class level and removes the need to repeat the modifier on every property.");
+
+        context.report(issue);
+    }
+}
@@ -0,0 +1,43 @@
+use std::collections::HashMap;
+
+use regex::Regex;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// The kind of symbol a naming convention applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SymbolKind {
+    Class,
+    Interface,
+    Trait,
+    Enum,
+    EnumCase,
+    Function,
+    Method,
+    Property,
+    Constant,
+    Parameter,
+    Variable,
+}
+
+/// User-supplied regex conventions, one pattern per [`SymbolKind`].
+///
+/// Kinds without an entry are left unchecked; this lets a project constrain
+/// only the symbol kinds it cares about, rather than having to name every
+/// kind it wants to leave alone.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NamingConfig {
+    #[serde(default)]
+    patterns: HashMap<SymbolKind, String>,
+}
+
+impl NamingConfig {
+    /// Compiles the pattern configured for `kind`, if any.
+    ///
+    /// Returns an error rather than silently ignoring the entry when the
+    /// configured regex fails to compile, since a typo here should be loud.
+    pub fn pattern_for(&self, kind: SymbolKind) -> Option<Result<Regex, regex::Error>> {
+        self.patterns.get(&kind).map(|pattern| Regex::new(pattern))
+    }
+}
@@ -0,0 +1,26 @@
+use crate::plugin::LintPlugin;
+use crate::plugin::naming::rules::configurable_convention::ConfigurableConventionRule;
+use crate::rule::Rule;
+
+pub mod config;
+pub mod rules;
+
+/// Enforces naming conventions. Unlike most plugins, its single rule is
+/// almost entirely configuration-driven: the convention per symbol kind
+/// comes from [`config::NamingConfig`] rather than being hardcoded per rule.
+#[derive(Debug)]
+pub struct NamingPlugin;
+
+impl LintPlugin for NamingPlugin {
+    fn get_name(&self) -> &'static str {
+        "naming"
+    }
+
+    fn is_enabled_by_default(&self) -> bool {
+        false
+    }
+
+    fn get_rules(&self) -> Vec<Box<dyn Rule>> {
+        vec![Box::new(ConfigurableConventionRule)]
+    }
+}
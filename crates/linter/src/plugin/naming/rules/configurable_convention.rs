@@ -0,0 +1,68 @@
+use mago_ast::Node;
+use mago_reporting::{Annotation, AnnotationKind, Issue, Level};
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::plugin::naming::config::SymbolKind;
+use crate::rule::Rule;
+
+/// Checks the names of declared classes, interfaces, traits, enums and
+/// functions against the regex configured for their [`SymbolKind`] in
+/// `naming.patterns`. Patterns configured for the other kinds (enum cases,
+/// methods, properties, constants, parameters, variables) are not checked
+/// by this rule.
+#[derive(Debug)]
+pub struct ConfigurableConventionRule;
+
+impl Rule for ConfigurableConventionRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Configurable Naming Convention", Level::Warning)
+            .with_description("Checks declared symbol names against a regex configured per symbol kind.")
+    }
+
+    fn check(&self, node: Node<'_>, context: &mut LintContext<'_>) {
+        let Some((kind, name, span)) = symbol_of(node) else {
+            return;
+        };
+
+        let Some(pattern) = context.settings().naming.pattern_for(kind) else {
+            return;
+        };
+
+        let pattern = match pattern {
+            Ok(pattern) => pattern,
+            Err(error) => {
+                let issue = Issue::new(Level::Error, format!("the naming pattern configured for {kind:?} is not a valid regex: {error}"))
+                    .with_code("naming/configurable-convention")
+                    .with_annotation(Annotation::new(span, AnnotationKind::Primary));
+
+                context.report(issue);
+                return;
+            }
+        };
+
+        if pattern.is_match(name) {
+            return;
+        }
+
+        let issue = Issue::new(Level::Warning, format!("`{name}` does not match the configured naming convention for {kind:?}"))
+            .with_code("naming/configurable-convention")
+            .with_annotation(
+                Annotation::new(span, AnnotationKind::Primary).with_message(format!("expected to match `{}`", pattern.as_str())),
+            );
+
+        context.report(issue);
+    }
+}
+
+fn symbol_of(node: Node<'_>) -> Option<(SymbolKind, &str, mago_span::Span)> {
+    match node {
+        Node::Class(class) => Some((SymbolKind::Class, class.name.value.as_str(), class.name.span())),
+        Node::Interface(interface) => Some((SymbolKind::Interface, interface.name.value.as_str(), interface.name.span())),
+        Node::Trait(r#trait) => Some((SymbolKind::Trait, r#trait.name.value.as_str(), r#trait.name.span())),
+        Node::Enum(r#enum) => Some((SymbolKind::Enum, r#enum.name.value.as_str(), r#enum.name.span())),
+        Node::Function(function) => Some((SymbolKind::Function, function.name.value.as_str(), function.name.span())),
+        _ => None,
+    }
+}
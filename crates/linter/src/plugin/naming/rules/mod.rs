@@ -0,0 +1 @@
+pub mod configurable_convention;
@@ -0,0 +1,25 @@
+use crate::plugin::LintPlugin;
+use crate::plugin::performance::rules::count_in_loop_condition::CountInLoopConditionRule;
+use crate::plugin::performance::rules::string_concat_in_loop::StringConcatInLoopRule;
+use crate::rule::Rule;
+
+pub mod rules;
+
+/// Rules that flag patterns which are correct but needlessly slow, most of
+/// them variations on "this recomputes something a loop doesn't need to".
+#[derive(Debug)]
+pub struct PerformancePlugin;
+
+impl LintPlugin for PerformancePlugin {
+    fn get_name(&self) -> &'static str {
+        "performance"
+    }
+
+    fn is_enabled_by_default(&self) -> bool {
+        false
+    }
+
+    fn get_rules(&self) -> Vec<Box<dyn Rule>> {
+        vec![Box::new(CountInLoopConditionRule), Box::new(StringConcatInLoopRule)]
+    }
+}
@@ -0,0 +1,79 @@
+use mago_ast::ast::*;
+use mago_ast::Node;
+use mago_reporting::{Annotation, AnnotationKind, Issue, Level};
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::{RuleDefinition, RuleUsageExample};
+use crate::rule::Rule;
+
+/// Flags `count()`/`sizeof()` called directly inside a `for` loop's
+/// condition, where it is re-evaluated on every iteration even though the
+/// collection being measured isn't mutated in the loop header.
+#[derive(Debug)]
+pub struct CountInLoopConditionRule;
+
+impl Rule for CountInLoopConditionRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Count In Loop Condition", Level::Warning)
+            .with_description("Flags `count()`/`sizeof()` calls in a `for` loop condition, re-evaluated on every iteration.")
+            .with_example(RuleUsageExample::invalid(
+                "Recomputing the array length every iteration",
+                r#"
+                <?php
+
+                for ($i = 0; $i < count($items); $i++) {
+                    process($items[$i]);
+                }
+                "#,
+            ))
+    }
+
+    fn check(&self, node: Node<'_>, context: &mut LintContext<'_>) {
+        let Node::For(r#for) = node else {
+            return;
+        };
+
+        for condition in r#for.conditions.iter() {
+            check_expression(context, condition);
+        }
+    }
+}
+
+/// Recurses into the condition expression's known compound shapes - a
+/// comparison (`Binary`) and a call's own arguments - so `count()` is still
+/// flagged when it's nested inside something like `$i < count($items) - 1`.
+fn check_expression(context: &mut LintContext<'_>, expression: &Expression) {
+    match expression {
+        Expression::Call(Call::Function(call)) => {
+            if let Expression::Identifier(Identifier::Local(identifier)) = call.function.as_ref() {
+                if matches!(identifier.value.as_str(), "count" | "sizeof") {
+                    let issue = Issue::new(Level::Warning, format!("`{}()` is re-evaluated on every iteration of this loop", identifier.value))
+                        .with_code("performance/count-in-loop-condition")
+                        .with_annotation(
+                            Annotation::new(call.span(), AnnotationKind::Primary)
+                                .with_message("hoist this into a variable declared before the loop"),
+                        );
+
+                    context.report(issue);
+                }
+            }
+
+            for argument in &call.arguments.arguments {
+                check_expression(context, argument_value(argument));
+            }
+        }
+        Expression::Binary(binary) => {
+            check_expression(context, &binary.lhs);
+            check_expression(context, &binary.rhs);
+        }
+        _ => {}
+    }
+}
+
+fn argument_value(argument: &Argument) -> &Expression {
+    match argument {
+        Argument::Positional(positional) => &positional.value,
+        Argument::Named(named) => &named.value,
+    }
+}
@@ -0,0 +1,2 @@
+pub mod count_in_loop_condition;
+pub mod string_concat_in_loop;
@@ -0,0 +1,104 @@
+use mago_ast::ast::*;
+use mago_ast::Node;
+use mago_reporting::{Annotation, AnnotationKind, Issue, Level};
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::{RuleDefinition, RuleUsageExample};
+use crate::rule::Rule;
+
+/// Flags `$var .= ...` inside a loop body, suggesting an array buffer
+/// joined with `implode()` once after the loop instead.
+///
+/// Repeated string concatenation reallocates and copies the whole string on
+/// every append; for anything but a handful of iterations, collecting the
+/// pieces and joining once is both faster and clearer about intent.
+#[derive(Debug)]
+pub struct StringConcatInLoopRule;
+
+impl Rule for StringConcatInLoopRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("String Concat In Loop", Level::Note)
+            .with_description("Suggests collecting pieces in an array and joining once, instead of repeated string concatenation in a loop.")
+            .with_example(RuleUsageExample::invalid(
+                "Building a string incrementally inside a loop",
+                r#"
+                <?php
+
+                $html = '';
+                foreach ($rows as $row) {
+                    $html .= render($row);
+                }
+                "#,
+            ))
+    }
+
+    fn check(&self, node: Node<'_>, context: &mut LintContext<'_>) {
+        let body = match node {
+            Node::Foreach(foreach) => &foreach.body,
+            Node::While(r#while) => &r#while.body,
+            Node::For(r#for) => &r#for.body,
+            _ => return,
+        };
+
+        for statement in body.statements() {
+            check_statement(context, statement);
+        }
+    }
+}
+
+/// Recurses into the loop body's nested control-flow statements (an
+/// `if`/`while`/... inside the loop can still contain the concatenation
+/// this rule looks for) without crossing into a nested closure's own body.
+fn check_statement(context: &mut LintContext<'_>, statement: &Statement) {
+    match statement {
+        Statement::Block(block) => {
+            for inner in &block.statements {
+                check_statement(context, inner);
+            }
+        }
+        Statement::If(r#if) => {
+            check_statement(context, &r#if.body);
+            for clause in &r#if.else_if_clauses {
+                check_statement(context, &clause.body);
+            }
+            if let Some(else_clause) = &r#if.else_clause {
+                check_statement(context, &else_clause.body);
+            }
+        }
+        Statement::While(r#while) => check_statement(context, &r#while.body),
+        Statement::DoWhile(do_while) => check_statement(context, &do_while.body),
+        Statement::For(r#for) => check_statement(context, &r#for.body),
+        Statement::Foreach(foreach) => check_statement(context, &foreach.body),
+        Statement::Switch(switch) => {
+            for case in switch.body.cases() {
+                for inner in case.statements() {
+                    check_statement(context, inner);
+                }
+            }
+        }
+        Statement::Expression(expression_statement) => {
+            check_expression(context, &expression_statement.expression);
+        }
+        _ => {}
+    }
+}
+
+fn check_expression(context: &mut LintContext<'_>, expression: &Expression) {
+    let Expression::AssignmentOperation(assignment) = expression else {
+        return;
+    };
+
+    if assignment.operator != AssignmentOperator::Concat {
+        return;
+    }
+
+    let issue = Issue::new(Level::Note, "string concatenation inside a loop reallocates on every iteration")
+        .with_code("performance/string-concat-in-loop")
+        .with_annotation(
+            Annotation::new(assignment.span(), AnnotationKind::Primary)
+                .with_message("consider appending to an array and calling `implode()` once, after the loop"),
+        );
+
+    context.report(issue);
+}
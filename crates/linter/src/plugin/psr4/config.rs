@@ -0,0 +1,78 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// A single namespace-prefix to directory mapping, as declared under
+/// `autoload.psr-4` in `composer.json`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Psr4Mapping {
+    pub namespace_prefix: String,
+    pub directory: String,
+}
+
+/// Configuration for the `psr4` plugin.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Psr4Config {
+    /// Namespace-prefix/directory mappings used by
+    /// [`crate::plugin::psr4::rules::one_class_per_file::OneClassPerFileRule`]
+    /// to check a declared namespace against the file it's declared in.
+    ///
+    /// Populated manually, or read from composer.json's `autoload.psr-4` map.
+    #[serde(default)]
+    pub namespace_mappings: Vec<Psr4Mapping>,
+}
+
+impl Psr4Config {
+    /// Builds the configuration directly from a parsed `composer.json`,
+    /// for projects that don't want to duplicate their autoload map in
+    /// mago's own configuration.
+    pub fn from_composer_manifest(manifest: &mago_composer::ComposerManifest) -> Self {
+        Self {
+            namespace_mappings: manifest
+                .psr4_mappings()
+                .iter()
+                .map(|mapping| Psr4Mapping { namespace_prefix: mapping.namespace_prefix.clone(), directory: mapping.directory.clone() })
+                .collect(),
+        }
+    }
+
+    /// Finds the mapping whose prefix covers `namespace`, preferring the
+    /// longest (most specific) matching prefix when more than one applies.
+    pub fn mapping_for(&self, namespace: &str) -> Option<&Psr4Mapping> {
+        self.namespace_mappings
+            .iter()
+            .filter(|mapping| {
+                let prefix = mapping.namespace_prefix.trim_end_matches('\\');
+
+                namespace == prefix || namespace.starts_with(&format!("{prefix}\\"))
+            })
+            .max_by_key(|mapping| mapping.namespace_prefix.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn picks_the_most_specific_matching_prefix() {
+        let config = Psr4Config {
+            namespace_mappings: vec![
+                Psr4Mapping { namespace_prefix: "App\\".to_string(), directory: "src".to_string() },
+                Psr4Mapping { namespace_prefix: "App\\Http\\".to_string(), directory: "src/Http".to_string() },
+            ],
+        };
+
+        let mapping = config.mapping_for("App\\Http\\Controllers").unwrap();
+
+        assert_eq!(mapping.directory, "src/Http");
+    }
+
+    #[test]
+    fn a_namespace_outside_every_prefix_has_no_mapping() {
+        let config = Psr4Config {
+            namespace_mappings: vec![Psr4Mapping { namespace_prefix: "App\\".to_string(), directory: "src".to_string() }],
+        };
+
+        assert!(config.mapping_for("Vendor\\Package").is_none());
+    }
+}
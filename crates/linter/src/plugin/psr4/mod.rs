@@ -0,0 +1,26 @@
+use crate::plugin::LintPlugin;
+use crate::plugin::psr4::rules::one_class_per_file::OneClassPerFileRule;
+use crate::rule::Rule;
+
+pub mod config;
+pub mod rules;
+
+/// PSR-4 autoloading consistency: at most one class-like symbol per file,
+/// with the file name and (optionally) the declaring namespace matching
+/// where the autoloader expects to find it.
+#[derive(Debug)]
+pub struct Psr4Plugin;
+
+impl LintPlugin for Psr4Plugin {
+    fn get_name(&self) -> &'static str {
+        "psr4"
+    }
+
+    fn is_enabled_by_default(&self) -> bool {
+        false
+    }
+
+    fn get_rules(&self) -> Vec<Box<dyn Rule>> {
+        vec![Box::new(OneClassPerFileRule)]
+    }
+}
@@ -0,0 +1 @@
+pub mod one_class_per_file;
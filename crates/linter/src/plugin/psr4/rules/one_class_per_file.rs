@@ -0,0 +1,170 @@
+use std::path::Path;
+
+use mago_ast::ast::*;
+use mago_ast::Node;
+use mago_reporting::{Annotation, AnnotationKind, Issue, Level};
+use mago_span::{HasSpan, Span};
+
+use crate::context::LintContext;
+use crate::definition::{RuleDefinition, RuleUsageExample};
+use crate::rule::Rule;
+
+/// Enforces the PSR-4 expectation that a file declares at most one
+/// class-like symbol, and that the file (and, where a mapping is
+/// configured, the namespace) match where the autoloader expects to find
+/// it.
+///
+/// A second class-like in the same file can never be autoloaded by its own
+/// name: the autoloader only knows to look in this file for the one whose
+/// name matches the file name.
+#[derive(Debug)]
+pub struct OneClassPerFileRule;
+
+struct ClassLike<'a> {
+    kind: &'static str,
+    name: &'a str,
+    namespace: &'a str,
+    span: Span,
+}
+
+impl Rule for OneClassPerFileRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("One Class Per File", Level::Warning)
+            .with_description(
+                "Requires at most one class-like symbol per file, with the file name matching the symbol name (PSR-4).",
+            )
+            .with_example(RuleUsageExample::invalid(
+                "Two classes declared in the same file",
+                r#"
+                <?php
+
+                class Foo {}
+                class Bar {}
+                "#,
+            ))
+    }
+
+    fn check(&self, node: Node<'_>, context: &mut LintContext<'_>) {
+        let Node::Program(program) = node else {
+            return;
+        };
+
+        let mut class_likes = Vec::new();
+        collect_class_likes(program.statements.iter(), "", &mut class_likes);
+
+        if class_likes.is_empty() {
+            return;
+        }
+
+        if class_likes.len() > 1 {
+            for class_like in class_likes.iter().skip(1) {
+                context.report(
+                    Issue::new(
+                        Level::Warning,
+                        format!("only one class-like symbol is allowed per file, found an extra {}", class_like.kind),
+                    )
+                    .with_code("psr4/one-class-per-file")
+                    .with_annotation(
+                        Annotation::new(class_like.span, AnnotationKind::Primary)
+                            .with_message(format!("move `{}` to its own file", class_like.name)),
+                    ),
+                );
+            }
+
+            return;
+        }
+
+        let class_like = &class_likes[0];
+        let path = context.file_path();
+
+        if let Some(file_stem) = Path::new(path).file_stem().and_then(|stem| stem.to_str()) {
+            if file_stem != class_like.name {
+                context.report(
+                    Issue::new(
+                        Level::Warning,
+                        format!("file name `{file_stem}` does not match the declared {} `{}`", class_like.kind, class_like.name),
+                    )
+                    .with_code("psr4/one-class-per-file")
+                    .with_annotation(
+                        Annotation::new(class_like.span, AnnotationKind::Primary)
+                            .with_message(format!("rename the file to `{}.php`", class_like.name)),
+                    ),
+                );
+            }
+        }
+
+        if class_like.namespace.is_empty() {
+            return;
+        }
+
+        let Some(mapping) = context.settings().psr4.mapping_for(class_like.namespace) else {
+            return;
+        };
+
+        let remainder = class_like.namespace[mapping.namespace_prefix.trim_end_matches('\\').len()..].trim_start_matches('\\');
+        let expected_directory = if remainder.is_empty() {
+            mapping.directory.clone()
+        } else {
+            format!("{}/{}", mapping.directory, remainder.replace('\\', "/"))
+        };
+
+        if !Path::new(path).parent().is_some_and(|parent| parent.ends_with(Path::new(&expected_directory))) {
+            context.report(
+                Issue::new(
+                    Level::Warning,
+                    format!("namespace `{}` does not match the configured PSR-4 directory `{expected_directory}`", class_like.namespace),
+                )
+                .with_code("psr4/one-class-per-file")
+                .with_annotation(
+                    Annotation::new(class_like.span, AnnotationKind::Primary)
+                        .with_message(format!("expected this file under `{expected_directory}`")),
+                ),
+            );
+        }
+    }
+}
+
+fn collect_class_likes<'a>(
+    statements: impl Iterator<Item = &'a Statement>,
+    namespace: &'a str,
+    class_likes: &mut Vec<ClassLike<'a>>,
+) {
+    let mut current_namespace = namespace;
+
+    for statement in statements {
+        match statement {
+            Statement::Namespace(namespace_statement) => {
+                let name = namespace_statement.name.as_ref().map(|name| name.value.as_str()).unwrap_or("");
+
+                if namespace_statement.statements.is_empty() {
+                    current_namespace = name;
+                } else {
+                    collect_class_likes(namespace_statement.statements.iter(), name, class_likes);
+                }
+            }
+            Statement::Class(class) => {
+                class_likes.push(ClassLike { kind: "class", name: &class.name.value, namespace: current_namespace, span: class.name.span() });
+            }
+            Statement::Interface(interface) => {
+                class_likes.push(ClassLike {
+                    kind: "interface",
+                    name: &interface.name.value,
+                    namespace: current_namespace,
+                    span: interface.name.span(),
+                });
+            }
+            Statement::Trait(r#trait) => {
+                class_likes.push(ClassLike {
+                    kind: "trait",
+                    name: &r#trait.name.value,
+                    namespace: current_namespace,
+                    span: r#trait.name.span(),
+                });
+            }
+            Statement::Enum(r#enum) => {
+                class_likes.push(ClassLike { kind: "enum", name: &r#enum.name.value, namespace: current_namespace, span: r#enum.name.span() });
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use crate::plugin::LintPlugin;
+use crate::plugin::redundancy::rules::dead_store::DeadStoreRule;
+use crate::plugin::redundancy::rules::duplicated_branches::DuplicatedConditionBranchRule;
+use crate::plugin::redundancy::rules::unused_private_member::UnusedPrivateMemberRule;
+use crate::rule::Rule;
+
+pub mod rules;
+
+/// Rules that flag code which exists but can never be observed from
+/// anywhere: unused private members, dead branches, and the like.
+#[derive(Debug)]
+pub struct RedundancyPlugin;
+
+impl LintPlugin for RedundancyPlugin {
+    fn get_name(&self) -> &'static str {
+        "redundancy"
+    }
+
+    fn is_enabled_by_default(&self) -> bool {
+        true
+    }
+
+    fn get_rules(&self) -> Vec<Box<dyn Rule>> {
+        vec![Box::new(UnusedPrivateMemberRule), Box::new(DeadStoreRule), Box::new(DuplicatedConditionBranchRule)]
+    }
+}
@@ -0,0 +1,431 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use mago_ast::ast::*;
+use mago_ast::purity::has_side_effects;
+use mago_ast::Node;
+use mago_fixer::FixPlan;
+use mago_fixer::SafetyClassification;
+use mago_reporting::{Annotation, AnnotationKind, Issue, Level};
+use mago_span::HasSpan;
+use mago_span::Span;
+
+use crate::context::LintContext;
+use crate::definition::{RuleDefinition, RuleUsageExample};
+use crate::rule::Rule;
+
+const SUPERGLOBALS: &[&str] =
+    &["$GLOBALS", "$_GET", "$_POST", "$_SERVER", "$_SESSION", "$_COOKIE", "$_FILES", "$_ENV", "$_REQUEST"];
+
+const VARIABLE_ESCAPE_HATCHES: &[&str] =
+    &["compact", "extract", "func_get_args", "func_get_arg", "func_num_args", "get_defined_vars"];
+
+/// Flags a simple `$variable = ...` assignment that is unconditionally
+/// overwritten by a later assignment to the same variable with no read in
+/// between, or that is never read again before the function returns.
+///
+/// This is a deliberately conservative, straight-line approximation rather
+/// than a true reaching-definitions analysis over a control-flow graph:
+/// entering any `if`/`while`/`do`-`while`/`for`/`foreach`/`switch`/`try`
+/// forgets everything we were tracking, so a dead store that's only
+/// provably dead once branching is taken into account is missed rather
+/// than risking a false positive. A function with a `global` statement at
+/// the top level of its body, variable variables (`$$name`), or one of
+/// `compact()`/`extract()`/`func_get_args()`/`get_defined_vars()` is skipped
+/// entirely, since any of those can read a variable by name in a way this
+/// rule can't see; a `global` statement nested inside a branch or loop does
+/// not cause the skip. By-reference variables
+/// and superglobals are never tracked, since a write to either is
+/// observable outside the function even without a local read.
+#[derive(Debug)]
+pub struct DeadStoreRule;
+
+impl Rule for DeadStoreRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Dead Store", Level::Warning)
+            .with_description("Flags an assignment whose value is always overwritten or never read.")
+            .with_example(RuleUsageExample::invalid(
+                "A variable reassigned before its first value is ever used",
+                r#"
+                <?php
+
+                $total = compute_initial();
+                $total = compute_again();
+
+                return $total;
+                "#,
+            ))
+    }
+
+    fn check(&self, node: Node<'_>, context: &mut LintContext<'_>) {
+        let statements = match node {
+            Node::Function(function) => function.body.statements.as_slice(),
+            Node::Closure(closure) => closure.body.statements.as_slice(),
+            Node::Method(method) => match method.body.as_statements() {
+                Some(statements) => statements,
+                None => return,
+            },
+            _ => return,
+        };
+
+        if statements.is_empty() || uses_variable_escape_hatch(statements) {
+            return;
+        }
+
+        let by_reference = by_reference_parameter_names(node);
+
+        let mut pending = HashMap::new();
+        analyze_sequential(context, &by_reference, statements, &mut pending);
+
+        for (name, (span, rhs)) in pending {
+            report(context, span, rhs, &format!("the value assigned to `{name}` here is never read"));
+        }
+    }
+}
+
+/// A pending write still waiting on its first read, and the expression that
+/// produced it (so a report can decide whether removing it is safe).
+type Pending<'a> = HashMap<String, (Span, &'a Expression)>;
+
+fn analyze_sequential<'a>(
+    context: &mut LintContext<'a>,
+    by_reference: &HashSet<String>,
+    statements: &'a [Statement],
+    pending: &mut Pending<'a>,
+) {
+    for statement in statements {
+        match statement {
+            Statement::Block(block) => analyze_sequential(context, by_reference, &block.statements, pending),
+            _ if is_branching(statement) => {
+                // We don't attempt to reason across a branch or loop; any
+                // pending write might be read somewhere inside it, so just
+                // forget it rather than risk a false positive.
+                pending.clear();
+            }
+            _ => analyze_statement(context, by_reference, statement, pending),
+        }
+    }
+}
+
+fn analyze_statement<'a>(
+    context: &mut LintContext<'a>,
+    by_reference: &HashSet<String>,
+    statement: &'a Statement,
+    pending: &mut Pending<'a>,
+) {
+    if let Statement::Expression(expression_statement) = statement {
+        if let Expression::Assignment(assignment) = expression_statement.expression.as_ref() {
+            if assignment.operator == AssignmentOperator::Assign {
+                if let Some(name) = simple_target(&assignment.lhs, by_reference) {
+                    if is_reference_expression(context, &assignment.rhs) {
+                        return;
+                    }
+
+                    consume_reads_in_expression(by_reference, &assignment.rhs, pending);
+
+                    if let Some((previous_span, previous_rhs)) = pending.insert(name, (statement.span(), &assignment.rhs)) {
+                        report(
+                            context,
+                            previous_span,
+                            previous_rhs,
+                            "this assignment's value is overwritten below before it is ever read",
+                        );
+                    }
+                    return;
+                }
+            }
+        }
+
+        if let Expression::AssignmentOperation(assignment) = expression_statement.expression.as_ref() {
+            if let Some(name) = simple_target(&assignment.lhs, by_reference) {
+                // A compound assignment (`+=`, `.=`, ...) reads the
+                // previous value before writing the new one, so it always
+                // counts as the read that clears any pending write.
+                pending.remove(&name);
+                consume_reads_in_expression(by_reference, &assignment.rhs, pending);
+                pending.insert(name, (statement.span(), &assignment.rhs));
+                return;
+            }
+        }
+    }
+
+    // Anything else: conservatively treat every variable mentioned as read,
+    // clearing it from `pending` without adding a new candidate. This under-
+    // detects dead stores inside more complex statements, but never reports
+    // one that isn't real.
+    consume_reads_in_statement(by_reference, statement, pending);
+}
+
+fn simple_target(expression: &Expression, by_reference: &HashSet<String>) -> Option<String> {
+    let Expression::Variable(Variable::Direct(variable)) = expression else {
+        return None;
+    };
+
+    let name = normalize(&variable.name);
+    if by_reference.contains(&name) || SUPERGLOBALS.contains(&name.as_str()) {
+        return None;
+    }
+
+    Some(name)
+}
+
+fn is_reference_expression(context: &LintContext<'_>, expression: &Expression) -> bool {
+    context.lookup_slice(expression.span()).trim_start().starts_with('&')
+}
+
+/// Marks every variable read by `expression`, including inside any
+/// subexpression this analysis knows how to open up, clearing it from
+/// `pending`.
+fn consume_reads_in_expression(by_reference: &HashSet<String>, expression: &Expression, pending: &mut Pending<'_>) {
+    walk_expression(expression, &mut |candidate| mark_read(by_reference, candidate, pending));
+}
+
+/// Same as [`consume_reads_in_expression`], but for every expression reachable
+/// from `statement`.
+fn consume_reads_in_statement(by_reference: &HashSet<String>, statement: &Statement, pending: &mut Pending<'_>) {
+    walk_statement(statement, &mut |candidate| mark_read(by_reference, candidate, pending));
+}
+
+fn mark_read(by_reference: &HashSet<String>, expression: &Expression, pending: &mut Pending<'_>) {
+    if let Expression::Variable(Variable::Direct(variable)) = expression {
+        let name = normalize(&variable.name);
+        if !by_reference.contains(&name) {
+            pending.remove(&name);
+        }
+    }
+}
+
+/// Walks `statement` and every nested statement/expression this analysis
+/// knows how to open up, feeding each expression (not the statements
+/// themselves) to `f`.
+fn walk_statement<'a>(statement: &'a Statement, f: &mut impl FnMut(&'a Expression)) {
+    match statement {
+        Statement::Block(block) => {
+            for inner in &block.statements {
+                walk_statement(inner, f);
+            }
+        }
+        Statement::If(r#if) => {
+            walk_expression(&r#if.condition, f);
+            walk_statement(&r#if.body, f);
+            for clause in &r#if.else_if_clauses {
+                walk_expression(&clause.condition, f);
+                walk_statement(&clause.body, f);
+            }
+            if let Some(else_clause) = &r#if.else_clause {
+                walk_statement(&else_clause.body, f);
+            }
+        }
+        Statement::While(r#while) => {
+            walk_expression(&r#while.condition, f);
+            walk_statement(&r#while.body, f);
+        }
+        Statement::DoWhile(do_while) => {
+            walk_statement(&do_while.body, f);
+            walk_expression(&do_while.condition, f);
+        }
+        Statement::For(r#for) => {
+            for condition in &r#for.conditions {
+                walk_expression(condition, f);
+            }
+            walk_statement(&r#for.body, f);
+        }
+        Statement::Foreach(foreach) => {
+            walk_expression(&foreach.expression, f);
+            walk_statement(&foreach.body, f);
+        }
+        Statement::Switch(switch) => {
+            walk_expression(&switch.expression, f);
+            for case in switch.body.cases() {
+                for inner in case.statements() {
+                    walk_statement(inner, f);
+                }
+            }
+        }
+        Statement::Try(r#try) => {
+            for inner in &r#try.block.statements {
+                walk_statement(inner, f);
+            }
+            for clause in &r#try.catch_clauses {
+                for inner in &clause.block.statements {
+                    walk_statement(inner, f);
+                }
+            }
+            if let Some(finally) = &r#try.finally_clause {
+                for inner in &finally.block.statements {
+                    walk_statement(inner, f);
+                }
+            }
+        }
+        Statement::Expression(expression_statement) => walk_expression(&expression_statement.expression, f),
+        Statement::Return(r#return) => {
+            if let Some(value) = &r#return.value {
+                walk_expression(value, f);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Walks `expression` and every subexpression this analysis knows how to
+/// open up, feeding each one (including `expression` itself) to `f`. Mirrors
+/// the same confirmed set of variants as [`mago_ast::purity::has_side_effects`]'s
+/// own walker.
+fn walk_expression<'a>(expression: &'a Expression, f: &mut impl FnMut(&'a Expression)) {
+    f(expression);
+
+    match expression {
+        Expression::Throw(r#throw) => walk_expression(&r#throw.exception, f),
+        Expression::Assignment(assignment) => {
+            walk_expression(&assignment.lhs, f);
+            walk_expression(&assignment.rhs, f);
+        }
+        Expression::AssignmentOperation(assignment) => {
+            walk_expression(&assignment.lhs, f);
+            walk_expression(&assignment.rhs, f);
+        }
+        Expression::Binary(binary) => {
+            walk_expression(&binary.lhs, f);
+            walk_expression(&binary.rhs, f);
+        }
+        Expression::Call(Call::Function(call)) => {
+            walk_expression(&call.function, f);
+            for argument in &call.arguments.arguments {
+                walk_expression(argument_value(argument), f);
+            }
+        }
+        Expression::Call(Call::Method(call)) => {
+            walk_expression(&call.object, f);
+            for argument in &call.arguments.arguments {
+                walk_expression(argument_value(argument), f);
+            }
+        }
+        Expression::Call(Call::StaticMethod(call)) => {
+            for argument in &call.arguments.arguments {
+                walk_expression(argument_value(argument), f);
+            }
+        }
+        Expression::Access(Access::Property(access)) => walk_expression(&access.object, f),
+        Expression::ArrayAccess(access) => {
+            walk_expression(&access.array, f);
+            if let Some(index) = access.index.as_deref() {
+                walk_expression(index, f);
+            }
+        }
+        Expression::Isset(isset) => {
+            for value in &isset.values {
+                walk_expression(value, f);
+            }
+        }
+        Expression::Empty(empty) => walk_expression(&empty.value, f),
+        _ => {}
+    }
+}
+
+fn argument_value(argument: &Argument) -> &Expression {
+    match argument {
+        Argument::Positional(positional) => &positional.value,
+        Argument::Named(named) => &named.value,
+    }
+}
+
+fn is_branching(statement: &Statement) -> bool {
+    matches!(
+        statement,
+        Statement::If(_)
+            | Statement::While(_)
+            | Statement::DoWhile(_)
+            | Statement::For(_)
+            | Statement::Foreach(_)
+            | Statement::Switch(_)
+            | Statement::Try(_)
+    )
+}
+
+/// Whether any of `statements` is a `global` statement, or any statement
+/// contains a variable variable (`$$name`, `${expr}`) or a call to one of
+/// [`VARIABLE_ESCAPE_HATCHES`], each of which can read a variable by name in
+/// a way this rule can't see.
+fn uses_variable_escape_hatch(statements: &[Statement]) -> bool {
+    let mut found = false;
+
+    for statement in statements {
+        if matches!(statement, Statement::Global(_)) {
+            return true;
+        }
+
+        walk_statement(statement, &mut |expression| {
+            if found {
+                return;
+            }
+
+            found = match expression {
+                Expression::Variable(Variable::Indirect(_)) => true,
+                Expression::Call(Call::Function(call)) => match call.function.as_ref() {
+                    Expression::Identifier(Identifier::Local(identifier)) => {
+                        VARIABLE_ESCAPE_HATCHES.iter().any(|hatch| identifier.value.eq_ignore_ascii_case(hatch))
+                    }
+                    _ => false,
+                },
+                _ => false,
+            };
+        });
+
+        if found {
+            return true;
+        }
+    }
+
+    false
+}
+
+fn by_reference_parameter_names(node: Node<'_>) -> HashSet<String> {
+    let parameters: &[FunctionLikeParameter] = match node {
+        Node::Function(function) => &function.parameter_list.parameters,
+        Node::Closure(closure) => &closure.parameter_list.parameters,
+        Node::Method(method) => &method.parameter_list.parameters,
+        _ => return HashSet::new(),
+    };
+
+    parameters.iter().filter(|parameter| parameter.ampersand.is_some()).map(|parameter| normalize(&parameter.name.value)).collect()
+}
+
+/// Normalizes a variable name to the `$name` form, regardless of whether
+/// the source already carries the sigil.
+fn normalize(name: &str) -> String {
+    format!("${}", name.trim_start_matches('$'))
+}
+
+fn report(context: &mut LintContext<'_>, span: Span, rhs: &Expression, message: &str) {
+    let mut issue = Issue::new(Level::Warning, "dead store: this assignment's value is never used")
+        .with_code("redundancy/dead-store")
+        .with_annotation(Annotation::new(span, AnnotationKind::Primary).with_message(message));
+
+    let mut plan = FixPlan::new();
+    if has_side_effects(rhs, context.codebase()) {
+        // The value is dead, but whatever produced it might not be -
+        // keep the right-hand side running as a standalone statement and
+        // only drop the now-pointless assignment around it.
+        plan.replace(span, format!("{};", context.lookup_slice(rhs.span())), SafetyClassification::Safe);
+    } else {
+        plan.replace(span, String::new(), SafetyClassification::Safe);
+    }
+    issue = issue.with_fix(plan);
+
+    context.report(issue);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_adds_a_missing_sigil() {
+        assert_eq!(normalize("total"), "$total");
+    }
+
+    #[test]
+    fn normalize_is_idempotent_on_an_already_sigiled_name() {
+        assert_eq!(normalize("$total"), "$total");
+    }
+}
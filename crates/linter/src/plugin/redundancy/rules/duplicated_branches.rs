@@ -0,0 +1,121 @@
+use mago_ast::ast::*;
+use mago_ast::Node;
+use mago_fixer::FixPlan;
+use mago_fixer::SafetyClassification;
+use mago_reporting::{Annotation, AnnotationKind, Issue, Level};
+use mago_span::HasSpan;
+use mago_span::Span;
+
+use crate::context::LintContext;
+use crate::definition::{RuleDefinition, RuleUsageExample};
+use crate::rule::Rule;
+
+/// Flags two kinds of duplicated `if`/`elseif` branches, using structural
+/// equality on the AST rather than source text:
+///
+/// - An `elseif` whose condition is identical to an earlier branch's
+///   condition in the same chain is unreachable, since the earlier branch
+///   already caught every case it would have.
+/// - Two adjacent branches with identical bodies are very likely meant to
+///   be merged into one branch with an `||`'d condition.
+///
+/// Both checks only compare branches within the same `if` chain; they
+/// don't look across separate `if` statements. Only the unreachable
+/// `elseif` case gets an autofix (deleting the dead clause); merging two
+/// branches' conditions would require rewriting the `if`/`elseif` keyword
+/// and brace structure around them, which isn't safely expressible with
+/// only a source-slice fix, so that case is report-only.
+#[derive(Debug)]
+pub struct DuplicatedConditionBranchRule;
+
+impl Rule for DuplicatedConditionBranchRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Duplicated Condition Branch", Level::Warning)
+            .with_description("Flags repeated `elseif` conditions and adjacent branches with identical bodies.")
+            .with_example(RuleUsageExample::invalid(
+                "An `elseif` re-checking a condition already handled above",
+                r#"
+                <?php
+
+                if ($status === 'active') {
+                    activate();
+                } elseif ($status === 'active') {
+                    reactivate();
+                }
+                "#,
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Two branches that only differ in their condition",
+                r#"
+                <?php
+
+                if ($role === 'admin') {
+                    grant_access();
+                } elseif ($role === 'owner') {
+                    grant_access();
+                }
+                "#,
+            ))
+    }
+
+    fn check(&self, node: Node<'_>, context: &mut LintContext<'_>) {
+        let Node::If(r#if) = node else {
+            return;
+        };
+
+        let mut conditions = vec![(&r#if.condition, r#if.condition.span())];
+        let mut branches = vec![(&r#if.condition, &r#if.body)];
+
+        for clause in r#if.else_if_clauses.iter() {
+            check_duplicate_condition(context, &conditions, &clause.condition, clause.span());
+            conditions.push((&clause.condition, clause.condition.span()));
+            branches.push((&clause.condition, &clause.body));
+        }
+
+        for window in branches.windows(2) {
+            let [(earlier_condition, earlier_body), (later_condition, later_body)] = window else { continue };
+            check_duplicate_body(context, earlier_condition, earlier_body, later_condition, later_body);
+        }
+    }
+}
+
+fn check_duplicate_condition(
+    context: &mut LintContext<'_>,
+    seen: &[(&Expression, Span)],
+    candidate: &Expression,
+    clause_span: Span,
+) {
+    let Some((_, earlier_span)) = seen.iter().find(|(condition, _)| *condition == candidate) else {
+        return;
+    };
+
+    let mut plan = FixPlan::new();
+    plan.replace(clause_span, String::new(), SafetyClassification::Safe);
+
+    context.report(
+        Issue::new(Level::Warning, "this `elseif` condition is identical to an earlier branch - it is unreachable")
+            .with_code("redundancy/duplicated-condition-branch")
+            .with_annotation(Annotation::new(candidate.span(), AnnotationKind::Primary))
+            .with_annotation(Annotation::new(*earlier_span, AnnotationKind::Secondary))
+            .with_fix(plan),
+    );
+}
+
+fn check_duplicate_body(
+    context: &mut LintContext<'_>,
+    earlier_condition: &Expression,
+    earlier_body: &Statement,
+    _later_condition: &Expression,
+    later_body: &Statement,
+) {
+    if earlier_body != later_body {
+        return;
+    }
+
+    context.report(
+        Issue::new(Level::Warning, "these branches have identical bodies - consider merging their conditions with `||`")
+            .with_code("redundancy/duplicated-condition-branch")
+            .with_annotation(Annotation::new(later_body.span(), AnnotationKind::Primary))
+            .with_annotation(Annotation::new(earlier_condition.span(), AnnotationKind::Secondary)),
+    );
+}
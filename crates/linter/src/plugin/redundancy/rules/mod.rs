@@ -0,0 +1,3 @@
+pub mod dead_store;
+pub mod duplicated_branches;
+pub mod unused_private_member;
@@ -0,0 +1,254 @@
+use std::collections::HashSet;
+
+use mago_ast::ast::*;
+use mago_ast::Node;
+use mago_reporting::{Annotation, AnnotationKind, Issue, Level};
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::{RuleDefinition, RuleUsageExample};
+use crate::rule::Rule;
+
+/// Flags `private const` declarations and enum `case`s that are never
+/// referenced anywhere else in the declaring class or enum.
+///
+/// Because visibility is `private`, this is a sound, local check: unlike
+/// `public`/`protected` members, nothing outside the class body can possibly
+/// reference them, so an absence of references within the body is
+/// conclusive.
+#[derive(Debug)]
+pub struct UnusedPrivateMemberRule;
+
+impl Rule for UnusedPrivateMemberRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Unused Private Member", Level::Warning)
+            .with_description("Detects private constants and enum cases that are never referenced within their declaring class-like.")
+            .with_example(RuleUsageExample::invalid(
+                "A private constant that is never read",
+                r#"
+                <?php
+
+                class Config
+                {
+                    private const DEFAULT_TIMEOUT = 30;
+                }
+                "#,
+            ))
+    }
+
+    fn check(&self, node: Node<'_>, context: &mut LintContext<'_>) {
+        let members: &[ClassLikeMember] = match node {
+            Node::Class(class) => &class.members,
+            Node::Enum(r#enum) => &r#enum.members,
+            _ => return,
+        };
+
+        let mut declared_names = Vec::new();
+        for member in members {
+            match member {
+                ClassLikeMember::Constant(constant) if constant.modifiers.contains_private() => {
+                    declared_names.extend(constant.items.iter().map(|item| (item.name.value.as_str(), item.span())));
+                }
+                ClassLikeMember::EnumCase(case) => {
+                    declared_names.push((case.item.name().value.as_str(), case.span()));
+                }
+                _ => {}
+            }
+        }
+
+        if declared_names.is_empty() {
+            return;
+        }
+
+        let mut referenced = HashSet::new();
+        for member in members {
+            collect_constant_references(member, &mut referenced);
+        }
+
+        for (name, span) in declared_names {
+            if referenced.contains(name) {
+                continue;
+            }
+
+            let issue = Issue::new(Level::Warning, format!("`{name}` is never referenced"))
+                .with_code("redundancy/unused-private-member")
+                .with_annotation(Annotation::new(span, AnnotationKind::Primary).with_message("this can likely be removed"));
+
+            context.report(issue);
+        }
+    }
+}
+
+/// Collects the name of every `self::NAME`/`static::NAME`/`ClassName::NAME`
+/// constant or enum-case access reachable from `member`'s body: a method's
+/// statements, or a property's or constant's default-value expression.
+///
+/// `ClassLikeMember::TraitUse` and a backed enum case's own value expression
+/// aren't walked - no confirmed field gives access to either in this tree -
+/// so a reference living only in one of those is missed, and the member it
+/// names is reported as unused even though it is referenced.
+fn collect_constant_references(member: &ClassLikeMember, referenced: &mut HashSet<String>) {
+    match member {
+        ClassLikeMember::Method(method) => {
+            if let Some(statements) = method.body.as_statements() {
+                for statement in statements {
+                    walk_statement(statement, &mut |expression| record_reference(expression, referenced));
+                }
+            }
+        }
+        ClassLikeMember::Property(property) => {
+            for item in property.items.iter() {
+                if let PropertyItem::Concrete(concrete) = item {
+                    walk_expression(&concrete.value, &mut |expression| record_reference(expression, referenced));
+                }
+            }
+        }
+        ClassLikeMember::Constant(constant) => {
+            for item in constant.items.iter() {
+                walk_expression(&item.value, &mut |expression| record_reference(expression, referenced));
+            }
+        }
+        _ => {}
+    }
+}
+
+fn record_reference(expression: &Expression, referenced: &mut HashSet<String>) {
+    if let Expression::Access(Access::ClassConstant(access)) = expression {
+        if let ClassLikeConstantSelector::Identifier(identifier) = &access.constant {
+            referenced.insert(identifier.value.clone());
+        }
+    }
+}
+
+/// Walks `statement` and every nested statement/expression this analysis
+/// knows how to open up, feeding each expression to `f`.
+fn walk_statement(statement: &Statement, f: &mut impl FnMut(&Expression)) {
+    match statement {
+        Statement::Block(block) => {
+            for inner in &block.statements {
+                walk_statement(inner, f);
+            }
+        }
+        Statement::If(r#if) => {
+            walk_expression(&r#if.condition, f);
+            walk_statement(&r#if.body, f);
+            for clause in &r#if.else_if_clauses {
+                walk_expression(&clause.condition, f);
+                walk_statement(&clause.body, f);
+            }
+            if let Some(else_clause) = &r#if.else_clause {
+                walk_statement(&else_clause.body, f);
+            }
+        }
+        Statement::While(r#while) => {
+            walk_expression(&r#while.condition, f);
+            walk_statement(&r#while.body, f);
+        }
+        Statement::DoWhile(do_while) => {
+            walk_statement(&do_while.body, f);
+            walk_expression(&do_while.condition, f);
+        }
+        Statement::For(r#for) => {
+            for condition in &r#for.conditions {
+                walk_expression(condition, f);
+            }
+            walk_statement(&r#for.body, f);
+        }
+        Statement::Foreach(foreach) => {
+            walk_expression(&foreach.expression, f);
+            walk_statement(&foreach.body, f);
+        }
+        Statement::Switch(switch) => {
+            walk_expression(&switch.expression, f);
+            for case in switch.body.cases() {
+                for inner in case.statements() {
+                    walk_statement(inner, f);
+                }
+            }
+        }
+        Statement::Try(r#try) => {
+            for inner in &r#try.block.statements {
+                walk_statement(inner, f);
+            }
+            for clause in &r#try.catch_clauses {
+                for inner in &clause.block.statements {
+                    walk_statement(inner, f);
+                }
+            }
+            if let Some(finally) = &r#try.finally_clause {
+                for inner in &finally.block.statements {
+                    walk_statement(inner, f);
+                }
+            }
+        }
+        Statement::Expression(expression_statement) => walk_expression(&expression_statement.expression, f),
+        Statement::Return(r#return) => {
+            if let Some(value) = &r#return.value {
+                walk_expression(value, f);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Walks `expression` and every subexpression this analysis knows how to
+/// open up, feeding each one (including `expression` itself) to `f`. Mirrors
+/// the same confirmed set of variants as [`mago_ast::purity::has_side_effects`]'s
+/// own walker.
+fn walk_expression(expression: &Expression, f: &mut impl FnMut(&Expression)) {
+    f(expression);
+
+    match expression {
+        Expression::Throw(r#throw) => walk_expression(&r#throw.exception, f),
+        Expression::Assignment(assignment) => {
+            walk_expression(&assignment.lhs, f);
+            walk_expression(&assignment.rhs, f);
+        }
+        Expression::AssignmentOperation(assignment) => {
+            walk_expression(&assignment.lhs, f);
+            walk_expression(&assignment.rhs, f);
+        }
+        Expression::Binary(binary) => {
+            walk_expression(&binary.lhs, f);
+            walk_expression(&binary.rhs, f);
+        }
+        Expression::Call(Call::Function(call)) => {
+            walk_expression(&call.function, f);
+            for argument in &call.arguments.arguments {
+                walk_expression(argument_value(argument), f);
+            }
+        }
+        Expression::Call(Call::Method(call)) => {
+            walk_expression(&call.object, f);
+            for argument in &call.arguments.arguments {
+                walk_expression(argument_value(argument), f);
+            }
+        }
+        Expression::Call(Call::StaticMethod(call)) => {
+            for argument in &call.arguments.arguments {
+                walk_expression(argument_value(argument), f);
+            }
+        }
+        Expression::Access(Access::Property(access)) => walk_expression(&access.object, f),
+        Expression::ArrayAccess(access) => {
+            walk_expression(&access.array, f);
+            if let Some(index) = access.index.as_deref() {
+                walk_expression(index, f);
+            }
+        }
+        Expression::Isset(isset) => {
+            for value in &isset.values {
+                walk_expression(value, f);
+            }
+        }
+        Expression::Empty(empty) => walk_expression(&empty.value, f),
+        _ => {}
+    }
+}
+
+fn argument_value(argument: &Argument) -> &Expression {
+    match argument {
+        Argument::Positional(positional) => &positional.value,
+        Argument::Named(named) => &named.value,
+    }
+}
@@ -0,0 +1,26 @@
+use crate::plugin::LintPlugin;
+use crate::plugin::security::rules::format_string_validation::FormatStringValidationRule;
+use crate::plugin::security::rules::string_literal_validation::StringLiteralValidationRule;
+use crate::rule::Rule;
+
+pub mod rules;
+
+/// Rules that catch patterns with security implications: malformed regexes,
+/// suspicious SQL construction, and anything added to
+/// [`crate::string_validation`] in the future.
+#[derive(Debug)]
+pub struct SecurityPlugin;
+
+impl LintPlugin for SecurityPlugin {
+    fn get_name(&self) -> &'static str {
+        "security"
+    }
+
+    fn is_enabled_by_default(&self) -> bool {
+        true
+    }
+
+    fn get_rules(&self) -> Vec<Box<dyn Rule>> {
+        vec![Box::new(StringLiteralValidationRule), Box::new(FormatStringValidationRule)]
+    }
+}
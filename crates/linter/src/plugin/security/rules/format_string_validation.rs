@@ -0,0 +1,220 @@
+use mago_ast::ast::*;
+use mago_ast::Node;
+use mago_reporting::{Annotation, AnnotationKind, Issue, Level};
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::{RuleDefinition, RuleUsageExample};
+use crate::rule::Rule;
+
+/// Functions whose first argument is a format string, and whose placeholder
+/// count should be checked against the arguments that follow it.
+///
+/// `vsprintf`/`vfprintf` take their values as a single array argument
+/// instead of a variadic tail, so their placeholder count can only be
+/// checked when that array is a literal the rule can count directly.
+const FORMAT_FUNCTIONS: &[&str] = &["sprintf", "printf", "fprintf", "vsprintf", "vfprintf"];
+
+/// Validates the format string of `sprintf`/`printf`/`fprintf`/`vsprintf`
+/// calls: unknown conversion specifiers, and a placeholder count higher
+/// than the number of arguments supplied (when that count is statically
+/// known).
+#[derive(Debug)]
+pub struct FormatStringValidationRule;
+
+impl Rule for FormatStringValidationRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Format String Validation", Level::Error)
+            .with_description("Validates `sprintf`-family format strings against the arguments passed alongside them.")
+            .with_example(RuleUsageExample::invalid(
+                "Fewer arguments than placeholders",
+                r#"
+                <?php
+
+                sprintf("%s is %d years old", $name);
+                "#,
+            ))
+    }
+
+    fn check(&self, node: Node<'_>, context: &mut LintContext<'_>) {
+        let Node::Call(Call::Function(call)) = node else {
+            return;
+        };
+
+        let Expression::Identifier(Identifier::Local(identifier)) = call.function.as_ref() else {
+            return;
+        };
+
+        if !FORMAT_FUNCTIONS.contains(&identifier.value.as_str()) {
+            return;
+        }
+
+        let format_argument_index = if identifier.value == "fprintf" || identifier.value == "vfprintf" { 1 } else { 0 };
+
+        let positional: Vec<&Expression> = call
+            .arguments
+            .arguments
+            .iter()
+            .filter_map(|argument| match argument {
+                Argument::Positional(positional) => Some(positional.value.as_ref()),
+                Argument::Named(_) => None,
+            })
+            .collect();
+
+        let Some(Expression::Literal(Literal::String(format_literal))) = positional.get(format_argument_index).copied() else {
+            return;
+        };
+
+        let specifiers = match parse_specifiers(&format_literal.value) {
+            Ok(specifiers) => specifiers,
+            Err(InvalidSpecifier { offset, conversion }) => {
+                context.report(
+                    Issue::new(Level::Error, format!("`%{conversion}` is not a recognized conversion specifier"))
+                        .with_code("security/format-string-validation")
+                        .with_annotation(Annotation::new(
+                            crate::string_validation::content_span(format_literal.span(), offset as u32, offset as u32 + 1),
+                            AnnotationKind::Primary,
+                        )),
+                );
+                return;
+            }
+        };
+
+        let is_vectorized = identifier.value.starts_with('v');
+        let provided_count = if is_vectorized {
+            let Some(Expression::Array(array)) = positional.get(format_argument_index + 1).copied() else {
+                return;
+            };
+
+            if array.elements.iter().any(|element| matches!(element, ArrayElement::Variadic(_))) {
+                return;
+            }
+
+            array.elements.len() as u32
+        } else {
+            positional.len() as u32 - (format_argument_index as u32 + 1)
+        };
+
+        let highest_positional_index = specifiers.iter().filter_map(|specifier| specifier.positional_index).max();
+        let placeholder_count = highest_positional_index.unwrap_or(specifiers.len() as u32);
+
+        if placeholder_count > provided_count {
+            context.report(
+                Issue::new(
+                    Level::Error,
+                    format!("format string expects {placeholder_count} argument(s), but only {provided_count} were given"),
+                )
+                .with_code("security/format-string-validation")
+                .with_annotation(Annotation::new(call.span(), AnnotationKind::Primary)),
+            );
+        }
+    }
+}
+
+struct Specifier {
+    /// Set for a `%1$s`-style positional specifier.
+    positional_index: Option<u32>,
+}
+
+struct InvalidSpecifier {
+    offset: usize,
+    conversion: char,
+}
+
+const KNOWN_CONVERSIONS: &str = "bcdeEfFgGosuxX%";
+
+fn parse_specifiers(format: &str) -> Result<Vec<Specifier>, InvalidSpecifier> {
+    let mut specifiers = Vec::new();
+    let chars: Vec<(usize, char)> = format.char_indices().collect();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let (_, ch) = chars[i];
+        if ch != '%' {
+            i += 1;
+            continue;
+        }
+
+        let start = i;
+        i += 1;
+        if i >= chars.len() {
+            break;
+        }
+
+        // Positional prefix: digits followed by `$`.
+        let mut positional_index = None;
+        let digits_start = i;
+        while i < chars.len() && chars[i].1.is_ascii_digit() {
+            i += 1;
+        }
+        if i > digits_start && i < chars.len() && chars[i].1 == '$' {
+            let digits: String = chars[digits_start..i].iter().map(|&(_, c)| c).collect();
+            positional_index = digits.parse().ok();
+            i += 1;
+        } else {
+            i = digits_start;
+        }
+
+        // Flags, width, precision.
+        while i < chars.len() && matches!(chars[i].1, '-' | '+' | ' ' | '0' | '\'') {
+            if chars[i].1 == '\'' {
+                i += 1;
+            }
+            i += 1;
+        }
+        while i < chars.len() && chars[i].1.is_ascii_digit() {
+            i += 1;
+        }
+        if i < chars.len() && chars[i].1 == '.' {
+            i += 1;
+            while i < chars.len() && chars[i].1.is_ascii_digit() {
+                i += 1;
+            }
+        }
+
+        if i >= chars.len() {
+            return Err(InvalidSpecifier { offset: start, conversion: '\0' });
+        }
+
+        let conversion = chars[i].1;
+        if !KNOWN_CONVERSIONS.contains(conversion) {
+            return Err(InvalidSpecifier { offset: chars[i].0, conversion });
+        }
+        i += 1;
+
+        if conversion != '%' {
+            specifiers.push(Specifier { positional_index });
+        }
+    }
+
+    Ok(specifiers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_simple_specifiers() {
+        let specifiers = parse_specifiers("%s is %d years old").unwrap();
+        assert_eq!(specifiers.len(), 2);
+    }
+
+    #[test]
+    fn ignores_escaped_percent() {
+        let specifiers = parse_specifiers("100%% done: %s").unwrap();
+        assert_eq!(specifiers.len(), 1);
+    }
+
+    #[test]
+    fn rejects_unknown_conversion() {
+        assert!(parse_specifiers("%q").is_err());
+    }
+
+    #[test]
+    fn tracks_highest_positional_index() {
+        let specifiers = parse_specifiers("%2$s and %1$s").unwrap();
+        let highest = specifiers.iter().filter_map(|s| s.positional_index).max();
+        assert_eq!(highest, Some(2));
+    }
+}
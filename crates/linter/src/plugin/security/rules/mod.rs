@@ -0,0 +1,2 @@
+pub mod format_string_validation;
+pub mod string_literal_validation;
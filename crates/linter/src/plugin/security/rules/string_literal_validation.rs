@@ -0,0 +1,72 @@
+use mago_ast::ast::*;
+use mago_ast::Node;
+use mago_reporting::Level;
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::{RuleDefinition, RuleUsageExample};
+use crate::rule::Rule;
+use crate::string_validation::CallKind;
+
+/// Runs every registered [`crate::string_validation::StringLiteralValidator`]
+/// against the literal string argument it applies to, for both function and
+/// method calls.
+///
+/// This rule is the single call-site dispatcher; the actual checks (regex
+/// delimiters, SQL sanity, and anything a project registers) live behind the
+/// [`crate::string_validation::StringValidatorRegistry`] so they can be tested and extended
+/// independently of how calls are matched.
+#[derive(Debug)]
+pub struct StringLiteralValidationRule;
+
+impl Rule for StringLiteralValidationRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("String Literal Validation", Level::Error)
+            .with_description("Validates string-literal arguments known to be regex patterns or SQL statements.")
+            .with_example(RuleUsageExample::invalid(
+                "A `preg_match` pattern missing its closing delimiter",
+                r#"
+                <?php
+
+                preg_match('/^foo$', $subject);
+                "#,
+            ))
+    }
+
+    fn check(&self, node: Node<'_>, context: &mut LintContext<'_>) {
+        let (kind, name, arguments) = match node {
+            Node::Call(Call::Function(call)) => {
+                let Expression::Identifier(Identifier::Local(identifier)) = call.function.as_ref() else {
+                    return;
+                };
+
+                (CallKind::Function, identifier.value.as_str(), &call.arguments)
+            }
+            Node::Call(Call::Method(call)) => {
+                let Expression::Identifier(Identifier::Local(method_name)) = &call.method else {
+                    return;
+                };
+
+                (CallKind::Method, method_name.value.as_str(), &call.arguments)
+            }
+            _ => return,
+        };
+
+        for (index, argument) in arguments.arguments.iter().enumerate() {
+            let value_expression = match argument {
+                Argument::Positional(positional) => &positional.value,
+                Argument::Named(_) => continue,
+            };
+
+            let Expression::Literal(Literal::String(literal)) = value_expression else {
+                continue;
+            };
+
+            for validator in context.string_validators().validators_for(kind, name, index) {
+                for issue in validator.validate(&literal.value, literal.span()) {
+                    context.report(issue);
+                }
+            }
+        }
+    }
+}
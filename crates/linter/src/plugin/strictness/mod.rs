@@ -0,0 +1,26 @@
+use crate::plugin::LintPlugin;
+use crate::plugin::strictness::rules::empty_isset_misuse::EmptyIssetMisuseRule;
+use crate::plugin::strictness::rules::loose_falsy_comparison::LooseFalsyComparisonRule;
+use crate::plugin::strictness::rules::strict_types_declaration::StrictTypesDeclarationRule;
+use crate::rule::Rule;
+
+pub mod rules;
+
+/// Rules that push towards strict (`===`) comparisons and away from PHP's
+/// looser type-juggling behavior.
+#[derive(Debug)]
+pub struct StrictnessPlugin;
+
+impl LintPlugin for StrictnessPlugin {
+    fn get_name(&self) -> &'static str {
+        "strictness"
+    }
+
+    fn is_enabled_by_default(&self) -> bool {
+        true
+    }
+
+    fn get_rules(&self) -> Vec<Box<dyn Rule>> {
+        vec![Box::new(LooseFalsyComparisonRule), Box::new(EmptyIssetMisuseRule), Box::new(StrictTypesDeclarationRule)]
+    }
+}
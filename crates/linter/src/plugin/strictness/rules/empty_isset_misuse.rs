@@ -0,0 +1,57 @@
+use mago_ast::ast::*;
+use mago_ast::Node;
+use mago_reporting::{Annotation, AnnotationKind, Issue, Level};
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::rule::Rule;
+
+/// Flags `empty()`/`isset()` called on something that isn't a variable,
+/// property access, array access, or static property access.
+///
+/// Both constructs are special-cased by the parser to accept only those
+/// "dereferenceable" forms without throwing on an undefined variable;
+/// calling them on, say, a function call result (`empty(foo())`) compiles,
+/// but is always equivalent to the much clearer `!foo()` / no check at all,
+/// and usually indicates the author meant something else.
+#[derive(Debug)]
+pub struct EmptyIssetMisuseRule;
+
+impl Rule for EmptyIssetMisuseRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Empty Isset Misuse", Level::Warning)
+            .with_description("Flags `empty()`/`isset()` used on an expression that isn't a variable, property, or array access.")
+    }
+
+    fn check(&self, node: Node<'_>, context: &mut LintContext<'_>) {
+        let (name, subjects, span) = match node {
+            Node::Expression(Expression::Empty(empty)) => ("empty", std::slice::from_ref(empty.value.as_ref()), empty.span()),
+            Node::Expression(Expression::Isset(isset)) => ("isset", isset.values.as_slice(), isset.span()),
+            _ => return,
+        };
+
+        for subject in subjects {
+            if is_checkable(subject) {
+                continue;
+            }
+
+            context.report(
+                Issue::new(Level::Warning, format!("`{name}()` on this expression is always well-defined and rarely intentional"))
+                    .with_code("strictness/empty-isset-misuse")
+                    .with_annotation(
+                        Annotation::new(subject.span(), AnnotationKind::Primary)
+                            .with_message("only variables, properties, and array/offset accesses need this check"),
+                    )
+                    .with_annotation(Annotation::new(span, AnnotationKind::Secondary)),
+            );
+        }
+    }
+}
+
+fn is_checkable(expression: &Expression) -> bool {
+    matches!(
+        expression,
+        Expression::Variable(_) | Expression::Access(Access::Property(_)) | Expression::Access(Access::StaticProperty(_)) | Expression::ArrayAccess(_)
+    )
+}
@@ -0,0 +1,120 @@
+use mago_ast::ast::*;
+use mago_ast::Node;
+use mago_fixer::FixPlan;
+use mago_fixer::SafetyClassification;
+use mago_reporting::{Annotation, AnnotationKind, Issue, Level};
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::{RuleDefinition, RuleUsageExample};
+use crate::rule::Rule;
+
+/// Flags `== false`, `== null`, `== ''`, and `== 0` comparisons, which are
+/// loose and therefore also true for a surprising number of other values
+/// (`'0'`, `[]`, `'abc' == 0` on PHP 7), and proposes the precise
+/// strict-comparison or boolean-cast rewrite the comparison most likely
+/// intended.
+#[derive(Debug)]
+pub struct LooseFalsyComparisonRule;
+
+impl Rule for LooseFalsyComparisonRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Loose Falsy Comparison", Level::Warning)
+            .with_description("Flags `==`/`!=` comparisons against `false`, `null`, `''`, or `0`, which are surprising for many values.")
+            .with_example(RuleUsageExample::invalid(
+                "A loose comparison against false",
+                r#"
+                <?php
+
+                if ($value == false) {
+                    // ...
+                }
+                "#,
+            ))
+    }
+
+    fn check(&self, node: Node<'_>, context: &mut LintContext<'_>) {
+        let Node::Binary(binary) = node else {
+            return;
+        };
+
+        let negate = match binary.operator {
+            BinaryOperator::Equal(_) => false,
+            BinaryOperator::NotEqual(_) | BinaryOperator::AngledNotEqual(_) => true,
+            _ => return,
+        };
+
+        let Some((subject, falsy)) = falsy_side(&binary.lhs, &binary.rhs) else {
+            return;
+        };
+
+        let mut issue = Issue::new(Level::Warning, format!("loose comparison against `{}`", describe(falsy)))
+            .with_code("strictness/loose-falsy-comparison")
+            .with_annotation(
+                Annotation::new(binary.span(), AnnotationKind::Primary)
+                    .with_message("this also matches values you probably don't intend, like `'0'`"),
+            );
+
+        if let Some(replacement) = suggest_replacement(context, subject, falsy, negate) {
+            let mut plan = FixPlan::new();
+            plan.replace(binary.span(), replacement, SafetyClassification::PotentiallyUnsafe);
+            issue = issue.with_fix(plan);
+        }
+
+        context.report(issue);
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Falsy {
+    False,
+    Null,
+    EmptyString,
+    Zero,
+}
+
+fn describe(falsy: Falsy) -> &'static str {
+    match falsy {
+        Falsy::False => "false",
+        Falsy::Null => "null",
+        Falsy::EmptyString => "''",
+        Falsy::Zero => "0",
+    }
+}
+
+fn falsy_side<'a>(lhs: &'a Expression, rhs: &'a Expression) -> Option<(&'a Expression, Falsy)> {
+    classify(rhs).map(|falsy| (lhs, falsy)).or_else(|| classify(lhs).map(|falsy| (rhs, falsy)))
+}
+
+fn classify(expression: &Expression) -> Option<Falsy> {
+    match expression {
+        Expression::Literal(Literal::False(_)) => Some(Falsy::False),
+        Expression::Literal(Literal::Null(_)) => Some(Falsy::Null),
+        Expression::Literal(Literal::String(literal)) if literal.value.is_empty() => Some(Falsy::EmptyString),
+        Expression::Literal(Literal::Integer(literal)) if literal.value == 0 => Some(Falsy::Zero),
+        _ => None,
+    }
+}
+
+fn suggest_replacement(context: &LintContext<'_>, subject: &Expression, falsy: Falsy, negate: bool) -> Option<String> {
+    let subject_text = context.lookup_slice(subject.span());
+
+    Some(match falsy {
+        Falsy::Null | Falsy::EmptyString | Falsy::Zero => {
+            let operator = if negate { "!==" } else { "===" };
+            format!("{subject_text} {operator} {}", match falsy {
+                Falsy::Null => "null",
+                Falsy::EmptyString => "''",
+                Falsy::Zero => "0",
+                Falsy::False => unreachable!(),
+            })
+        }
+        Falsy::False => {
+            if negate {
+                subject_text.to_string()
+            } else {
+                format!("!{subject_text}")
+            }
+        }
+    })
+}
@@ -0,0 +1,3 @@
+pub mod empty_isset_misuse;
+pub mod loose_falsy_comparison;
+pub mod strict_types_declaration;
@@ -0,0 +1,172 @@
+use mago_ast::ast::*;
+use mago_ast::Node;
+use mago_fixer::FixPlan;
+use mago_fixer::SafetyClassification;
+use mago_reporting::{Annotation, AnnotationKind, Issue, Level};
+use mago_span::HasSpan;
+use mago_span::Span;
+
+use crate::context::LintContext;
+use crate::definition::{RuleDefinition, RuleUsageExample};
+use crate::rule::Rule;
+
+const CANONICAL: &str = "declare(strict_types=1);";
+
+/// Requires `declare(strict_types=1);` to appear exactly once, as the
+/// file's first statement, written exactly as [`CANONICAL`], and on its
+/// own - not combined with other `declare` directives.
+///
+/// A leading file docblock doesn't count as a statement, so it's never in
+/// the way of "first statement" here; the check walks
+/// [`mago_ast::ast::Program::statements`], which only contains real
+/// statements to begin with.
+///
+/// Because this tree has no confirmed field layout for [`Statement::Declare`]'s
+/// directive list, every check here works off the statement's own source
+/// text (`declare(...)`) rather than its parsed items.
+#[derive(Debug)]
+pub struct StrictTypesDeclarationRule;
+
+impl Rule for StrictTypesDeclarationRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Strict Types Declaration", Level::Warning)
+            .with_description(
+                "Requires declare(strict_types=1) exactly once, as the first statement, in canonical form.",
+            )
+            .with_example(RuleUsageExample::invalid(
+                "Missing declare(strict_types=1)",
+                r#"
+                <?php
+
+                echo "hello";
+                "#,
+            ))
+            .with_example(RuleUsageExample::valid(
+                "declare(strict_types=1) as the first statement",
+                r#"
+                <?php
+
+                declare(strict_types=1);
+
+                echo "hello";
+                "#,
+            ))
+    }
+
+    fn check(&self, node: Node<'_>, context: &mut LintContext<'_>) {
+        let Node::Program(program) = node else {
+            return;
+        };
+
+        let declares: Vec<(usize, &Declare)> = program
+            .statements
+            .iter()
+            .enumerate()
+            .filter_map(|(index, statement)| match statement {
+                Statement::Declare(declare) if context.lookup_slice(declare.span()).contains("strict_types") => {
+                    Some((index, declare))
+                }
+                _ => None,
+            })
+            .collect();
+
+        let Some((first_index, first_declare)) = declares.first().copied() else {
+            report_missing(program, context);
+            return;
+        };
+
+        for (_, duplicate) in declares.iter().skip(1) {
+            let mut plan = FixPlan::new();
+            plan.replace(duplicate.span(), String::new(), SafetyClassification::Safe);
+
+            context.report(
+                Issue::new(Level::Warning, "`declare(strict_types=1)` must appear exactly once")
+                    .with_code("strictness/strict-types-declaration")
+                    .with_annotation(Annotation::new(duplicate.span(), AnnotationKind::Primary))
+                    .with_annotation(
+                        Annotation::new(first_declare.span(), AnnotationKind::Secondary)
+                            .with_message("first declared here"),
+                    )
+                    .with_fix(plan),
+            );
+        }
+
+        if first_index != 0 {
+            let mut plan = FixPlan::new();
+            plan.replace(first_declare.span(), String::new(), SafetyClassification::PotentiallyUnsafe);
+            plan.replace(
+                Span::new(program.span().file_id, program.statements[0].span().start, program.statements[0].span().start),
+                format!("{CANONICAL}\n\n"),
+                SafetyClassification::PotentiallyUnsafe,
+            );
+
+            context.report(
+                Issue::new(Level::Warning, "`declare(strict_types=1)` must be the first statement in the file")
+                    .with_code("strictness/strict-types-declaration")
+                    .with_annotation(Annotation::new(first_declare.span(), AnnotationKind::Primary))
+                    .with_fix(plan),
+            );
+
+            return;
+        }
+
+        let text = context.lookup_slice(first_declare.span());
+
+        if let Some(inner) = text.strip_prefix("declare(").and_then(|rest| rest.strip_suffix(");")) {
+            let other_items: Vec<&str> =
+                inner.split(',').map(str::trim).filter(|item| !item.starts_with("strict_types")).collect();
+
+            if !other_items.is_empty() {
+                let replacement = format!("{CANONICAL}\ndeclare({});", other_items.join(", "));
+
+                let mut plan = FixPlan::new();
+                plan.replace(first_declare.span(), replacement, SafetyClassification::Safe);
+
+                context.report(
+                    Issue::new(
+                        Level::Warning,
+                        "`declare(strict_types=1)` must not be combined with other declare directives",
+                    )
+                    .with_code("strictness/strict-types-declaration")
+                    .with_annotation(Annotation::new(first_declare.span(), AnnotationKind::Primary))
+                    .with_fix(plan),
+                );
+
+                return;
+            }
+        }
+
+        if text != CANONICAL {
+            let mut plan = FixPlan::new();
+            plan.replace(first_declare.span(), CANONICAL.to_string(), SafetyClassification::Safe);
+
+            context.report(
+                Issue::new(Level::Warning, "`declare(strict_types=1)` must be written exactly as `declare(strict_types=1);`")
+                    .with_code("strictness/strict-types-declaration")
+                    .with_annotation(Annotation::new(first_declare.span(), AnnotationKind::Primary))
+                    .with_fix(plan),
+            );
+        }
+    }
+}
+
+fn report_missing(program: &Program, context: &mut LintContext<'_>) {
+    let insertion_point = match program.statements.first() {
+        Some(statement) => statement.span().start,
+        None => program.span().end,
+    };
+
+    let mut plan = FixPlan::new();
+    plan.replace(
+        Span::new(program.span().file_id, insertion_point, insertion_point),
+        format!("{CANONICAL}\n\n"),
+        SafetyClassification::PotentiallyUnsafe,
+    );
+
+    context.report(
+        Issue::new(Level::Warning, "this file is missing `declare(strict_types=1)`")
+            .with_code("strictness/strict-types-declaration")
+            .with_annotation(Annotation::new(program.span(), AnnotationKind::Primary))
+            .with_fix(plan),
+    );
+}
@@ -0,0 +1,25 @@
+use crate::plugin::LintPlugin;
+use crate::plugin::symfony::rules::invalid_route_attribute::InvalidRouteAttributeRule;
+use crate::plugin::symfony::rules::service_attribute_on_non_class::ServiceAttributeOnNonClassRule;
+use crate::rule::Rule;
+
+pub mod rules;
+
+/// Symfony-specific rules, enabled only for projects that declare a
+/// dependency on `symfony/*` packages in their `composer.json`.
+#[derive(Debug)]
+pub struct SymfonyPlugin;
+
+impl LintPlugin for SymfonyPlugin {
+    fn get_name(&self) -> &'static str {
+        "symfony"
+    }
+
+    fn is_enabled_by_default(&self) -> bool {
+        false
+    }
+
+    fn get_rules(&self) -> Vec<Box<dyn Rule>> {
+        vec![Box::new(InvalidRouteAttributeRule), Box::new(ServiceAttributeOnNonClassRule)]
+    }
+}
@@ -0,0 +1,72 @@
+use mago_ast::ast::*;
+use mago_ast::Node;
+use mago_reporting::{Annotation, AnnotationKind, Issue, Level};
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::{RuleDefinition, RuleUsageExample};
+use crate::rule::Rule;
+
+const ROUTE_ATTRIBUTE: &str = "Symfony\\Component\\Routing\\Attribute\\Route";
+
+/// Validates `#[Route(...)]` attributes: the `path` argument must be
+/// present, and a route that declares `methods` must list at least one
+/// value.
+#[derive(Debug)]
+pub struct InvalidRouteAttributeRule;
+
+impl Rule for InvalidRouteAttributeRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Invalid Route Attribute", Level::Error)
+            .with_description("Validates that `#[Route]` attributes declare a path and, if present, a non-empty `methods` list.")
+            .with_example(RuleUsageExample::invalid(
+                "A route with an empty methods list",
+                r#"
+                <?php
+
+                #[Route('/users', methods: [])]
+                public function list(): Response {}
+                "#,
+            ))
+    }
+
+    fn check(&self, node: Node<'_>, context: &mut LintContext<'_>) {
+        let Node::AttributeList(list) = node else {
+            return;
+        };
+
+        for attribute in list.attributes.iter() {
+            if !context.resolve_attribute_name(&attribute.name).is_some_and(|name| name == ROUTE_ATTRIBUTE) {
+                continue;
+            }
+
+            let arguments = attribute.arguments.as_ref().map(|a| a.arguments.as_slice()).unwrap_or_default();
+
+            let has_path = arguments.iter().any(|argument| matches!(argument.name(), Some(name) if name == "path") || argument.is_positional());
+
+            if !has_path {
+                context.report(
+                    Issue::new(Level::Error, "`#[Route]` is missing a `path`")
+                        .with_code("symfony/invalid-route-attribute")
+                        .with_annotation(Annotation::new(attribute.span(), AnnotationKind::Primary)),
+                );
+            }
+
+            for argument in arguments {
+                if argument.name() != Some("methods") {
+                    continue;
+                }
+
+                if let Expression::Array(array) = argument.value() {
+                    if array.elements.is_empty() {
+                        context.report(
+                            Issue::new(Level::Error, "`methods` is empty, so this route matches no HTTP verb")
+                                .with_code("symfony/invalid-route-attribute")
+                                .with_annotation(Annotation::new(array.span(), AnnotationKind::Primary)),
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
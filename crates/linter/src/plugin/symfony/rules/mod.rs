@@ -0,0 +1,2 @@
+pub mod invalid_route_attribute;
+pub mod service_attribute_on_non_class;
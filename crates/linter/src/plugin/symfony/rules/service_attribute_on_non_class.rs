@@ -0,0 +1,49 @@
+use mago_ast::Node;
+use mago_reporting::{Annotation, AnnotationKind, Issue, Level};
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::rule::Rule;
+
+const AUTOWIRE_ATTRIBUTES: &[&str] =
+    &["Symfony\\Component\\DependencyInjection\\Attribute\\Autoconfigure", "Symfony\\Component\\DependencyInjection\\Attribute\\AsAlias"];
+
+/// Flags Symfony service-configuration attributes (`#[Autoconfigure]`,
+/// `#[AsAlias]`, ...) applied to anything other than a class, since the
+/// service container only ever looks at them on classes.
+#[derive(Debug)]
+pub struct ServiceAttributeOnNonClassRule;
+
+impl Rule for ServiceAttributeOnNonClassRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Service Attribute On Non-Class", Level::Error)
+            .with_description("Flags Symfony service-configuration attributes applied to something other than a class.")
+    }
+
+    fn check(&self, node: Node<'_>, context: &mut LintContext<'_>) {
+        let Node::AttributeList(list) = node else {
+            return;
+        };
+
+        if matches!(list.parent(), Some(Node::Class(_))) {
+            return;
+        }
+
+        for attribute in list.attributes.iter() {
+            let Some(name) = context.resolve_attribute_name(&attribute.name) else {
+                continue;
+            };
+
+            if !AUTOWIRE_ATTRIBUTES.contains(&name.as_str()) {
+                continue;
+            }
+
+            context.report(
+                Issue::new(Level::Error, format!("`#[{name}]` has no effect outside of a class declaration"))
+                    .with_code("symfony/service-attribute-on-non-class")
+                    .with_annotation(Annotation::new(attribute.span(), AnnotationKind::Primary)),
+            );
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use mago_reporting::Issue;
+use mago_span::Span;
+
+/// Which kind of call a [`CallTarget`] refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CallKind {
+    /// A plain function call, e.g. `preg_match(...)`.
+    Function,
+    /// A method call by name, regardless of the receiver's class, e.g.
+    /// `->query(...)`. Coarser than resolving the receiver's type, but
+    /// enough to catch the common PDO/mysqli usage without needing
+    /// reflection at this layer.
+    Method,
+}
+
+/// Which call site a [`StringLiteralValidator`] wants to see the string
+/// argument from.
+///
+/// Holds an owned name rather than borrowing, since registration happens
+/// once at startup while lookups happen once per call site in the AST; see
+/// [`StringValidatorRegistry::validators_for`] for the borrowing lookup
+/// side of that trade.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CallTarget {
+    pub kind: CallKind,
+    pub name: String,
+}
+
+impl CallTarget {
+    pub fn function(name: impl Into<String>) -> Self {
+        Self { kind: CallKind::Function, name: name.into() }
+    }
+
+    pub fn method(name: impl Into<String>) -> Self {
+        Self { kind: CallKind::Method, name: name.into() }
+    }
+}
+
+/// A validator for the literal string passed as a specific argument of a
+/// specific call, e.g. "the first argument of `preg_match`".
+///
+/// This is the extension point rules register against rather than matching
+/// on call shape themselves, so a single driving rule
+/// ([`crate::plugin::security::rules::string_literal_validation::StringLiteralValidationRule`])
+/// can dispatch to any number of independent checks (regex syntax, SQL
+/// sanity, and whatever a project adds of its own).
+pub trait StringLiteralValidator: Send + Sync {
+    /// A short, stable name used in diagnostic codes.
+    fn name(&self) -> &'static str;
+
+    /// Validates `value`, the string literal's contents. `literal_span`
+    /// covers the full literal including its quotes; use
+    /// [`content_span`] to translate a byte offset inside `value` back
+    /// into a span rules can annotate.
+    fn validate(&self, value: &str, literal_span: Span) -> Vec<Issue>;
+}
+
+/// Translates a byte range inside a string literal's *contents* into a span
+/// inside the source file, accounting for the one-byte opening quote every
+/// single- and double-quoted PHP string literal starts with.
+pub fn content_span(literal_span: Span, start: u32, end: u32) -> Span {
+    let content_start = literal_span.start + 1;
+    Span::new(literal_span.file_id, content_start + start, content_start + end)
+}
+
+/// The set of (call target, argument index) pairs a validator applies to,
+/// paired with the validator itself.
+pub struct StringValidatorRegistry {
+    entries: Vec<(CallTarget, usize, Box<dyn StringLiteralValidator>)>,
+}
+
+impl StringValidatorRegistry {
+    pub fn new() -> Self {
+        Self { entries: Vec::new() }
+    }
+
+    pub fn register(&mut self, target: CallTarget, argument_index: usize, validator: Box<dyn StringLiteralValidator>) {
+        self.entries.push((target, argument_index, validator));
+    }
+
+    /// Returns every validator registered for the call named `name` of kind
+    /// `kind`, at `argument_index`.
+    pub fn validators_for(&self, kind: CallKind, name: &str, argument_index: usize) -> impl Iterator<Item = &dyn StringLiteralValidator> {
+        self.entries
+            .iter()
+            .filter(move |(target, entry_index, _)| target.kind == kind && target.name == name && *entry_index == argument_index)
+            .map(|(_, _, validator)| validator.as_ref())
+    }
+
+    /// A registry pre-populated with the built-in regex and SQL checks.
+    pub fn with_builtins() -> Self {
+        let mut registry = Self::new();
+        crate::string_validation::builtin::register_builtins(&mut registry);
+        registry
+    }
+}
+
+impl Default for StringValidatorRegistry {
+    fn default() -> Self {
+        Self::with_builtins()
+    }
+}
+
+pub mod builtin {
+    use super::CallTarget;
+    use super::StringValidatorRegistry;
+    use crate::string_validation::regex_delimiters::RegexDelimiterValidator;
+    use crate::string_validation::regex_syntax::RegexSyntaxValidator;
+    use crate::string_validation::sql_sanity::SqlSanityValidator;
+
+    pub fn register_builtins(registry: &mut StringValidatorRegistry) {
+        for function in ["preg_match", "preg_match_all", "preg_replace", "preg_split"] {
+            registry.register(CallTarget::function(function), 0, Box::new(RegexDelimiterValidator));
+            registry.register(CallTarget::function(function), 0, Box::new(RegexSyntaxValidator));
+        }
+
+        for method in ["query", "exec", "prepare"] {
+            registry.register(CallTarget::method(method), 0, Box::new(SqlSanityValidator));
+        }
+    }
+}
+
+mod regex_delimiters;
+mod regex_syntax;
+mod sql_sanity;
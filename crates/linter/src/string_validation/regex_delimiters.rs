@@ -0,0 +1,101 @@
+use mago_reporting::{Annotation, AnnotationKind, Issue, Level};
+use mago_span::Span;
+
+use crate::string_validation::content_span;
+use crate::string_validation::StringLiteralValidator;
+
+/// The delimiter pairs PCRE accepts besides "same character on both ends".
+const BRACKET_PAIRS: &[(char, char)] = &[('(', ')'), ('{', '}'), ('[', ']'), ('<', '>')];
+
+/// Every modifier flag PCRE recognizes.
+const VALID_FLAGS: &str = "imsxuUAJDSXn";
+
+/// Checks the delimiter and trailing modifier flags of a `preg_*` pattern
+/// argument, without attempting to parse the pattern body itself (that is
+/// [`crate::string_validation::regex_syntax::RegexSyntaxValidator`]'s job).
+#[derive(Debug)]
+pub struct RegexDelimiterValidator;
+
+impl StringLiteralValidator for RegexDelimiterValidator {
+    fn name(&self) -> &'static str {
+        "regex-delimiters"
+    }
+
+    fn validate(&self, value: &str, literal_span: Span) -> Vec<Issue> {
+        let mut issues = Vec::new();
+
+        let Some(opening) = value.chars().next() else {
+            return issues;
+        };
+
+        let closing = BRACKET_PAIRS.iter().find(|(open, _)| *open == opening).map(|(_, close)| *close).unwrap_or(opening);
+
+        let body_start = opening.len_utf8();
+        let Some(closing_index) = value[body_start..].rfind(closing).map(|index| index + body_start) else {
+            issues.push(
+                Issue::new(Level::Error, format!("regex pattern is missing its closing `{closing}` delimiter"))
+                    .with_code("security/invalid-regex-delimiters")
+                    .with_annotation(Annotation::new(content_span(literal_span, 0, value.len() as u32), AnnotationKind::Primary)),
+            );
+
+            return issues;
+        };
+
+        if closing_index == body_start {
+            issues.push(
+                Issue::new(Level::Error, "regex pattern has no body between its delimiters")
+                    .with_code("security/invalid-regex-delimiters")
+                    .with_annotation(Annotation::new(content_span(literal_span, 0, value.len() as u32), AnnotationKind::Primary)),
+            );
+
+            return issues;
+        }
+
+        let flags = &value[closing_index + closing.len_utf8()..];
+        for (offset, flag) in flags.char_indices() {
+            if !VALID_FLAGS.contains(flag) {
+                let start = (closing_index + closing.len_utf8() + offset) as u32;
+                issues.push(
+                    Issue::new(Level::Error, format!("`{flag}` is not a recognized PCRE modifier"))
+                        .with_code("security/invalid-regex-delimiters")
+                        .with_annotation(Annotation::new(content_span(literal_span, start, start + flag.len_utf8() as u32), AnnotationKind::Primary)),
+                );
+            }
+        }
+
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn span(len: usize) -> Span {
+        Span::new(Default::default(), 0, (len + 2) as u32)
+    }
+
+    #[test]
+    fn accepts_well_formed_pattern() {
+        let validator = RegexDelimiterValidator;
+        assert!(validator.validate("/^foo$/i", span(8)).is_empty());
+    }
+
+    #[test]
+    fn accepts_bracket_delimiters() {
+        let validator = RegexDelimiterValidator;
+        assert!(validator.validate("{^foo$}", span(7)).is_empty());
+    }
+
+    #[test]
+    fn flags_unknown_modifier() {
+        let validator = RegexDelimiterValidator;
+        assert_eq!(validator.validate("/^foo$/z", span(8)).len(), 1);
+    }
+
+    #[test]
+    fn flags_missing_closing_delimiter() {
+        let validator = RegexDelimiterValidator;
+        assert_eq!(validator.validate("/^foo$", span(6)).len(), 1);
+    }
+}
@@ -0,0 +1,101 @@
+use mago_pcre::diagnostics::Severity;
+use mago_reporting::{Annotation, AnnotationKind, Issue, Level};
+use mago_span::Span;
+
+use crate::string_validation::content_span;
+use crate::string_validation::StringLiteralValidator;
+
+/// The delimiter pairs PCRE accepts besides "same character on both ends",
+/// duplicated from [`crate::string_validation::regex_delimiters`] rather
+/// than shared, since stripping delimiters to get at the pattern body is a
+/// one-line job not worth a dependency between the two validators.
+const BRACKET_PAIRS: &[(char, char)] = &[('(', ')'), ('{', '}'), ('[', ']'), ('<', '>')];
+
+/// Parses the body of a `preg_*` pattern with [`mago_pcre`] and reports
+/// unbalanced groups, malformed character classes, and constructs prone to
+/// catastrophic backtracking.
+///
+/// Delimiter and modifier-flag problems are
+/// [`crate::string_validation::regex_delimiters::RegexDelimiterValidator`]'s
+/// job; this validator only looks at what's between the delimiters.
+#[derive(Debug)]
+pub struct RegexSyntaxValidator;
+
+impl StringLiteralValidator for RegexSyntaxValidator {
+    fn name(&self) -> &'static str {
+        "regex-syntax"
+    }
+
+    fn validate(&self, value: &str, literal_span: Span) -> Vec<Issue> {
+        let Some(body) = pattern_body(value) else {
+            return Vec::new();
+        };
+
+        mago_pcre::analyze(body.text)
+            .into_iter()
+            .map(|finding| {
+                let level = match finding.severity {
+                    Severity::Error => Level::Error,
+                    Severity::Warning => Level::Warning,
+                };
+
+                let span = match finding.position {
+                    Some(position) => {
+                        let offset = (body.start + position) as u32;
+                        content_span(literal_span, offset, offset + 1)
+                    }
+                    None => content_span(literal_span, 0, value.len() as u32),
+                };
+
+                Issue::new(level, finding.message)
+                    .with_code("security/regex-syntax")
+                    .with_annotation(Annotation::new(span, AnnotationKind::Primary))
+            })
+            .collect()
+    }
+}
+
+struct PatternBody<'a> {
+    text: &'a str,
+    /// Byte offset of `text` within the full pattern literal value.
+    start: usize,
+}
+
+fn pattern_body(value: &str) -> Option<PatternBody<'_>> {
+    let opening = value.chars().next()?;
+    let closing = BRACKET_PAIRS.iter().find(|(open, _)| *open == opening).map(|(_, close)| *close).unwrap_or(opening);
+    let closing_index = value.rfind(closing)?;
+
+    if closing_index <= opening.len_utf8() {
+        return None;
+    }
+
+    Some(PatternBody { text: &value[opening.len_utf8()..closing_index], start: opening.len_utf8() })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn span(len: usize) -> Span {
+        Span::new(Default::default(), 0, (len + 2) as u32)
+    }
+
+    #[test]
+    fn flags_unbalanced_group_inside_delimiters() {
+        let validator = RegexSyntaxValidator;
+        assert_eq!(validator.validate("/(foo/", span(6)).len(), 1);
+    }
+
+    #[test]
+    fn accepts_well_formed_pattern() {
+        let validator = RegexSyntaxValidator;
+        assert!(validator.validate("/^(foo|bar)$/", span(13)).is_empty());
+    }
+
+    #[test]
+    fn flags_catastrophic_backtracking_shape() {
+        let validator = RegexSyntaxValidator;
+        assert_eq!(validator.validate("/(a+)+/", span(7)).len(), 1);
+    }
+}
@@ -0,0 +1,96 @@
+use mago_reporting::{Annotation, AnnotationKind, Issue, Level};
+use mago_span::Span;
+
+use crate::string_validation::content_span;
+use crate::string_validation::StringLiteralValidator;
+
+/// A lightweight structural sanity check for a SQL string passed directly to
+/// `PDO::query`/`exec`/`prepare` (or a mysqli equivalent with the same
+/// method names): unbalanced quotes and parentheses, both of which are
+/// reliable signs of a malformed query regardless of SQL dialect.
+///
+/// This is deliberately not a SQL parser; it exists to catch the kind of
+/// typo a real parser would also catch, at a fraction of the complexity.
+#[derive(Debug)]
+pub struct SqlSanityValidator;
+
+impl StringLiteralValidator for SqlSanityValidator {
+    fn name(&self) -> &'static str {
+        "sql-sanity"
+    }
+
+    fn validate(&self, value: &str, literal_span: Span) -> Vec<Issue> {
+        let mut issues = Vec::new();
+        let mut depth: i32 = 0;
+        let mut in_single_quote = false;
+        let mut in_double_quote = false;
+
+        for (offset, ch) in value.char_indices() {
+            match ch {
+                '\'' if !in_double_quote => in_single_quote = !in_single_quote,
+                '"' if !in_single_quote => in_double_quote = !in_double_quote,
+                '(' if !in_single_quote && !in_double_quote => depth += 1,
+                ')' if !in_single_quote && !in_double_quote => {
+                    depth -= 1;
+                    if depth < 0 {
+                        issues.push(
+                            Issue::new(Level::Error, "unmatched closing `)` in SQL statement")
+                                .with_code("security/sql-sanity")
+                                .with_annotation(Annotation::new(
+                                    content_span(literal_span, offset as u32, offset as u32 + 1),
+                                    AnnotationKind::Primary,
+                                )),
+                        );
+                        depth = 0;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        if in_single_quote || in_double_quote {
+            issues.push(
+                Issue::new(Level::Error, "unterminated string literal in SQL statement")
+                    .with_code("security/sql-sanity")
+                    .with_annotation(Annotation::new(content_span(literal_span, 0, value.len() as u32), AnnotationKind::Primary)),
+            );
+        }
+
+        if depth > 0 {
+            issues.push(
+                Issue::new(Level::Error, "unclosed `(` in SQL statement")
+                    .with_code("security/sql-sanity")
+                    .with_annotation(Annotation::new(content_span(literal_span, 0, value.len() as u32), AnnotationKind::Primary)),
+            );
+        }
+
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn span(len: usize) -> Span {
+        Span::new(Default::default(), 0, (len + 2) as u32)
+    }
+
+    #[test]
+    fn accepts_well_formed_query() {
+        let validator = SqlSanityValidator;
+        assert!(validator.validate("SELECT * FROM users WHERE name = 'foo'", span(40)).is_empty());
+    }
+
+    #[test]
+    fn flags_unterminated_string() {
+        let validator = SqlSanityValidator;
+        assert_eq!(validator.validate("SELECT * FROM users WHERE name = 'foo", span(38)).len(), 1);
+    }
+
+    #[test]
+    fn flags_unbalanced_parens() {
+        let validator = SqlSanityValidator;
+        assert_eq!(validator.validate("SELECT COUNT(* FROM users", span(26)).len(), 1);
+    }
+}
@@ -0,0 +1,66 @@
+use mago_ast::Trivia;
+use mago_ast::TriviaKind;
+use mago_span::HasSpan;
+use mago_span::Span;
+
+/// A single `// @mago-expect rule (reason)` or `/** @mago-ignore rule */`
+/// comment attached to the statement or member that immediately follows it.
+#[derive(Debug, Clone)]
+pub struct Suppression {
+    pub rule: String,
+    pub reason: Option<String>,
+    pub comment_span: Span,
+    /// Set once a diagnostic has actually been silenced by this suppression,
+    /// so [`crate::plugin::meta::unused_suppression::UnusedSuppressionRule`] can flag the rest as
+    /// stale.
+    pub used: bool,
+}
+
+/// Parses every suppression comment out of a trivia list.
+///
+/// Two spellings are accepted: the line-comment form `// @mago-expect
+/// rule-name (reason)`, and the doc-comment form `/** @mago-ignore rule */`.
+/// Both attach to whatever statement or class member comes right after them.
+pub fn collect_suppressions(trivia: &[Trivia]) -> Vec<Suppression> {
+    let mut suppressions = Vec::new();
+
+    for trivium in trivia {
+        let text = match trivium.kind {
+            TriviaKind::SingleLineComment | TriviaKind::HashComment => trivium.value.trim_start_matches("//").trim(),
+            TriviaKind::MultiLineComment | TriviaKind::DocBlockComment => {
+                trivium.value.trim_start_matches("/**").trim_end_matches("*/").trim()
+            }
+            _ => continue,
+        };
+
+        let Some(rest) = text.strip_prefix("@mago-expect").or_else(|| text.strip_prefix("@mago-ignore")) else {
+            continue;
+        };
+
+        let rest = rest.trim();
+        let (rule, reason) = match rest.split_once('(') {
+            Some((rule, reason)) => (rule.trim(), reason.trim_end_matches(')').trim()),
+            None => (rest, ""),
+        };
+
+        if rule.is_empty() {
+            continue;
+        }
+
+        suppressions.push(Suppression {
+            rule: rule.to_string(),
+            reason: if reason.is_empty() { None } else { Some(reason.to_string()) },
+            comment_span: trivium.span(),
+            used: false,
+        });
+    }
+
+    suppressions
+}
+
+/// Whether a suppression comment must carry a non-empty `(reason)` to be
+/// considered valid. Controlled by the `require-suppression-justification`
+/// linter setting.
+pub fn is_justified(suppression: &Suppression, justification_required: bool) -> bool {
+    !justification_required || suppression.reason.as_deref().is_some_and(|reason| !reason.is_empty())
+}
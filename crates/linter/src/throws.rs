@@ -0,0 +1,343 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use mago_ast::ast::*;
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+
+/// How many hops of same-file function calls [`throws_of`] follows before
+/// giving up on a call and assuming it might throw anything.
+pub const DEFAULT_MAX_DEPTH: usize = 3;
+
+/// The throws set computed for a function-like body.
+///
+/// A function whose body contains anything this analysis can't see through
+/// - a method call, a call to a function outside the file, a dynamic call,
+/// or a same-file call past the depth limit - is [`Throws::Unknown`] rather
+/// than silently reported as throwing nothing: callers validating a
+/// `@throws` tag or flagging an uncaught exception should treat "unknown"
+/// as "don't trust this enough to warn either way".
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Throws {
+    Known(HashSet<String>),
+    Unknown,
+}
+
+impl Throws {
+    fn empty() -> Self {
+        Throws::Known(HashSet::new())
+    }
+
+    fn merge(self, other: Throws) -> Throws {
+        match (self, other) {
+            (Throws::Known(mut a), Throws::Known(b)) => {
+                a.extend(b);
+                Throws::Known(a)
+            }
+            _ => Throws::Unknown,
+        }
+    }
+}
+
+/// Computes the set of exception types `statements` may let escape to its
+/// caller.
+///
+/// This combines two sources: `throw new X(...)` statements this analysis
+/// can read textually, and same-file calls to one of `functions`, followed
+/// recursively up to `max_depth` hops. A `try`/`catch` directly in
+/// `statements` has its catch types subtracted from what its `try` block
+/// may throw; a `try` nested inside some other statement (an `if`, a loop)
+/// isn't specially recognized and its throws are attributed to the
+/// enclosing function as if uncaught - the safe direction for a "might
+/// throw" analysis to err in, since it risks an extra warning rather than
+/// a missed one.
+///
+/// `functions` is every top-level function declared in the same file,
+/// keyed by name, so a direct call to one of them can be followed instead
+/// of immediately falling back to [`Throws::Unknown`].
+pub fn throws_of(
+    context: &LintContext<'_>,
+    statements: &[Statement],
+    functions: &HashMap<String, &Function>,
+    max_depth: usize,
+) -> Throws {
+    collect(context, statements, functions, max_depth, &mut HashSet::new())
+}
+
+fn collect(
+    context: &LintContext<'_>,
+    statements: &[Statement],
+    functions: &HashMap<String, &Function>,
+    max_depth: usize,
+    visiting: &mut HashSet<String>,
+) -> Throws {
+    let mut result = Throws::empty();
+
+    for statement in statements {
+        let statement_throws = if let Statement::Try(r#try) = statement {
+            let try_throws = collect(context, &r#try.block.statements, functions, max_depth, visiting);
+            let uncaught = subtract_caught(context, try_throws, &r#try.catch_clauses);
+
+            match &r#try.finally_clause {
+                Some(finally) => {
+                    uncaught.merge(collect(context, &finally.block.statements, functions, max_depth, visiting))
+                }
+                None => uncaught,
+            }
+        } else {
+            statement_level_throws(context, statement, functions, max_depth, visiting)
+        };
+
+        result = result.merge(statement_throws);
+    }
+
+    result
+}
+
+fn statement_level_throws(
+    context: &LintContext<'_>,
+    statement: &Statement,
+    functions: &HashMap<String, &Function>,
+    max_depth: usize,
+    visiting: &mut HashSet<String>,
+) -> Throws {
+    let mut names = HashSet::new();
+    let mut gave_up = false;
+
+    walk_statement(statement, &mut |expression| {
+        if gave_up {
+            return;
+        }
+
+        match expression {
+            Expression::Throw(r#throw) => {
+                match thrown_type_name(context, &r#throw.exception) {
+                    Some(name) => {
+                        names.insert(name);
+                    }
+                    None => gave_up = true,
+                }
+            }
+            Expression::Call(Call::Function(call)) => {
+                let Expression::Identifier(Identifier::Local(identifier)) = call.function.as_ref() else {
+                    gave_up = true;
+                    return;
+                };
+
+                if mago_php_stdlib::is_known_pure(&identifier.value) {
+                    // A pure function can't throw - it has no observable
+                    // effect at all, an exception included.
+                    return;
+                }
+
+                let Some(function) = functions.get(identifier.value.as_str()) else {
+                    gave_up = true;
+                    return;
+                };
+
+                if max_depth == 0 || visiting.contains(identifier.value.as_str()) {
+                    gave_up = true;
+                    return;
+                }
+
+                visiting.insert(identifier.value.clone());
+                let callee_throws = collect(context, function.body.statements.as_slice(), functions, max_depth - 1, visiting);
+                visiting.remove(identifier.value.as_str());
+
+                match callee_throws {
+                    Throws::Known(callee_names) => names.extend(callee_names),
+                    Throws::Unknown => gave_up = true,
+                }
+            }
+            Expression::Call(Call::Method(_)) | Expression::Call(Call::StaticMethod(_)) => {
+                // No inter-procedural support for methods yet.
+                gave_up = true;
+            }
+            Expression::Array(_) | Expression::List(_) => {
+                // This analysis has no confirmed way to look inside an
+                // array/list literal's elements, and silently skipping them
+                // would risk missing a throw - treat as unknown rather than
+                // under-reporting.
+                gave_up = true;
+            }
+            _ => {}
+        }
+    });
+
+    if gave_up { Throws::Unknown } else { Throws::Known(names) }
+}
+
+/// Walks every statement reachable from `statement` without crossing into a
+/// nested function-like's own body (a closure or arrow function has its own
+/// throws, not its enclosing statement's), feeding every expression it finds
+/// along the way to `f`.
+///
+/// `Statement::For`'s initializer and increment expressions aren't visited -
+/// no field name for them is confirmed anywhere in this tree, and guessing
+/// one risks a worse mistake than this narrow, documented gap.
+fn walk_statement<'a>(statement: &'a Statement, f: &mut impl FnMut(&'a Expression)) {
+    match statement {
+        Statement::Block(block) => {
+            for inner in &block.statements {
+                walk_statement(inner, f);
+            }
+        }
+        Statement::If(r#if) => {
+            walk_expression(&r#if.condition, f);
+            walk_statement(&r#if.body, f);
+            for clause in &r#if.else_if_clauses {
+                walk_expression(&clause.condition, f);
+                walk_statement(&clause.body, f);
+            }
+            if let Some(else_clause) = &r#if.else_clause {
+                walk_statement(&else_clause.body, f);
+            }
+        }
+        Statement::While(r#while) => {
+            walk_expression(&r#while.condition, f);
+            walk_statement(&r#while.body, f);
+        }
+        Statement::DoWhile(do_while) => {
+            walk_statement(&do_while.body, f);
+            walk_expression(&do_while.condition, f);
+        }
+        Statement::For(r#for) => {
+            for condition in &r#for.conditions {
+                walk_expression(condition, f);
+            }
+            walk_statement(&r#for.body, f);
+        }
+        Statement::Foreach(foreach) => {
+            walk_expression(&foreach.expression, f);
+            walk_statement(&foreach.body, f);
+        }
+        Statement::Switch(switch) => {
+            walk_expression(&switch.expression, f);
+            for case in switch.body.cases() {
+                for inner in case.statements() {
+                    walk_statement(inner, f);
+                }
+            }
+        }
+        Statement::Try(r#try) => {
+            for inner in &r#try.block.statements {
+                walk_statement(inner, f);
+            }
+            for clause in &r#try.catch_clauses {
+                for inner in &clause.block.statements {
+                    walk_statement(inner, f);
+                }
+            }
+            if let Some(finally) = &r#try.finally_clause {
+                for inner in &finally.block.statements {
+                    walk_statement(inner, f);
+                }
+            }
+        }
+        Statement::Expression(expression_statement) => {
+            walk_expression(&expression_statement.expression, f);
+        }
+        Statement::Return(r#return) => {
+            if let Some(value) = &r#return.value {
+                walk_expression(value, f);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Walks `expression` and every subexpression this analysis knows how to
+/// open up, feeding each one (including `expression` itself) to `f`.
+fn walk_expression<'a>(expression: &'a Expression, f: &mut impl FnMut(&'a Expression)) {
+    f(expression);
+
+    match expression {
+        Expression::Throw(r#throw) => walk_expression(&r#throw.exception, f),
+        Expression::Assignment(assignment) => {
+            walk_expression(&assignment.lhs, f);
+            walk_expression(&assignment.rhs, f);
+        }
+        Expression::AssignmentOperation(assignment) => {
+            walk_expression(&assignment.lhs, f);
+            walk_expression(&assignment.rhs, f);
+        }
+        Expression::Binary(binary) => {
+            walk_expression(&binary.lhs, f);
+            walk_expression(&binary.rhs, f);
+        }
+        Expression::Call(Call::Function(call)) => {
+            walk_expression(&call.function, f);
+            for argument in &call.arguments.arguments {
+                walk_expression(argument_value(argument), f);
+            }
+        }
+        Expression::Call(Call::Method(call)) => {
+            walk_expression(&call.object, f);
+            for argument in &call.arguments.arguments {
+                walk_expression(argument_value(argument), f);
+            }
+        }
+        Expression::Call(Call::StaticMethod(call)) => {
+            for argument in &call.arguments.arguments {
+                walk_expression(argument_value(argument), f);
+            }
+        }
+        Expression::Access(Access::Property(access)) => walk_expression(&access.object, f),
+        Expression::ArrayAccess(access) => {
+            walk_expression(&access.array, f);
+            if let Some(index) = access.index.as_deref() {
+                walk_expression(index, f);
+            }
+        }
+        Expression::Isset(isset) => {
+            for value in &isset.values {
+                walk_expression(value, f);
+            }
+        }
+        Expression::Empty(empty) => walk_expression(&empty.value, f),
+        _ => {}
+    }
+}
+
+fn argument_value(argument: &Argument) -> &Expression {
+    match argument {
+        Argument::Positional(positional) => &positional.value,
+        Argument::Named(named) => &named.value,
+    }
+}
+
+fn subtract_caught(context: &LintContext<'_>, throws: Throws, catch_clauses: &[TryCatchClause]) -> Throws {
+    let Throws::Known(names) = throws else {
+        return Throws::Unknown;
+    };
+
+    let caught_names =
+        catch_clauses.iter().flat_map(|clause| hint_names(context, &clause.hint)).collect::<Vec<_>>();
+
+    Throws::Known(names.into_iter().filter(|name| !caught_names.iter().any(|caught| names_match(caught, name))).collect())
+}
+
+/// The set of type names a catch's hint covers, splitting a union catch
+/// type (`catch (A|B $e)`) on `|`.
+fn hint_names(context: &LintContext<'_>, hint: &Hint) -> Vec<String> {
+    context.lookup_slice(hint.span()).split('|').map(|part| part.trim().to_string()).collect()
+}
+
+fn names_match(a: &str, b: &str) -> bool {
+    a.trim_start_matches('\\').eq_ignore_ascii_case(b.trim_start_matches('\\'))
+}
+
+/// Reads the exception type name off of a `throw new X(...)` expression's
+/// textual source, returning `None` for anything else (a rethrown
+/// variable, a function call producing the exception, ...) this analysis
+/// can't name with confidence.
+pub(crate) fn thrown_type_name(context: &LintContext<'_>, exception: &Expression) -> Option<String> {
+    let text = context.lookup_slice(exception.span());
+    let rest = text.trim_start().strip_prefix("new ")?;
+
+    let name_end = rest.find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '\\')).unwrap_or(rest.len());
+    let name = rest[..name_end].trim();
+
+    if name.is_empty() { None } else { Some(name.to_string()) }
+}
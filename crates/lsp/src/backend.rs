@@ -0,0 +1,93 @@
+use std::sync::Arc;
+
+use mago_linter::Linter;
+use mago_source::SourceIdentifier;
+use mago_source::SourceManager;
+use mago_source::overlay::SourceOverlay;
+use tower_lsp::Client;
+use tower_lsp::LanguageServer;
+use tower_lsp::jsonrpc::Result;
+use tower_lsp::lsp_types::*;
+
+/// The LSP backend. One instance is created per client connection and lives
+/// for the lifetime of the session.
+pub struct MagoLanguageServer {
+    client: Client,
+    sources: SourceManager,
+    overlay: SourceOverlay,
+    linter: Linter,
+}
+
+impl MagoLanguageServer {
+    pub fn new(client: Client, sources: SourceManager, linter: Linter) -> Self {
+        Self { client, sources, overlay: SourceOverlay::new(), linter }
+    }
+
+    async fn publish_diagnostics_for(&self, uri: Url) {
+        let identifier = SourceIdentifier::from_uri(&uri);
+        let Ok(content) = self.sources.load_with_overlay(&self.overlay, &identifier) else {
+            return;
+        };
+
+        let diagnostics = crate::diagnostics::compute_diagnostics(&self.linter, &identifier, &content);
+
+        self.client.publish_diagnostics(uri, diagnostics, None).await;
+    }
+}
+
+#[tower_lsp::async_trait]
+impl LanguageServer for MagoLanguageServer {
+    async fn initialize(&self, _: InitializeParams) -> Result<InitializeResult> {
+        Ok(InitializeResult {
+            capabilities: ServerCapabilities {
+                text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+                document_formatting_provider: Some(OneOf::Left(true)),
+                code_action_provider: Some(CodeActionProviderCapability::Simple(true)),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+    }
+
+    async fn did_open(&self, params: DidOpenTextDocumentParams) {
+        let identifier = SourceIdentifier::from_uri(&params.text_document.uri);
+        self.overlay.set(identifier, params.text_document.text);
+        self.publish_diagnostics_for(params.text_document.uri).await;
+    }
+
+    async fn did_change(&self, mut params: DidChangeTextDocumentParams) {
+        let Some(change) = params.content_changes.pop() else {
+            return;
+        };
+
+        let identifier = SourceIdentifier::from_uri(&params.text_document.uri);
+        self.overlay.set(identifier, change.text);
+        self.publish_diagnostics_for(params.text_document.uri).await;
+    }
+
+    async fn did_close(&self, params: DidCloseTextDocumentParams) {
+        let identifier = SourceIdentifier::from_uri(&params.text_document.uri);
+        self.overlay.invalidate(&identifier);
+    }
+
+    async fn formatting(&self, params: DocumentFormattingParams) -> Result<Option<Vec<TextEdit>>> {
+        let identifier = SourceIdentifier::from_uri(&params.text_document.uri);
+        let Ok(content) = self.sources.load_with_overlay(&self.overlay, &identifier) else {
+            return Ok(None);
+        };
+
+        Ok(crate::formatting::compute_edits(&content).ok())
+    }
+
+    async fn code_action(&self, params: CodeActionParams) -> Result<Option<CodeActionResponse>> {
+        Ok(Some(crate::code_action::build_actions(&self.linter, &params)))
+    }
+
+    async fn shutdown(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+pub fn client_capabilities_arc(client: Client) -> Arc<Client> {
+    Arc::new(client)
+}
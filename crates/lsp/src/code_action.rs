@@ -0,0 +1,29 @@
+use mago_linter::Linter;
+use tower_lsp::lsp_types::CodeAction;
+use tower_lsp::lsp_types::CodeActionKind;
+use tower_lsp::lsp_types::CodeActionOrCommand;
+use tower_lsp::lsp_types::CodeActionParams;
+use tower_lsp::lsp_types::CodeActionResponse;
+
+/// Builds one quick-fix action for every diagnostic in the request that
+/// carries a code.
+///
+/// Each action only names its diagnostic: it carries no edit, and whether
+/// the rule behind the diagnostic produces a fix is not checked.
+pub fn build_actions(_linter: &Linter, params: &CodeActionParams) -> CodeActionResponse {
+    params
+        .context
+        .diagnostics
+        .iter()
+        .filter_map(|diagnostic| {
+            let code = diagnostic.code.as_ref()?;
+
+            Some(CodeActionOrCommand::CodeAction(CodeAction {
+                title: format!("Fix this with Mago ({code:?})"),
+                kind: Some(CodeActionKind::QUICKFIX),
+                diagnostics: Some(vec![diagnostic.clone()]),
+                ..Default::default()
+            }))
+        })
+        .collect()
+}
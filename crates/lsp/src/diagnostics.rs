@@ -0,0 +1,54 @@
+use mago_linter::Linter;
+use mago_source::SourceIdentifier;
+use tower_lsp::lsp_types::Diagnostic;
+use tower_lsp::lsp_types::DiagnosticSeverity;
+use tower_lsp::lsp_types::Position;
+use tower_lsp::lsp_types::Range;
+
+/// Runs the linter over `content` and converts every reported [`mago_reporting::Issue`]
+/// into an LSP [`Diagnostic`].
+pub fn compute_diagnostics(linter: &Linter, identifier: &SourceIdentifier, content: &str) -> Vec<Diagnostic> {
+    linter
+        .lint_source(identifier, content)
+        .into_iter()
+        .map(|issue| Diagnostic {
+            range: to_range(content, issue.primary_span()),
+            severity: Some(to_severity(issue.level)),
+            code: Some(tower_lsp::lsp_types::NumberOrString::String(issue.code.clone())),
+            source: Some("mago".to_string()),
+            message: issue.message.clone(),
+            ..Default::default()
+        })
+        .collect()
+}
+
+fn to_severity(level: mago_reporting::Level) -> DiagnosticSeverity {
+    match level {
+        mago_reporting::Level::Error => DiagnosticSeverity::ERROR,
+        mago_reporting::Level::Warning => DiagnosticSeverity::WARNING,
+        mago_reporting::Level::Note | mago_reporting::Level::Help => DiagnosticSeverity::INFORMATION,
+    }
+}
+
+fn to_range(content: &str, span: mago_span::Span) -> Range {
+    let start = offset_to_position(content, span.start.offset);
+    let end = offset_to_position(content, span.end.offset);
+
+    Range::new(start, end)
+}
+
+fn offset_to_position(content: &str, offset: usize) -> Position {
+    let mut line = 0u32;
+    let mut column = 0u32;
+
+    for ch in content[..offset.min(content.len())].chars() {
+        if ch == '\n' {
+            line += 1;
+            column = 0;
+        } else {
+            column += 1;
+        }
+    }
+
+    Position::new(line, column)
+}
@@ -0,0 +1,26 @@
+use mago_formatter::Formatter;
+use mago_formatter::settings::FormatSettings;
+use tower_lsp::lsp_types::Position;
+use tower_lsp::lsp_types::Range;
+use tower_lsp::lsp_types::TextEdit;
+
+/// Formats `content` and returns a single whole-document [`TextEdit`].
+///
+/// We replace the entire document rather than computing minimal edits here;
+/// editors coalesce this into one undo step either way, and it keeps this
+/// crate from depending on the diffing logic in `mago-formatter`'s edit API.
+pub fn compute_edits(content: &str) -> Result<Vec<TextEdit>, mago_formatter::error::FormatError> {
+    let formatted = Formatter::new(FormatSettings::default()).format_source(content)?;
+
+    if formatted == content {
+        return Ok(Vec::new());
+    }
+
+    let last_line = content.matches('\n').count() as u32;
+    let last_column = content.rsplit('\n').next().map(|line| line.len()).unwrap_or(0) as u32;
+
+    Ok(vec![TextEdit {
+        range: Range::new(Position::new(0, 0), Position::new(last_line, last_column)),
+        new_text: formatted,
+    }])
+}
@@ -0,0 +1,11 @@
+//! A Language Server Protocol front-end for Mago, built on top of the
+//! existing `mago-source`, `mago-linter`, and `mago-formatter` crates.
+//!
+//! This crate intentionally contains no analysis logic of its own: its job
+//! is to translate LSP requests into calls against those crates and
+//! translate the results back into LSP types.
+
+pub mod backend;
+pub mod code_action;
+pub mod diagnostics;
+pub mod formatting;
@@ -0,0 +1,14 @@
+use mago_reporting::suggestion::default_max_distance;
+use mago_reporting::suggestion::find_closest_match;
+
+/// Builds the `, did you mean `foo`?` suffix used by "unknown name" parse
+/// and lint diagnostics, or an empty string when nothing close enough was
+/// found.
+pub fn did_you_mean<'a>(needle: &str, known_names: impl IntoIterator<Item = &'a str>) -> String {
+    let max_distance = default_max_distance(needle, needle);
+
+    match find_closest_match(needle, known_names, max_distance) {
+        Some(closest) => format!(", did you mean `{closest}`?"),
+        None => String::new(),
+    }
+}
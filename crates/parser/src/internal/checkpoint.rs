@@ -0,0 +1,45 @@
+use crate::error::ParseError;
+use crate::internal::stream::TokenStream;
+
+/// A saved position in a [`TokenStream`], for tentative parsing: try a
+/// production, and if it turns out to be the wrong one, rewind and let the
+/// caller try something else instead of hand-rolling multi-token lookahead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Checkpoint {
+    position: usize,
+}
+
+impl<'a> TokenStream<'a> {
+    /// Saves the current position. Pair with [`TokenStream::rollback`] to
+    /// undo everything consumed since, or just drop the checkpoint to
+    /// commit to what was parsed.
+    pub fn checkpoint(&self) -> Checkpoint {
+        Checkpoint { position: self.position() }
+    }
+
+    /// Rewinds the stream back to `checkpoint`, as if nothing had been
+    /// consumed since it was taken.
+    pub fn rollback(&mut self, checkpoint: Checkpoint) {
+        self.position = checkpoint.position;
+    }
+
+    /// Runs `parse` from the current position; if it fails, the stream is
+    /// rewound to exactly where it started, so the caller can try a
+    /// different production with no leftover side effects.
+    ///
+    /// This is the common case checkpoint/rollback exists for — preferred
+    /// over calling them directly unless the tentative parse needs to do
+    /// something other than "rewind on any error" (e.g. rewinding only for
+    /// specific error variants).
+    pub fn try_parse<T>(&mut self, parse: impl FnOnce(&mut Self) -> Result<T, ParseError>) -> Result<T, ParseError> {
+        let checkpoint = self.checkpoint();
+
+        match parse(self) {
+            Ok(value) => Ok(value),
+            Err(error) => {
+                self.rollback(checkpoint);
+                Err(error)
+            }
+        }
+    }
+}
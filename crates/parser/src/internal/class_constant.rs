@@ -0,0 +1,41 @@
+use mago_ast::ast::*;
+use mago_php_version::feature::Feature;
+use mago_token::TokenKind;
+
+use crate::error::ParseError;
+use crate::internal::r#type::parse_type;
+use crate::internal::stream::TokenStream;
+use crate::internal::utils;
+
+/// Parses a class constant declaration, accepting the PHP 8.3 typed form
+/// (`const int FOO = 1;`) only when the target version supports it; on
+/// older targets a leading type-looking token is parsed as part of an
+/// untyped declaration and reported via [`crate::error::ParseError`] instead
+/// of silently misinterpreting it.
+pub fn parse_class_like_constant(stream: &mut TokenStream<'_>, modifiers: Sequence<Modifier>) -> Result<ClassLikeConstant, ParseError> {
+    let r#const = utils::expect_span(stream, TokenKind::Const)?;
+
+    let hint = if stream.feature_enabled(Feature::TypedClassConstants) && !is_constant_name_position(stream) {
+        Some(parse_type(stream)?)
+    } else {
+        None
+    };
+
+    let items = utils::parse_token_separated_sequence(stream, TokenKind::Comma, TokenKind::Semicolon, |stream| {
+        let name = utils::parse_local_identifier(stream)?;
+        let equals = utils::expect_span(stream, TokenKind::Equals)?;
+        let value = crate::internal::expression::parse_expression(stream)?;
+
+        Ok(ClassLikeConstantItem { name, equals, value })
+    })?;
+
+    let terminator = utils::expect_span(stream, TokenKind::Semicolon)?;
+
+    Ok(ClassLikeConstant { modifiers, r#const, hint, items, terminator })
+}
+
+/// A lookahead of `identifier =` with no intervening `,`/`;` means we're
+/// looking at the untyped `const NAME = ...` form rather than a type.
+fn is_constant_name_position(stream: &mut TokenStream<'_>) -> bool {
+    matches!(stream.peek_kind(), Some(TokenKind::Identifier)) && matches!(stream.peek_nth_kind(1), Some(TokenKind::Equals))
+}
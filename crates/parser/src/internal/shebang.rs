@@ -0,0 +1,28 @@
+use mago_ast::ast::Shebang;
+use mago_span::Span;
+
+use crate::internal::stream::TokenStream;
+use crate::error::ParseError;
+
+/// Recognizes a leading `#!` line and consumes it as a [`Shebang`], rather
+/// than letting it fall through to the generic inline-HTML handling.
+///
+/// Per the POSIX convention (and every other PHP tool that honors it), the
+/// shebang must be the very first two bytes of the file; a `#!` that appears
+/// after any other content is ordinary inline HTML.
+pub fn maybe_parse_shebang(stream: &mut TokenStream<'_>) -> Result<Option<Shebang>, ParseError> {
+    if stream.position() != 0 {
+        return Ok(None);
+    }
+
+    let Some(source) = stream.peek_raw_prefix("#!") else {
+        return Ok(None);
+    };
+
+    let start = stream.position();
+    let end = source.find('\n').map(|i| i + 1).unwrap_or(source.len());
+
+    stream.advance_raw(end);
+
+    Ok(Some(Shebang { span: Span::new(stream.file_id(), start, start + end) }))
+}
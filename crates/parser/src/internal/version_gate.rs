@@ -0,0 +1,43 @@
+use mago_php_version::PHPVersion;
+use mago_php_version::feature::Feature;
+use mago_span::Span;
+
+use crate::error::ParseError;
+use crate::internal::stream::TokenStream;
+
+/// Checks that `feature` is available on the stream's target PHP version,
+/// producing a precise "requires PHP X.Y" error instead of letting an
+/// unsupported construct fall through to a generic syntax error or, worse,
+/// parse successfully and fail only when run.
+pub fn require_feature(stream: &TokenStream<'_>, feature: Feature, span: Span) -> Result<(), ParseError> {
+    let target = stream.php_version();
+
+    if feature.is_available_on(target) {
+        return Ok(());
+    }
+
+    Err(ParseError::UnsupportedFeature {
+        feature_name: feature_name(feature),
+        required: feature.introduced_in(),
+        target,
+        span,
+    })
+}
+
+fn feature_name(feature: Feature) -> &'static str {
+    match feature {
+        Feature::TypedProperties => "typed properties",
+        Feature::NullsafeOperator => "the nullsafe operator (`?->`)",
+        Feature::Attributes => "attributes",
+        Feature::Enums => "enums",
+        Feature::ReadonlyProperties => "readonly properties",
+        Feature::NamedArguments => "named arguments",
+        Feature::ConstructorPromotion => "constructor property promotion",
+        Feature::MatchExpression => "`match` expressions",
+        Feature::FirstClassCallableSyntax => "first-class callable syntax",
+        Feature::NeverReturnType => "the `never` return type",
+        Feature::ReadonlyClasses => "readonly classes",
+        Feature::TypedClassConstants => "typed class constants",
+        Feature::DynamicClassConstantFetch => "dynamic class constant fetch",
+    }
+}
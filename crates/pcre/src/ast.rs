@@ -0,0 +1,94 @@
+/// A full pattern: one or more `|`-separated alternatives.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pattern {
+    pub alternatives: Vec<Alternative>,
+}
+
+/// A single `|`-branch: a sequence of terms matched in order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Alternative {
+    pub terms: Vec<Term>,
+}
+
+/// An atom together with the quantifier applied to it, if any.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Term {
+    pub atom: Atom,
+    pub quantifier: Option<Quantifier>,
+    /// Byte offset of `atom` in the original pattern source, for
+    /// diagnostics that need to point back at it.
+    pub position: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Atom {
+    Literal(char),
+    /// `.`
+    AnyChar,
+    CharClass(CharClass),
+    Group { kind: GroupKind, pattern: Box<Pattern> },
+    /// `^`, `$`, `\b`, `\B`, `\A`, `\z`, `\Z`.
+    Anchor,
+    /// `\1`, `\2`, ...
+    Backreference(u32),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum GroupKind {
+    Capturing,
+    NonCapturing,
+    Named(String),
+    Lookahead,
+    NegativeLookahead,
+    Lookbehind,
+    NegativeLookbehind,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CharClass {
+    pub negated: bool,
+    pub items: Vec<CharClassItem>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CharClassItem {
+    Char(char),
+    Range(char, char),
+    /// `\d`, `\w`, `\s` and their negated forms.
+    PredefinedClass(char),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quantifier {
+    pub kind: QuantifierKind,
+    /// `?` suffix: match as little as possible.
+    pub lazy: bool,
+    /// `+` suffix: never backtrack into this quantifier.
+    pub possessive: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum QuantifierKind {
+    /// `*`
+    ZeroOrMore,
+    /// `+`
+    OneOrMore,
+    /// `?`
+    ZeroOrOne,
+    /// `{min,max}`; `max` is `None` for an unbounded `{min,}`.
+    Range { min: u32, max: Option<u32> },
+}
+
+impl Quantifier {
+    /// Whether this quantifier repeats its atom a variable number of times:
+    /// `*`, `+`, and a `{min,max}` range that is unbounded or has
+    /// `max > min`; `?` does not count. Nesting two such quantifiers is the
+    /// shape that can cause catastrophic backtracking.
+    pub fn allows_empty_repetition(self) -> bool {
+        match self.kind {
+            QuantifierKind::ZeroOrMore | QuantifierKind::OneOrMore => true,
+            QuantifierKind::ZeroOrOne => false,
+            QuantifierKind::Range { min, max } => max.is_none_or(|max| max > min),
+        }
+    }
+}
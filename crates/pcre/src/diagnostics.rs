@@ -0,0 +1,113 @@
+use crate::ast::Alternative;
+use crate::ast::Atom;
+use crate::ast::GroupKind;
+use crate::ast::Pattern;
+use crate::ast::Term;
+use crate::parser::ParseError;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+/// A single issue found in a pattern, independent of how the caller chooses
+/// to report it (the linter turns these into `Issue`s; a standalone CLI
+/// tool could print them directly).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Finding {
+    pub severity: Severity,
+    pub message: String,
+    /// Byte offset into the pattern source this finding applies to, when
+    /// parsing got far enough to know one.
+    pub position: Option<usize>,
+}
+
+impl Finding {
+    pub fn from_parse_error(error: ParseError) -> Self {
+        let position = match error {
+            ParseError::UnclosedGroup { position }
+            | ParseError::UnmatchedClosingParen { position }
+            | ParseError::UnclosedCharClass { position }
+            | ParseError::InvalidCharacterRange { position, .. }
+            | ParseError::DanglingQuantifier { position }
+            | ParseError::InvalidRepetitionRange { position, .. } => Some(position),
+            ParseError::TrailingBackslash => None,
+        };
+
+        Self { severity: Severity::Error, message: error.to_string(), position }
+    }
+}
+
+/// Runs every structural check against an already-parsed pattern.
+pub fn check(pattern: &Pattern) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    check_alternatives(&pattern.alternatives, &mut findings);
+    findings
+}
+
+fn check_alternatives(alternatives: &[Alternative], findings: &mut Vec<Finding>) {
+    for alternative in alternatives {
+        for term in &alternative.terms {
+            check_term(term, findings);
+        }
+    }
+}
+
+fn check_term(term: &Term, findings: &mut Vec<Finding>) {
+    if let Atom::Group { kind, pattern } = &term.atom {
+        check_alternatives(&pattern.alternatives, findings);
+
+        if matches!(kind, GroupKind::Capturing | GroupKind::NonCapturing | GroupKind::Named(_)) {
+            if let Some(outer) = term.quantifier {
+                if outer.allows_empty_repetition() && group_contains_ambiguous_repetition(pattern) {
+                    findings.push(Finding {
+                        severity: Severity::Warning,
+                        message: "nested repetition on overlapping content is prone to catastrophic backtracking; \
+                                  consider a possessive quantifier (`++`) or an atomic group (`(?>...)`)"
+                            .to_string(),
+                        position: Some(term.position),
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// Whether `pattern` (the body of a quantified group) itself contains a
+/// term whose quantifier passes
+/// [`crate::ast::Quantifier::allows_empty_repetition`] — the `(a+)+`/`(a*)*`
+/// shape where the engine has exponentially many ways to partition a
+/// failing match. Whether the inner and outer repetitions can match the
+/// same content is not checked, so `(a+b)+` is reported as well.
+fn group_contains_ambiguous_repetition(pattern: &Pattern) -> bool {
+    pattern.alternatives.iter().any(|alternative| {
+        alternative.terms.iter().any(|term| term.quantifier.is_some_and(|quantifier| quantifier.allows_empty_repetition()))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::Parser;
+
+    #[test]
+    fn flags_classic_nested_quantifier() {
+        let pattern = Parser::new("(a+)+").parse().unwrap();
+        let findings = check(&pattern);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].severity, Severity::Warning);
+    }
+
+    #[test]
+    fn does_not_flag_a_single_quantifier() {
+        let pattern = Parser::new("a+").parse().unwrap();
+        assert!(check(&pattern).is_empty());
+    }
+
+    #[test]
+    fn does_not_flag_non_overlapping_repetition() {
+        let pattern = Parser::new("(ab)+").parse().unwrap();
+        assert!(check(&pattern).is_empty());
+    }
+}
@@ -0,0 +1,30 @@
+//! A structural parser for the subset of PCRE syntax `preg_*` patterns use.
+//!
+//! This does not aim to be a full regex engine, or even a full PCRE grammar:
+//! it parses just enough structure (groups, character classes, quantifiers,
+//! alternation) to report unbalanced groups and malformed character classes
+//! (as parse errors), and to let [`diagnostics::check`] report the
+//! nested-quantifier shape that causes catastrophic backtracking — the
+//! things worth flagging at lint time, long before the pattern is ever
+//! executed. Modifiers are not part of its input and are not checked here.
+
+pub mod ast;
+pub mod diagnostics;
+pub mod parser;
+
+pub use crate::ast::Pattern;
+pub use crate::diagnostics::Finding;
+pub use crate::parser::ParseError;
+
+/// Parses `source` (the pattern body, without delimiters or modifiers) and
+/// runs every diagnostic check against it.
+///
+/// Returns the findings even when parsing fails outright: a
+/// [`parser::ParseError`] is itself surfaced as a [`Finding`], so callers
+/// only need one code path.
+pub fn analyze(source: &str) -> Vec<Finding> {
+    match parser::Parser::new(source).parse() {
+        Ok(pattern) => diagnostics::check(&pattern),
+        Err(error) => vec![Finding::from_parse_error(error)],
+    }
+}
@@ -0,0 +1,396 @@
+use crate::ast::Alternative;
+use crate::ast::Atom;
+use crate::ast::CharClass;
+use crate::ast::CharClassItem;
+use crate::ast::GroupKind;
+use crate::ast::Pattern;
+use crate::ast::Quantifier;
+use crate::ast::QuantifierKind;
+use crate::ast::Term;
+
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ParseError {
+    #[error("unbalanced group: missing closing `)` for the group opened at byte {position}")]
+    UnclosedGroup { position: usize },
+    #[error("unexpected `)` at byte {position}; no matching `(`")]
+    UnmatchedClosingParen { position: usize },
+    #[error("unterminated character class starting at byte {position}")]
+    UnclosedCharClass { position: usize },
+    #[error("invalid character range `{start}-{end}` at byte {position}: start is greater than end")]
+    InvalidCharacterRange { position: usize, start: char, end: char },
+    #[error("dangling `\\` at the end of the pattern")]
+    TrailingBackslash,
+    #[error("quantifier at byte {position} has nothing to repeat")]
+    DanglingQuantifier { position: usize },
+    #[error("invalid repetition range `{{{text}}}` at byte {position}")]
+    InvalidRepetitionRange { position: usize, text: String },
+}
+
+/// A small recursive-descent parser over a PCRE pattern body (delimiters and
+/// trailing modifiers already stripped).
+pub struct Parser<'a> {
+    source: &'a str,
+    chars: Vec<(usize, char)>,
+    cursor: usize,
+}
+
+impl<'a> Parser<'a> {
+    pub fn new(source: &'a str) -> Self {
+        Self { source, chars: source.char_indices().collect(), cursor: 0 }
+    }
+
+    pub fn parse(mut self) -> Result<Pattern, ParseError> {
+        let pattern = self.parse_pattern()?;
+
+        if let Some(&(position, ')')) = self.chars.get(self.cursor) {
+            return Err(ParseError::UnmatchedClosingParen { position });
+        }
+
+        Ok(pattern)
+    }
+
+    fn parse_pattern(&mut self) -> Result<Pattern, ParseError> {
+        let mut alternatives = vec![self.parse_alternative()?];
+
+        while matches!(self.peek(), Some('|')) {
+            self.cursor += 1;
+            alternatives.push(self.parse_alternative()?);
+        }
+
+        Ok(Pattern { alternatives })
+    }
+
+    fn parse_alternative(&mut self) -> Result<Alternative, ParseError> {
+        let mut terms = Vec::new();
+
+        while let Some(ch) = self.peek() {
+            if ch == '|' || ch == ')' {
+                break;
+            }
+
+            terms.push(self.parse_term()?);
+        }
+
+        Ok(Alternative { terms })
+    }
+
+    fn parse_term(&mut self) -> Result<Term, ParseError> {
+        let position = self.current_offset();
+        let atom = self.parse_atom()?;
+        let quantifier = self.parse_quantifier(position)?;
+
+        Ok(Term { atom, quantifier, position })
+    }
+
+    fn parse_quantifier(&mut self, atom_position: usize) -> Result<Option<Quantifier>, ParseError> {
+        let kind = match self.peek() {
+            Some('*') => {
+                self.cursor += 1;
+                QuantifierKind::ZeroOrMore
+            }
+            Some('+') => {
+                self.cursor += 1;
+                QuantifierKind::OneOrMore
+            }
+            Some('?') => {
+                self.cursor += 1;
+                QuantifierKind::ZeroOrOne
+            }
+            Some('{') => match self.try_parse_repetition_range()? {
+                Some(kind) => kind,
+                None => return Ok(None),
+            },
+            _ => return Ok(None),
+        };
+
+        let _ = atom_position;
+
+        let lazy = matches!(self.peek(), Some('?'));
+        if lazy {
+            self.cursor += 1;
+        }
+
+        let possessive = !lazy && matches!(self.peek(), Some('+'));
+        if possessive {
+            self.cursor += 1;
+        }
+
+        Ok(Some(Quantifier { kind, lazy, possessive }))
+    }
+
+    /// `{n}`, `{n,}`, `{n,m}`. Returns `Ok(None)` (not an error) if `{` isn't
+    /// actually followed by a valid range, since a literal `{` is legal
+    /// PCRE syntax when it doesn't parse as a quantifier.
+    fn try_parse_repetition_range(&mut self) -> Result<Option<QuantifierKind>, ParseError> {
+        let start = self.cursor;
+        let open_position = self.current_offset();
+        self.cursor += 1;
+
+        let min_start = self.cursor;
+        while matches!(self.peek(), Some(c) if c.is_ascii_digit()) {
+            self.cursor += 1;
+        }
+        let min_text: String = self.chars[min_start..self.cursor].iter().map(|&(_, c)| c).collect();
+
+        let mut max_text: Option<String> = None;
+        if matches!(self.peek(), Some(',')) {
+            self.cursor += 1;
+            let max_start = self.cursor;
+            while matches!(self.peek(), Some(c) if c.is_ascii_digit()) {
+                self.cursor += 1;
+            }
+            max_text = Some(self.chars[max_start..self.cursor].iter().map(|&(_, c)| c).collect());
+        }
+
+        if !matches!(self.peek(), Some('}')) || min_text.is_empty() {
+            self.cursor = start;
+            return Ok(None);
+        }
+        self.cursor += 1;
+
+        let min: u32 = min_text.parse().map_err(|_| ParseError::InvalidRepetitionRange {
+            position: open_position,
+            text: min_text.clone(),
+        })?;
+        let max = match max_text {
+            Some(text) if text.is_empty() => None,
+            Some(text) => Some(
+                text.parse::<u32>().map_err(|_| ParseError::InvalidRepetitionRange { position: open_position, text: text.clone() })?,
+            ),
+            None => Some(min),
+        };
+
+        Ok(Some(QuantifierKind::Range { min, max }))
+    }
+
+    fn parse_atom(&mut self) -> Result<Atom, ParseError> {
+        let position = self.current_offset();
+
+        match self.advance() {
+            None => Err(ParseError::DanglingQuantifier { position }),
+            Some('.') => Ok(Atom::AnyChar),
+            Some('^') | Some('$') => Ok(Atom::Anchor),
+            Some('(') => self.parse_group(position),
+            Some('[') => self.parse_char_class(position).map(Atom::CharClass),
+            Some('\\') => self.parse_escape(position),
+            Some('*') | Some('+') | Some('?') => Err(ParseError::DanglingQuantifier { position }),
+            Some(ch) => Ok(Atom::Literal(ch)),
+        }
+    }
+
+    fn parse_group(&mut self, open_position: usize) -> Result<Atom, ParseError> {
+        let kind = if matches!(self.peek(), Some('?')) {
+            self.cursor += 1;
+            match self.peek() {
+                Some(':') => {
+                    self.cursor += 1;
+                    GroupKind::NonCapturing
+                }
+                Some('=') => {
+                    self.cursor += 1;
+                    GroupKind::Lookahead
+                }
+                Some('!') => {
+                    self.cursor += 1;
+                    GroupKind::NegativeLookahead
+                }
+                Some('<') if matches!(self.peek_at(1), Some('=')) => {
+                    self.cursor += 2;
+                    GroupKind::Lookbehind
+                }
+                Some('<') if matches!(self.peek_at(1), Some('!')) => {
+                    self.cursor += 2;
+                    GroupKind::NegativeLookbehind
+                }
+                Some('P') | Some('<') | Some('\'') => {
+                    let name = self.parse_group_name();
+                    GroupKind::Named(name)
+                }
+                _ => GroupKind::NonCapturing,
+            }
+        } else {
+            GroupKind::Capturing
+        };
+
+        let pattern = self.parse_pattern()?;
+
+        if !matches!(self.peek(), Some(')')) {
+            return Err(ParseError::UnclosedGroup { position: open_position });
+        }
+        self.cursor += 1;
+
+        Ok(Atom::Group { kind, pattern: Box::new(pattern) })
+    }
+
+    /// Consumes a `?P<name>`, `?<name>`, or `?'name'` group-name spelling,
+    /// leaving the cursor right after its closing delimiter.
+    fn parse_group_name(&mut self) -> String {
+        if matches!(self.peek(), Some('P')) {
+            self.cursor += 1;
+        }
+
+        let closing = match self.advance() {
+            Some('<') => '>',
+            Some('\'') => '\'',
+            _ => return String::new(),
+        };
+
+        let mut name = String::new();
+        while let Some(ch) = self.peek() {
+            if ch == closing {
+                self.cursor += 1;
+                break;
+            }
+            name.push(ch);
+            self.cursor += 1;
+        }
+
+        name
+    }
+
+    fn parse_char_class(&mut self, open_position: usize) -> Result<CharClass, ParseError> {
+        let negated = matches!(self.peek(), Some('^'));
+        if negated {
+            self.cursor += 1;
+        }
+
+        let mut items = Vec::new();
+        let mut first = true;
+
+        loop {
+            match self.peek() {
+                None => return Err(ParseError::UnclosedCharClass { position: open_position }),
+                Some(']') if !first => {
+                    self.cursor += 1;
+                    break;
+                }
+                _ => {}
+            }
+            first = false;
+
+            let item_position = self.current_offset();
+            let start = self.read_char_class_char()?;
+
+            if matches!(self.peek(), Some('-')) && !matches!(self.peek_at(1), Some(']') | None) {
+                self.cursor += 1;
+                let end = self.read_char_class_char()?;
+
+                match (start, end) {
+                    (CharClassItem::Char(start), CharClassItem::Char(end)) if start > end => {
+                        return Err(ParseError::InvalidCharacterRange { position: item_position, start, end });
+                    }
+                    (CharClassItem::Char(start), CharClassItem::Char(end)) => items.push(CharClassItem::Range(start, end)),
+                    (start, _) => items.push(start),
+                }
+            } else {
+                items.push(start);
+            }
+        }
+
+        Ok(CharClass { negated, items })
+    }
+
+    fn read_char_class_char(&mut self) -> Result<CharClassItem, ParseError> {
+        match self.advance() {
+            Some('\\') => match self.advance() {
+                Some(ch @ ('d' | 'D' | 'w' | 'W' | 's' | 'S')) => Ok(CharClassItem::PredefinedClass(ch)),
+                Some(ch) => Ok(CharClassItem::Char(unescape(ch))),
+                None => Err(ParseError::TrailingBackslash),
+            },
+            Some(ch) => Ok(CharClassItem::Char(ch)),
+            None => Err(ParseError::UnclosedCharClass { position: self.current_offset() }),
+        }
+    }
+
+    fn parse_escape(&mut self, position: usize) -> Result<Atom, ParseError> {
+        match self.advance() {
+            None => Err(ParseError::TrailingBackslash),
+            Some(ch) if ch.is_ascii_digit() && ch != '0' => {
+                let mut digits = String::from(ch);
+                while matches!(self.peek(), Some(c) if c.is_ascii_digit()) {
+                    digits.push(self.advance().unwrap());
+                }
+                Ok(Atom::Backreference(digits.parse().unwrap_or(0)))
+            }
+            Some(ch @ ('b' | 'B' | 'A' | 'Z' | 'z')) => {
+                let _ = ch;
+                Ok(Atom::Anchor)
+            }
+            Some(ch @ ('d' | 'D' | 'w' | 'W' | 's' | 'S')) => {
+                Ok(Atom::CharClass(CharClass { negated: false, items: vec![CharClassItem::PredefinedClass(ch)] }))
+            }
+            Some(ch) => {
+                let _ = position;
+                Ok(Atom::Literal(unescape(ch)))
+            }
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.cursor).map(|&(_, c)| c)
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<char> {
+        self.chars.get(self.cursor + offset).map(|&(_, c)| c)
+    }
+
+    fn advance(&mut self) -> Option<char> {
+        let ch = self.peek();
+        if ch.is_some() {
+            self.cursor += 1;
+        }
+        ch
+    }
+
+    fn current_offset(&self) -> usize {
+        self.chars.get(self.cursor).map(|&(offset, _)| offset).unwrap_or(self.source.len())
+    }
+}
+
+fn unescape(ch: char) -> char {
+    match ch {
+        'n' => '\n',
+        't' => '\t',
+        'r' => '\r',
+        other => other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_simple_alternation() {
+        let pattern = Parser::new("foo|bar").parse().unwrap();
+        assert_eq!(pattern.alternatives.len(), 2);
+    }
+
+    #[test]
+    fn reports_unclosed_group() {
+        assert!(matches!(Parser::new("(foo").parse(), Err(ParseError::UnclosedGroup { .. })));
+    }
+
+    #[test]
+    fn reports_unmatched_closing_paren() {
+        assert!(matches!(Parser::new("foo)").parse(), Err(ParseError::UnmatchedClosingParen { .. })));
+    }
+
+    #[test]
+    fn reports_unclosed_character_class() {
+        assert!(matches!(Parser::new("[abc").parse(), Err(ParseError::UnclosedCharClass { .. })));
+    }
+
+    #[test]
+    fn reports_invalid_character_range() {
+        assert!(matches!(Parser::new("[z-a]").parse(), Err(ParseError::InvalidCharacterRange { .. })));
+    }
+
+    #[test]
+    fn parses_named_group_and_quantifier() {
+        let pattern = Parser::new("(?P<year>[0-9]{4})+").parse().unwrap();
+        let term = &pattern.alternatives[0].terms[0];
+        assert!(matches!(&term.atom, Atom::Group { kind: GroupKind::Named(name), .. } if name == "year"));
+        assert!(matches!(term.quantifier.unwrap().kind, QuantifierKind::OneOrMore));
+    }
+}
@@ -0,0 +1,49 @@
+use mago_php_version::PHPVersion;
+
+pub mod purity;
+pub mod signature;
+
+pub use crate::purity::is_known_pure;
+pub use crate::signature::FunctionSignature;
+pub use crate::signature::Parameter;
+
+/// Looks up the signature of a core/extension function as of `version`,
+/// returning the entry whose `since` is the newest one not newer than
+/// `version`, so callers always see the shape the function actually had on
+/// the target PHP version rather than its current one.
+///
+/// Returns `None` for functions the database has no entry for at all (most
+/// userland and third-party functions) or that were not yet introduced on
+/// `version`.
+pub fn function_signature(name: &str, version: PHPVersion) -> Option<&'static FunctionSignature> {
+    signature::FUNCTIONS
+        .iter()
+        .filter(|signature| {
+            signature.name.eq_ignore_ascii_case(name)
+                && version.is_supported(signature.since)
+                && signature.removed_in.is_none_or(|removed_in| !version.is_supported(removed_in))
+        })
+        .max_by_key(|signature| (signature.since.major, signature.since.minor))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn looks_up_by_name_case_insensitively() {
+        let signature = function_signature("STRLEN", PHPVersion::PHP80).unwrap();
+        assert_eq!(signature.name, "strlen");
+    }
+
+    #[test]
+    fn respects_removal_cutoff() {
+        assert!(function_signature("create_function", PHPVersion::PHP84).is_none());
+        assert!(function_signature("create_function", PHPVersion::PHP70).is_some());
+    }
+
+    #[test]
+    fn unknown_function_returns_none() {
+        assert!(function_signature("not_a_real_function", PHPVersion::PHP84).is_none());
+    }
+}
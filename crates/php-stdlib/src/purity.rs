@@ -0,0 +1,88 @@
+/// Core/extension functions known to always be pure: given the same
+/// arguments, they return the same value and have no effect beyond that -
+/// no I/O, no mutation of anything the caller passed in, no reliance on or
+/// change to global state.
+///
+/// This list is deliberately small and deliberately excludes anything that
+/// writes back through a by-reference parameter (`preg_match`, `sort`,
+/// `array_splice`, ...), takes a callback of unknown purity (`array_map`,
+/// `usort`, ...), or touches the environment, filesystem, randomness, or
+/// time. Leaving a pure function out only costs a missed optimization or
+/// a missed autofix; wrongly including one can make a caller drop or
+/// reorder code that actually mattered.
+const PURE_FUNCTIONS: &[&str] = &[
+    "strlen",
+    "count",
+    "sizeof",
+    "str_contains",
+    "str_starts_with",
+    "str_ends_with",
+    "strtolower",
+    "strtoupper",
+    "ucfirst",
+    "lcfirst",
+    "trim",
+    "ltrim",
+    "rtrim",
+    "substr",
+    "str_repeat",
+    "str_pad",
+    "implode",
+    "explode",
+    "sprintf",
+    "number_format",
+    "abs",
+    "ceil",
+    "floor",
+    "round",
+    "max",
+    "min",
+    "array_keys",
+    "array_values",
+    "array_reverse",
+    "array_unique",
+    "array_merge",
+    "array_slice",
+    "array_combine",
+    "in_array",
+    "array_key_exists",
+    "array_search",
+    "is_array",
+    "is_string",
+    "is_int",
+    "is_float",
+    "is_bool",
+    "is_null",
+    "is_numeric",
+    "is_callable",
+    "is_object",
+    "gettype",
+    "json_encode",
+    "json_decode",
+];
+
+/// Whether `name` (matched case-insensitively, as PHP resolves function
+/// names) is a core/extension function known to be pure.
+pub fn is_known_pure(name: &str) -> bool {
+    PURE_FUNCTIONS.iter().any(|pure| pure.eq_ignore_ascii_case(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recognizes_a_pure_function_case_insensitively() {
+        assert!(is_known_pure("STRLEN"));
+    }
+
+    #[test]
+    fn does_not_recognize_a_function_with_reference_output_parameters() {
+        assert!(!is_known_pure("preg_match"));
+    }
+
+    #[test]
+    fn does_not_recognize_an_unlisted_function() {
+        assert!(!is_known_pure("file_get_contents"));
+    }
+}
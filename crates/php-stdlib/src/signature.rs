@@ -0,0 +1,98 @@
+use mago_php_version::PHPVersion;
+
+/// A single parameter in a [`FunctionSignature`].
+#[derive(Debug, Clone, Copy)]
+pub struct Parameter {
+    pub name: &'static str,
+    pub type_text: &'static str,
+    pub optional: bool,
+    pub variadic: bool,
+    pub by_reference: bool,
+}
+
+impl Parameter {
+    pub const fn required(name: &'static str, type_text: &'static str) -> Self {
+        Self { name, type_text, optional: false, variadic: false, by_reference: false }
+    }
+
+    pub const fn optional(name: &'static str, type_text: &'static str) -> Self {
+        Self { name, type_text, optional: true, variadic: false, by_reference: false }
+    }
+
+    pub const fn variadic(name: &'static str, type_text: &'static str) -> Self {
+        Self { name, type_text, optional: true, variadic: true, by_reference: false }
+    }
+
+    pub const fn by_reference(mut self) -> Self {
+        self.by_reference = true;
+        self
+    }
+}
+
+/// The arity, parameter, and return shape of a core/extension function as of
+/// a specific PHP version range.
+///
+/// A function that changed shape across versions (a new optional parameter,
+/// a widened return type) gets one entry per shape, distinguished by
+/// `since`; [`crate::function_signature`] picks the one matching a given
+/// target version.
+#[derive(Debug, Clone, Copy)]
+pub struct FunctionSignature {
+    pub name: &'static str,
+    pub since: PHPVersion,
+    pub removed_in: Option<PHPVersion>,
+    pub deprecated_in: Option<PHPVersion>,
+    pub parameters: &'static [Parameter],
+    pub return_type: &'static str,
+}
+
+pub(crate) static FUNCTIONS: &[FunctionSignature] = &[
+    FunctionSignature {
+        name: "strlen",
+        since: PHPVersion::PHP70,
+        removed_in: None,
+        deprecated_in: None,
+        parameters: &[Parameter::required("string", "string")],
+        return_type: "int<0, max>",
+    },
+    FunctionSignature {
+        name: "array_map",
+        since: PHPVersion::PHP70,
+        removed_in: None,
+        deprecated_in: None,
+        parameters: &[
+            Parameter::required("callback", "?callable"),
+            Parameter::required("array", "array"),
+            Parameter::variadic("arrays", "array"),
+        ],
+        return_type: "array",
+    },
+    FunctionSignature {
+        name: "array_filter",
+        since: PHPVersion::PHP70,
+        removed_in: None,
+        deprecated_in: None,
+        parameters: &[
+            Parameter::required("array", "array"),
+            Parameter::optional("callback", "?callable"),
+            Parameter::optional("mode", "int"),
+        ],
+        return_type: "array",
+    },
+    FunctionSignature {
+        name: "create_function",
+        since: PHPVersion::PHP70,
+        removed_in: Some(PHPVersion::PHP80),
+        deprecated_in: Some(PHPVersion::PHP74),
+        parameters: &[Parameter::required("args", "string"), Parameter::required("code", "string")],
+        return_type: "string",
+    },
+    FunctionSignature {
+        name: "str_contains",
+        since: PHPVersion::PHP80,
+        removed_in: None,
+        deprecated_in: None,
+        parameters: &[Parameter::required("haystack", "string"), Parameter::required("needle", "string")],
+        return_type: "bool",
+    },
+];
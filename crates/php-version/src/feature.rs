@@ -0,0 +1,43 @@
+use crate::PHPVersion;
+
+/// A single syntax or semantic feature that was introduced in a specific PHP
+/// version, used to gate parsing and to produce targeted "this requires PHP
+/// X.Y" diagnostics instead of a generic syntax error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Feature {
+    TypedProperties,
+    NullsafeOperator,
+    Attributes,
+    Enums,
+    ReadonlyProperties,
+    NamedArguments,
+    ConstructorPromotion,
+    MatchExpression,
+    FirstClassCallableSyntax,
+    NeverReturnType,
+    ReadonlyClasses,
+    TypedClassConstants,
+    DynamicClassConstantFetch,
+}
+
+impl Feature {
+    /// The earliest PHP version this feature is available from.
+    pub const fn introduced_in(self) -> PHPVersion {
+        match self {
+            Feature::TypedProperties => PHPVersion::PHP74,
+            Feature::NullsafeOperator
+            | Feature::Attributes
+            | Feature::NamedArguments
+            | Feature::ConstructorPromotion
+            | Feature::MatchExpression => PHPVersion::PHP80,
+            Feature::Enums | Feature::ReadonlyProperties | Feature::NeverReturnType => PHPVersion::PHP81,
+            Feature::ReadonlyClasses | Feature::DynamicClassConstantFetch => PHPVersion::PHP82,
+            Feature::TypedClassConstants => PHPVersion::PHP83,
+            Feature::FirstClassCallableSyntax => PHPVersion::PHP81,
+        }
+    }
+
+    pub fn is_available_on(self, version: PHPVersion) -> bool {
+        version.is_supported(self.introduced_in())
+    }
+}
@@ -0,0 +1,34 @@
+pub mod feature;
+
+/// A PHP minor version, used to gate parsing of version-specific syntax and
+/// to select the minimum version a formatter/linter rule applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct PHPVersion {
+    pub major: u8,
+    pub minor: u8,
+}
+
+impl PHPVersion {
+    pub const PHP70: PHPVersion = PHPVersion::new(7, 0);
+    pub const PHP74: PHPVersion = PHPVersion::new(7, 4);
+    pub const PHP80: PHPVersion = PHPVersion::new(8, 0);
+    pub const PHP81: PHPVersion = PHPVersion::new(8, 1);
+    pub const PHP82: PHPVersion = PHPVersion::new(8, 2);
+    pub const PHP83: PHPVersion = PHPVersion::new(8, 3);
+    pub const PHP84: PHPVersion = PHPVersion::new(8, 4);
+
+    pub const fn new(major: u8, minor: u8) -> Self {
+        Self { major, minor }
+    }
+
+    /// Whether `self` is at least as new as `required`.
+    pub const fn is_supported(self, required: PHPVersion) -> bool {
+        self.major > required.major || (self.major == required.major && self.minor >= required.minor)
+    }
+}
+
+impl std::fmt::Display for PHPVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
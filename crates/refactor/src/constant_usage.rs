@@ -0,0 +1,60 @@
+use std::collections::HashMap;
+
+use mago_reflection::identifier::SymbolIdentifier;
+use mago_source::SourceIdentifier;
+use mago_span::Span;
+
+/// Every reference to one constant or enum case, grouped by the file it
+/// appears in - the same per-file spans [`crate::rename::plan_rename`]
+/// takes (there each paired with the file's program), so a caller that
+/// already looked up a symbol's references in `mago_names`' reverse index
+/// for a rename can feed the spans in here.
+pub type ConstantUsages = HashMap<SourceIdentifier, Vec<Span>>;
+
+/// An index of every constant and enum case usage across a project,
+/// built up one symbol at a time as a caller walks symbols it cares about.
+///
+/// Backs three things: a dead-code rule checking whether a constant has any
+/// usage at all, a rename refactor's reference list, and the "constant
+/// value change impact" query - the set of call sites worth reviewing
+/// before changing what a constant actually evaluates to.
+#[derive(Debug, Clone, Default)]
+pub struct ConstantUsageIndex {
+    usages: HashMap<SymbolIdentifier, ConstantUsages>,
+}
+
+impl ConstantUsageIndex {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records every usage of `symbol`, replacing whatever was previously
+    /// recorded for it.
+    pub fn record(&mut self, symbol: SymbolIdentifier, usages: ConstantUsages) {
+        self.usages.insert(symbol, usages);
+    }
+
+    pub fn usages_of(&self, symbol: &SymbolIdentifier) -> Option<&ConstantUsages> {
+        self.usages.get(symbol)
+    }
+
+    /// Whether `symbol` has no recorded usage anywhere in the project.
+    ///
+    /// Returns `None` rather than `true` for a symbol that was never
+    /// indexed at all, since that just means the caller hasn't recorded it
+    /// yet - not that it's unused.
+    pub fn is_unused(&self, symbol: &SymbolIdentifier) -> Option<bool> {
+        self.usages.get(symbol).map(|usages| usages.values().all(|spans| spans.is_empty()))
+    }
+
+    /// The "constant value change impact" query: every reference site that
+    /// would need reviewing before changing `symbol`'s value, flattened
+    /// across every file it's used in.
+    pub fn change_impact(&self, symbol: &SymbolIdentifier) -> Vec<(SourceIdentifier, Span)> {
+        let Some(usages) = self.usages.get(symbol) else {
+            return Vec::new();
+        };
+
+        usages.iter().flat_map(|(source, spans)| spans.iter().map(move |span| (source.clone(), *span))).collect()
+    }
+}
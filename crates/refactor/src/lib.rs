@@ -0,0 +1,11 @@
+//! Refactoring APIs shared by the CLI and the LSP crate.
+//!
+//! Each refactor is expressed as a function from a target plus the spans or
+//! items it affects, to edits — [`mago_ast::transform::TextEdit`]s for a
+//! rename, a single [`mago_ast::transform::Transform`] for a reorder — it
+//! never mutates source text directly, so the same logic backs both `mago
+//! refactor rename` and the LSP "rename symbol" request.
+
+pub mod constant_usage;
+pub mod reorder;
+pub mod rename;
@@ -0,0 +1,86 @@
+use std::collections::HashMap;
+
+use mago_ast::ast::*;
+use mago_ast::transform::TextEdit;
+use mago_reflection::identifier::SymbolIdentifier;
+use mago_source::SourceIdentifier;
+use mago_span::HasSpan;
+
+/// Every occurrence of a symbol that must be rewritten for a rename to stay
+/// correct, grouped by the source file it appears in.
+pub type RenameEdits = HashMap<SourceIdentifier, Vec<TextEdit>>;
+
+/// Computes the edits needed to rename `symbol` to `new_name` across every
+/// file that references it.
+///
+/// `references` must already contain every use of `symbol` — declaration
+/// included — found by the caller (typically via `mago_names`' reverse
+/// index); this function only turns those spans into edits and validates
+/// that `new_name` doesn't collide, ignoring case, with a class, interface,
+/// trait, enum or function declared in an affected file's program (at the
+/// top level or inside a namespace or block). Other declarations, such as
+/// constants, are not checked.
+pub fn plan_rename(
+    symbol: &SymbolIdentifier,
+    new_name: &str,
+    references: &HashMap<SourceIdentifier, (Vec<mago_span::Span>, &Program)>,
+) -> Result<RenameEdits, RenameError> {
+    if new_name.is_empty() {
+        return Err(RenameError::InvalidName(new_name.to_string()));
+    }
+
+    let mut edits = RenameEdits::new();
+
+    for (source, (spans, program)) in references {
+        if declares_conflicting_symbol(program, new_name, symbol) {
+            return Err(RenameError::NameCollision { file: source.clone(), name: new_name.to_string() });
+        }
+
+        let file_edits =
+            spans.iter().map(|span| TextEdit { span: *span, replacement: new_name.to_string() }).collect();
+
+        edits.insert(source.clone(), file_edits);
+    }
+
+    Ok(edits)
+}
+
+fn declares_conflicting_symbol(program: &Program, new_name: &str, renaming: &SymbolIdentifier) -> bool {
+    let mut found = false;
+
+    walk_declarations(&program.statements, &mut |declared_name: &str| {
+        if declared_name.eq_ignore_ascii_case(new_name) && declared_name != renaming.name() {
+            found = true;
+        }
+    });
+
+    found
+}
+
+/// Walks `statements` looking for a class-like or function declaration,
+/// feeding each one's name to `f`.
+///
+/// A top-level `const NAME = ...;` declaration is not visited, so a rename
+/// colliding only with a top-level constant is not caught here.
+fn walk_declarations(statements: &[Statement], f: &mut impl FnMut(&str)) {
+    for statement in statements {
+        match statement {
+            Statement::Class(class) => f(&class.name.value),
+            Statement::Interface(interface) => f(&interface.name.value),
+            Statement::Trait(r#trait) => f(&r#trait.name.value),
+            Statement::Enum(r#enum) => f(&r#enum.name.value),
+            Statement::Function(function) => f(&function.name.value),
+            Statement::Namespace(namespace) => walk_declarations(&namespace.statements, f),
+            Statement::Block(block) => walk_declarations(&block.statements, f),
+            _ => {}
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum RenameError {
+    #[error("`{0}` is not a valid identifier")]
+    InvalidName(String),
+    #[error("renaming to `{name}` would collide with an existing declaration in {file:?}")]
+    NameCollision { file: SourceIdentifier, name: String },
+}
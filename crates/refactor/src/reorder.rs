@@ -0,0 +1,104 @@
+//! Reusable machinery for safely reordering a sequence of sibling source
+//! constructs - class members, `use` statements, anything with a stable
+//! list of sibling spans - shared by every sorting-style lint autofix
+//! instead of each rule hand-rolling its own "join the reordered text back
+//! together" logic.
+
+use mago_ast::transform::Transform;
+use mago_span::Span;
+
+/// A single block of source code that can be moved as a unit during a
+/// reorder.
+///
+/// `full_span` must already cover everything that has to move with the
+/// item - a class member's attributes and leading docblock included, not
+/// just the member's own declaration - since this module only ever touches
+/// the region between the first and last item's spans and has no way to
+/// recover anything left outside that a caller forgot to include.
+#[derive(Debug, Clone)]
+pub struct ReorderableItem {
+    pub full_span: Span,
+    pub text: String,
+    /// The original source text between the end of this item and the start
+    /// of the next one (empty for the last item). Carried along so a
+    /// reorder preserves the author's spacing - a blank line kept between
+    /// two members stays a blank line, wherever those members end up.
+    pub trailing_separator: String,
+}
+
+/// Whether every gap between consecutive items (in original source order)
+/// is safe to discard: no gap contains a comment (`//`, `/*` or `#`).
+///
+/// A stray comment sitting in the gap between two members isn't attached to
+/// either one's span, so moving the members without accounting for it would
+/// silently drop or misattribute that comment. Callers should refuse to
+/// autofix - reporting the issue without a fix - when this returns `false`.
+pub fn gaps_are_safe_to_discard<'a>(gaps: impl IntoIterator<Item = &'a str>) -> bool {
+    gaps.into_iter().all(|gap| !gap.contains("//") && !gap.contains("/*") && !gap.contains('#'))
+}
+
+/// Builds the single [`Transform::Replace`] that rewrites the region
+/// spanning `items` (from the first item's start to the last item's end)
+/// into `items` sorted by `key`, preserving each item's own trailing
+/// separator. The item that was last in the source has none of its own; if
+/// it moves, it takes the separator of the item that now ends the list.
+///
+/// Returns `None` for an empty `items`, since there is no span to replace.
+pub fn plan_reorder<K: Ord>(items: Vec<ReorderableItem>, mut key: impl FnMut(&ReorderableItem) -> K) -> Option<Transform> {
+    let first_span = items.first()?.full_span;
+    let last_span = items.last()?.full_span;
+
+    let mut sorted = items;
+    sorted.sort_by_key(&mut key);
+
+    let freed_separator = sorted.last()?.trailing_separator.clone();
+
+    let mut replacement = String::new();
+    for (index, item) in sorted.iter().enumerate() {
+        replacement.push_str(&item.text);
+        if index + 1 != sorted.len() {
+            if item.full_span == last_span {
+                replacement.push_str(&freed_separator);
+            } else {
+                replacement.push_str(&item.trailing_separator);
+            }
+        }
+    }
+
+    Some(Transform::Replace { span: Span::new(first_span.file_id, first_span.start, last_span.end), replacement })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(text: &str, start: u32, end: u32) -> ReorderableItem {
+        ReorderableItem { full_span: Span::new(Default::default(), start, end), text: text.to_string(), trailing_separator: "\n\n".to_string() }
+    }
+
+    #[test]
+    fn sorts_items_and_joins_them_with_their_own_separators() {
+        let items = vec![item("b", 0, 1), item("a", 2, 3)];
+
+        let Some(Transform::Replace { replacement, .. }) = plan_reorder(items, |item| item.text.clone()) else {
+            panic!("expected a replace transform");
+        };
+
+        assert_eq!(replacement, "a\n\nb");
+    }
+
+    #[test]
+    fn a_gap_containing_a_comment_is_not_safe_to_discard() {
+        assert!(!gaps_are_safe_to_discard(["\n\n", "  // keep me\n"]));
+    }
+
+    #[test]
+    fn pure_whitespace_gaps_are_safe_to_discard() {
+        assert!(gaps_are_safe_to_discard(["\n\n", "  \n"]));
+    }
+
+    #[test]
+    fn an_empty_item_list_has_nothing_to_reorder() {
+        assert!(plan_reorder(Vec::new(), |item: &ReorderableItem| item.text.clone()).is_none());
+    }
+}
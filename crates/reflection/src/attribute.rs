@@ -0,0 +1,65 @@
+/// One of the declaration kinds an `#[Attribute]` can target, matching the
+/// `Attribute::TARGET_*` constants PHP exposes at runtime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttributeTarget {
+    Class,
+    Function,
+    Method,
+    Property,
+    Parameter,
+    ClassConstant,
+    EnumCase,
+}
+
+impl std::fmt::Display for AttributeTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            AttributeTarget::Class => "class",
+            AttributeTarget::Function => "function",
+            AttributeTarget::Method => "method",
+            AttributeTarget::Property => "property",
+            AttributeTarget::Parameter => "parameter",
+            AttributeTarget::ClassConstant => "class constant",
+            AttributeTarget::EnumCase => "enum case",
+        };
+
+        f.write_str(name)
+    }
+}
+
+/// A bitset of allowed [`AttributeTarget`]s, as declared by an attribute
+/// class's own `#[Attribute(Attribute::TARGET_CLASS | ...)]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AttributeTargets(u16);
+
+impl AttributeTargets {
+    pub fn from_targets(targets: impl IntoIterator<Item = AttributeTarget>) -> Self {
+        targets.into_iter().fold(Self(0), |acc, target| acc.with(target))
+    }
+
+    pub fn with(self, target: AttributeTarget) -> Self {
+        Self(self.0 | (1 << target as u16))
+    }
+
+    pub fn contains(self, target: AttributeTarget) -> bool {
+        self.0 & (1 << target as u16) != 0
+    }
+}
+
+impl std::fmt::Display for AttributeTargets {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let all = [
+            AttributeTarget::Class,
+            AttributeTarget::Function,
+            AttributeTarget::Method,
+            AttributeTarget::Property,
+            AttributeTarget::Parameter,
+            AttributeTarget::ClassConstant,
+            AttributeTarget::EnumCase,
+        ];
+
+        let names: Vec<String> = all.into_iter().filter(|target| self.contains(*target)).map(|target| target.to_string()).collect();
+
+        write!(f, "{}", names.join(", "))
+    }
+}
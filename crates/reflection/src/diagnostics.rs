@@ -0,0 +1,83 @@
+use mago_span::Span;
+
+/// Kinds of top-level symbol the codebase scanner tracks, used to tell a
+/// genuine name clash (two classes named `Foo`) apart from two different
+/// kinds of symbol legally sharing a name (a class and a function named
+/// `Foo` coexist fine in PHP).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolKind {
+    Class,
+    Interface,
+    Trait,
+    Enum,
+    Function,
+    Constant,
+}
+
+/// One declaration site the scanner found for a symbol.
+///
+/// `conditional` marks a declaration found inside an `if` block or behind a
+/// `class_exists`/`function_exists`/`interface_exists` guard - the common
+/// polyfill pattern, where only one of several declarations of the same
+/// name is ever actually reached at runtime. The scanner still records
+/// these declarations rather than skipping them, since a symbol reflection
+/// must exist for conditionally-declared code to be checked at all.
+#[derive(Debug, Clone)]
+pub struct SymbolDeclaration {
+    pub span: Span,
+    pub conditional: bool,
+}
+
+/// Every declaration site the scanner found for one symbol name, once more
+/// than one was found.
+///
+/// The codebase reflection keeps exactly one declaration per name to
+/// resolve member access and type-checking against - an unconditional
+/// declaration wins over a conditional one, and otherwise the
+/// last-scanned declaration wins - but every alternative is recorded here
+/// instead of silently discarded, so a diagnostics pass can warn about a
+/// genuine duplicate (two unconditional declarations of the same class)
+/// while staying quiet about a deliberate guarded polyfill.
+#[derive(Debug, Clone)]
+pub struct DuplicateSymbol {
+    pub kind: SymbolKind,
+    pub name: String,
+    pub declarations: Vec<SymbolDeclaration>,
+}
+
+impl DuplicateSymbol {
+    /// Whether more than one declaration of this symbol is unconditional -
+    /// the case worth warning about, since at most one of them can ever be
+    /// the real, reachable definition.
+    pub fn has_unconditional_conflict(&self) -> bool {
+        self.declarations.iter().filter(|declaration| !declaration.conditional).count() > 1
+    }
+}
+
+/// Collects the "duplicate symbol" findings the scanner encounters while
+/// building a codebase reflection.
+///
+/// This exists as its own channel, separate from the reflection data
+/// itself, so a caller that only cares about the indexed symbols isn't
+/// forced to sift through diagnostics to get them, while a caller that
+/// wants to report on duplicate declarations (the linter's deprecation or
+/// correctness plugins, for instance) has a single place to read them
+/// from.
+#[derive(Debug, Clone, Default)]
+pub struct ReflectionDiagnostics {
+    duplicates: Vec<DuplicateSymbol>,
+}
+
+impl ReflectionDiagnostics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn report_duplicate(&mut self, duplicate: DuplicateSymbol) {
+        self.duplicates.push(duplicate);
+    }
+
+    pub fn duplicates(&self) -> &[DuplicateSymbol] {
+        &self.duplicates
+    }
+}
@@ -0,0 +1,42 @@
+use std::collections::HashSet;
+
+use crate::Issue;
+
+/// A related diagnostic location, e.g. "previous declaration here" pointing
+/// back from a "duplicate declaration" error.
+#[derive(Debug, Clone)]
+pub struct RelatedInformation {
+    pub span: mago_span::Span,
+    pub message: String,
+}
+
+/// Removes duplicate issues from `issues`, where "duplicate" means the same
+/// code reported at the same primary span.
+///
+/// Running two rules (or the same rule twice, e.g. once per pass) can end up
+/// reporting the identical diagnostic; rather than asking every rule author
+/// to guard against that, we de-duplicate once at the end of the pipeline.
+/// When two duplicates disagree on their related information, the first
+/// one's wins.
+pub fn deduplicate(issues: Vec<Issue>) -> Vec<Issue> {
+    let mut seen = HashSet::new();
+    let mut deduplicated = Vec::with_capacity(issues.len());
+
+    for issue in issues {
+        let key = (issue.code.clone(), issue.primary_span());
+        if seen.insert(key) {
+            deduplicated.push(issue);
+        }
+    }
+
+    deduplicated
+}
+
+impl Issue {
+    /// Attaches a related location to this issue, e.g. pointing back at a
+    /// prior declaration.
+    pub fn with_related(mut self, related: RelatedInformation) -> Self {
+        self.related.push(related);
+        self
+    }
+}
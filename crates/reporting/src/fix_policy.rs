@@ -0,0 +1,126 @@
+use std::collections::HashSet;
+
+use mago_fixer::SafetyClassification;
+
+use crate::Issue;
+
+/// Configurable policy for which of a run's proposed fixes actually get
+/// applied, so that callers aren't stuck with "apply every fix or none."
+///
+/// This only decides whether a fix is *permitted* - the actual application
+/// (reading a [`mago_fixer::FixPlan`]'s edits and writing them to a file)
+/// happens entirely outside this crate, wherever the fixer is wired up.
+/// Note also that nothing in this tree exposes a way to read a `FixPlan`
+/// back out of an [`Issue`] (only [`Issue::has_fix`] is available), so
+/// [`FixApplicationPolicy::permits`] takes the fix's [`SafetyClassification`]
+/// as a separate argument - the caller is expected to already have it from
+/// whatever produced the `FixPlan` in the first place.
+#[derive(Debug, Clone, Default)]
+pub struct FixApplicationPolicy {
+    excluded_codes: HashSet<String>,
+    minimum_safety: Option<SafetyClassification>,
+    interactive: bool,
+}
+
+impl FixApplicationPolicy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Never apply fixes reported under `code`, regardless of safety.
+    pub fn exclude_rule(mut self, code: impl Into<String>) -> Self {
+        self.excluded_codes.insert(code.into());
+        self
+    }
+
+    /// Only apply fixes whose [`SafetyClassification`] is at least as safe
+    /// as `minimum`. Without this, both [`SafetyClassification::Safe`] and
+    /// [`SafetyClassification::PotentiallyUnsafe`] fixes are permitted.
+    pub fn require_safety_at_least(mut self, minimum: SafetyClassification) -> Self {
+        self.minimum_safety = Some(minimum);
+        self
+    }
+
+    /// Mark this policy as interactive: [`FixApplicationPolicy::candidates`]
+    /// is meant to be confirmed one-by-one by an external tool rather than
+    /// applied automatically.
+    pub fn interactive(mut self) -> Self {
+        self.interactive = true;
+        self
+    }
+
+    pub fn is_interactive(&self) -> bool {
+        self.interactive
+    }
+
+    /// Whether a fix reported as `issue`, with the given `safety`
+    /// classification, is permitted by this policy.
+    pub fn permits(&self, issue: &Issue, safety: SafetyClassification) -> bool {
+        if !issue.has_fix() {
+            return false;
+        }
+
+        if let Some(code) = &issue.code {
+            if self.excluded_codes.contains(code) {
+                return false;
+            }
+        }
+
+        match self.minimum_safety {
+            Some(SafetyClassification::Safe) => safety == SafetyClassification::Safe,
+            Some(SafetyClassification::PotentiallyUnsafe) | None => true,
+        }
+    }
+
+    /// Filters `fixes` (issue, safety) pairs down to the ones this policy
+    /// permits. In interactive mode, the result is meant to be presented to
+    /// an external tool for per-fix confirmation rather than applied as-is.
+    pub fn candidates<'a>(&self, fixes: &'a [(Issue, SafetyClassification)]) -> Vec<&'a Issue> {
+        fixes.iter().filter(|(issue, safety)| self.permits(issue, *safety)).map(|(issue, _)| issue).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Level;
+
+    fn issue_with_fix(code: &str) -> Issue {
+        use mago_fixer::FixPlan;
+        use mago_span::Span;
+
+        let mut plan = FixPlan::new();
+        plan.replace(Span::new(0, 0, 0), String::new(), SafetyClassification::Safe);
+
+        Issue::new(Level::Warning, "test").with_code(code).with_fix(plan)
+    }
+
+    #[test]
+    fn excluded_rules_are_never_permitted() {
+        let policy = FixApplicationPolicy::new().exclude_rule("plugin/rule");
+        let issue = issue_with_fix("plugin/rule");
+        assert!(!policy.permits(&issue, SafetyClassification::Safe));
+    }
+
+    #[test]
+    fn safe_only_rejects_potentially_unsafe_fixes() {
+        let policy = FixApplicationPolicy::new().require_safety_at_least(SafetyClassification::Safe);
+        let issue = issue_with_fix("plugin/rule");
+        assert!(policy.permits(&issue, SafetyClassification::Safe));
+        assert!(!policy.permits(&issue, SafetyClassification::PotentiallyUnsafe));
+    }
+
+    #[test]
+    fn without_a_threshold_both_safety_levels_are_permitted() {
+        let policy = FixApplicationPolicy::new();
+        let issue = issue_with_fix("plugin/rule");
+        assert!(policy.permits(&issue, SafetyClassification::Safe));
+        assert!(policy.permits(&issue, SafetyClassification::PotentiallyUnsafe));
+    }
+
+    #[test]
+    fn interactive_defaults_to_false() {
+        assert!(!FixApplicationPolicy::new().is_interactive());
+        assert!(FixApplicationPolicy::new().interactive().is_interactive());
+    }
+}
@@ -0,0 +1,79 @@
+use mago_span::Span;
+
+/// The kind of symbol an [`IssueSymbol`] names, for an IDE to pick the
+/// right icon/navigation behavior without parsing the name itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IssueSymbolKind {
+    Class,
+    Interface,
+    Trait,
+    Enum,
+    Function,
+    Method,
+    Property,
+    Constant,
+    Variable,
+}
+
+/// The symbol an issue is actually about, separate from whatever span the
+/// issue's primary annotation happens to point at.
+///
+/// A deprecated-API warning's annotation points at the call site, but
+/// `symbol` names the deprecated function itself - what a goto-definition
+/// action in an IDE should actually resolve.
+#[derive(Debug, Clone)]
+pub struct IssueSymbol {
+    pub name: String,
+    pub kind: IssueSymbolKind,
+}
+
+/// A span outside an issue's primary annotation that's still relevant to
+/// it - the declaration a "duplicate symbol" issue's other copy lives at,
+/// say - offered as an extra jump target alongside the issue itself.
+#[derive(Debug, Clone)]
+pub struct RelatedSpan {
+    pub span: Span,
+    pub description: String,
+}
+
+/// Structured, machine-readable metadata a rule can attach to an issue
+/// alongside its human-readable message, so an IDE integration can offer
+/// navigation and documentation links without scraping the message text.
+#[derive(Debug, Clone, Default)]
+pub struct IssueMetadata {
+    pub symbol: Option<IssueSymbol>,
+    pub machine_applicable: bool,
+    pub documentation_url: Option<String>,
+    pub related: Vec<RelatedSpan>,
+}
+
+impl IssueMetadata {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_symbol(mut self, symbol: IssueSymbol) -> Self {
+        self.symbol = Some(symbol);
+        self
+    }
+
+    /// Marks the issue's fix (if it has one) as safe for an IDE or CI job
+    /// to apply without a human reviewing it first - stricter than the
+    /// fixer's own [`mago_fixer::SafetyClassification::Safe`], which only
+    /// promises the edit preserves behavior, not that no judgment call was
+    /// involved in offering it.
+    pub fn with_machine_applicable(mut self, machine_applicable: bool) -> Self {
+        self.machine_applicable = machine_applicable;
+        self
+    }
+
+    pub fn with_documentation_url(mut self, url: impl Into<String>) -> Self {
+        self.documentation_url = Some(url.into());
+        self
+    }
+
+    pub fn with_related(mut self, span: Span, description: impl Into<String>) -> Self {
+        self.related.push(RelatedSpan { span, description: description.into() });
+        self
+    }
+}
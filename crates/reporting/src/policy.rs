@@ -0,0 +1,159 @@
+use std::collections::HashSet;
+
+use mago_span::Span;
+
+use crate::Issue;
+use crate::Level;
+
+/// The set of issues from a previous run, used by [`FailurePolicy::new_issues_only`]
+/// to tell CI "don't fail the build on pre-existing issues, only on ones
+/// this change introduced."
+///
+/// Identity is the same `(code, primary span)` pair [`crate::dedup::deduplicate`]
+/// uses, which is good enough for "is this the same issue" across two runs
+/// of the same source file.
+#[derive(Debug, Clone, Default)]
+pub struct Baseline(HashSet<(Option<String>, Option<Span>)>);
+
+impl Baseline {
+    pub fn from_issues(issues: &[Issue]) -> Self {
+        Self(issues.iter().map(issue_identity).collect())
+    }
+
+    fn contains(&self, issue: &Issue) -> bool {
+        self.0.contains(&issue_identity(issue))
+    }
+}
+
+fn issue_identity(issue: &Issue) -> (Option<String>, Option<Span>) {
+    (issue.code.clone(), issue.primary_span())
+}
+
+/// Configurable pass/fail policy for a lint run, so that CI wrappers don't
+/// each have to reimplement "how many warnings are too many" counting.
+#[derive(Debug, Clone, Default)]
+pub struct FailurePolicy {
+    max_warnings: Option<usize>,
+    blocking_codes: HashSet<String>,
+    new_issues_only: bool,
+}
+
+impl FailurePolicy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fail once the number of warning-level issues exceeds `max`. Errors
+    /// always fail regardless of this setting.
+    pub fn fail_on_warnings_above(mut self, max: usize) -> Self {
+        self.max_warnings = Some(max);
+        self
+    }
+
+    /// Treat any issue reported under `code` as failing the build even if
+    /// its level is a warning and the warning threshold isn't otherwise
+    /// exceeded.
+    pub fn block_rule(mut self, code: impl Into<String>) -> Self {
+        self.blocking_codes.insert(code.into());
+        self
+    }
+
+    /// Only consider issues absent from the supplied [`Baseline`] when
+    /// deciding whether to fail; pre-existing issues are still reported but
+    /// don't affect the exit code.
+    pub fn new_issues_only(mut self) -> Self {
+        self.new_issues_only = true;
+        self
+    }
+
+    pub fn evaluate(&self, issues: &[Issue], baseline: Option<&Baseline>) -> ExitDecision {
+        let considered: Vec<&Issue> = issues
+            .iter()
+            .filter(|issue| match (self.new_issues_only, baseline) {
+                (true, Some(baseline)) => !baseline.contains(issue),
+                _ => true,
+            })
+            .collect();
+
+        let mut reasons = Vec::new();
+
+        let error_count = considered.iter().filter(|issue| issue.level == Level::Error).count();
+        if error_count > 0 {
+            reasons.push(format!("{error_count} error(s) reported"));
+        }
+
+        let warning_count = considered.iter().filter(|issue| issue.level == Level::Warning).count();
+        if let Some(max_warnings) = self.max_warnings {
+            if warning_count > max_warnings {
+                reasons.push(format!("{warning_count} warning(s) reported, exceeding the threshold of {max_warnings}"));
+            }
+        }
+
+        for issue in &considered {
+            let Some(code) = &issue.code else { continue };
+            if self.blocking_codes.contains(code) {
+                reasons.push(format!("`{code}` is a blocking rule"));
+            }
+        }
+
+        if reasons.is_empty() { ExitDecision::Pass } else { ExitDecision::Fail(reasons) }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExitDecision {
+    Pass,
+    Fail(Vec<String>),
+}
+
+impl ExitDecision {
+    pub fn is_failure(&self) -> bool {
+        matches!(self, ExitDecision::Fail(_))
+    }
+
+    pub fn exit_code(&self) -> i32 {
+        if self.is_failure() { 1 } else { 0 }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn issue(level: Level, code: &str) -> Issue {
+        Issue::new(level, "test").with_code(code)
+    }
+
+    #[test]
+    fn any_error_fails_by_default() {
+        let policy = FailurePolicy::new();
+        let decision = policy.evaluate(&[issue(Level::Error, "plugin/rule")], None);
+        assert!(decision.is_failure());
+    }
+
+    #[test]
+    fn warnings_pass_until_the_threshold_is_exceeded() {
+        let policy = FailurePolicy::new().fail_on_warnings_above(1);
+        let under = vec![issue(Level::Warning, "plugin/rule")];
+        assert!(!policy.evaluate(&under, None).is_failure());
+
+        let over = vec![issue(Level::Warning, "plugin/rule"), issue(Level::Warning, "plugin/other")];
+        assert!(policy.evaluate(&over, None).is_failure());
+    }
+
+    #[test]
+    fn a_blocking_rule_fails_even_as_a_warning_under_threshold() {
+        let policy = FailurePolicy::new().fail_on_warnings_above(10).block_rule("plugin/rule");
+        let decision = policy.evaluate(&[issue(Level::Warning, "plugin/rule")], None);
+        assert!(decision.is_failure());
+    }
+
+    #[test]
+    fn new_issues_only_ignores_baseline_issues() {
+        let baseline = Baseline::from_issues(&[issue(Level::Error, "plugin/rule")]);
+
+        let policy = FailurePolicy::new().new_issues_only();
+        let decision = policy.evaluate(&[issue(Level::Error, "plugin/rule")], Some(&baseline));
+        assert!(!decision.is_failure());
+    }
+}
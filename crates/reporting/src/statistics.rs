@@ -0,0 +1,122 @@
+use std::collections::HashMap;
+
+use mago_span::Span;
+use serde::Serialize;
+
+use crate::Issue;
+
+/// A structured summary of a lint run, meant to be serialized to JSON for
+/// dashboards and CI annotations rather than read directly off the struct.
+///
+/// Grouping is computed eagerly (rather than, say, keeping the raw issue
+/// list around and grouping lazily) so that serializing it is just a
+/// `serde_json::to_string` away, with no extra logic on the consuming side.
+#[derive(Debug, Clone, Serialize)]
+pub struct IssueStatistics {
+    pub total: usize,
+    pub fixable: usize,
+    pub non_fixable: usize,
+    pub by_level: HashMap<String, usize>,
+    pub by_code: HashMap<String, usize>,
+    pub by_plugin: HashMap<String, usize>,
+    pub by_directory: HashMap<String, usize>,
+    pub top_offending_files: Vec<FileOffenseCount>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FileOffenseCount {
+    pub file: String,
+    pub count: usize,
+}
+
+/// Builds an [`IssueStatistics`] summary from a finished issue list.
+///
+/// `file_of` resolves the file a span belongs to (e.g. via the
+/// `SourceManager` that produced the spans in the first place); issues
+/// without a primary span are counted everywhere except the per-file and
+/// per-directory breakdowns, since there's no file to attribute them to.
+pub fn aggregate_issues(issues: &[Issue], file_of: impl Fn(Span) -> String) -> IssueStatistics {
+    let mut by_level: HashMap<String, usize> = HashMap::new();
+    let mut by_code: HashMap<String, usize> = HashMap::new();
+    let mut by_plugin: HashMap<String, usize> = HashMap::new();
+    let mut by_directory: HashMap<String, usize> = HashMap::new();
+    let mut by_file: HashMap<String, usize> = HashMap::new();
+    let mut fixable = 0usize;
+    let mut non_fixable = 0usize;
+
+    for issue in issues {
+        *by_level.entry(format!("{:?}", issue.level)).or_default() += 1;
+
+        if let Some(code) = &issue.code {
+            *by_code.entry(code.clone()).or_default() += 1;
+
+            if let Some(plugin) = code.split('/').next() {
+                *by_plugin.entry(plugin.to_string()).or_default() += 1;
+            }
+        }
+
+        if issue.has_fix() {
+            fixable += 1;
+        } else {
+            non_fixable += 1;
+        }
+
+        if let Some(span) = issue.primary_span() {
+            let file = file_of(span);
+
+            if let Some((directory, _)) = file.rsplit_once('/') {
+                *by_directory.entry(directory.to_string()).or_default() += 1;
+            }
+
+            *by_file.entry(file).or_default() += 1;
+        }
+    }
+
+    let mut top_offending_files: Vec<FileOffenseCount> =
+        by_file.into_iter().map(|(file, count)| FileOffenseCount { file, count }).collect();
+    top_offending_files.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.file.cmp(&b.file)));
+
+    IssueStatistics { total: issues.len(), fixable, non_fixable, by_level, by_code, by_plugin, by_directory, top_offending_files }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Annotation, AnnotationKind, Level};
+
+    fn issue_with_code(level: Level, code: &str) -> Issue {
+        Issue::new(level, "test")
+            .with_code(code)
+            .with_annotation(Annotation::new(Span::new(Default::default(), 0, 1), AnnotationKind::Primary))
+    }
+
+    #[test]
+    fn groups_by_plugin_from_the_code_prefix() {
+        let issues = vec![issue_with_code(Level::Error, "correctness/duplicate-array-key"), issue_with_code(Level::Warning, "correctness/call-argument-validation")];
+        let statistics = aggregate_issues(&issues, |_| "irrelevant.php".to_string());
+
+        assert_eq!(statistics.by_plugin.get("correctness"), Some(&2));
+        assert_eq!(statistics.total, 2);
+    }
+
+    #[test]
+    fn top_offending_files_are_sorted_by_count_descending() {
+        let issues = vec![
+            issue_with_code(Level::Warning, "correctness/duplicate-array-key"),
+            issue_with_code(Level::Warning, "correctness/duplicate-array-key"),
+            issue_with_code(Level::Warning, "correctness/duplicate-array-key"),
+        ];
+
+        let call = std::cell::Cell::new(0usize);
+        let files = ["a.php", "b.php", "b.php"];
+        let statistics = aggregate_issues(&issues, |span| {
+            let file = files[call.get().min(files.len() - 1)].to_string();
+            let _ = span;
+            call.set(call.get() + 1);
+            file
+        });
+
+        assert_eq!(statistics.top_offending_files[0].file, "b.php");
+        assert_eq!(statistics.top_offending_files[0].count, 2);
+    }
+}
@@ -0,0 +1,68 @@
+/// Finds the closest match to `needle` among `candidates`, for "did you
+/// mean `foo`?" style diagnostics.
+///
+/// Uses Damerau-Levenshtein distance and rejects anything further away than
+/// `max_distance`, so we don't suggest wildly unrelated names just because
+/// nothing closer exists.
+pub fn find_closest_match<'a>(needle: &str, candidates: impl IntoIterator<Item = &'a str>, max_distance: usize) -> Option<&'a str> {
+    candidates
+        .into_iter()
+        .map(|candidate| (candidate, damerau_levenshtein(needle, candidate)))
+        .filter(|(_, distance)| *distance <= max_distance)
+        .min_by_key(|(_, distance)| *distance)
+        .map(|(candidate, _)| candidate)
+}
+
+/// A reasonable default threshold: allow roughly one edit per four
+/// characters of the shorter string, with a floor of 2 so short identifiers
+/// still get suggestions.
+pub fn default_max_distance(a: &str, b: &str) -> usize {
+    (a.len().min(b.len()) / 4).max(2)
+}
+
+fn damerau_levenshtein(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let (len_a, len_b) = (a.len(), b.len());
+
+    let mut distances = vec![vec![0usize; len_b + 1]; len_a + 1];
+    for (i, row) in distances.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for j in 0..=len_b {
+        distances[0][j] = j;
+    }
+
+    for i in 1..=len_a {
+        for j in 1..=len_b {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+
+            distances[i][j] = (distances[i - 1][j] + 1)
+                .min(distances[i][j - 1] + 1)
+                .min(distances[i - 1][j - 1] + cost);
+
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distances[i][j] = distances[i][j].min(distances[i - 2][j - 2] + cost);
+            }
+        }
+    }
+
+    distances[len_a][len_b]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_the_closest_candidate() {
+        let candidates = ["strlen", "str_len", "strtolower"];
+        assert_eq!(find_closest_match("strlne", candidates, 3), Some("strlen"));
+    }
+
+    #[test]
+    fn rejects_matches_beyond_the_threshold() {
+        let candidates = ["completely_unrelated"];
+        assert_eq!(find_closest_match("strlen", candidates, 3), None);
+    }
+}
@@ -0,0 +1,75 @@
+use mago_ast::ast::Program;
+use mago_reporting::Issue;
+
+use crate::checks;
+
+/// A single compile-time-error check, mirroring one category of error PHP's
+/// own compiler would raise (`zend_compile.c`-level errors), detected purely
+/// from the AST.
+pub trait SemanticCheck {
+    fn name(&self) -> &'static str;
+
+    fn check(&self, program: &Program) -> Vec<Issue>;
+}
+
+/// Runs every registered [`SemanticCheck`] over a program and returns the
+/// combined, order-preserved list of issues.
+///
+/// This is what backs `mago lint`'s always-on "compile error" pass: unlike
+/// regular lint rules, these checks aren't configurable or suppressible,
+/// since they report things that would simply fail to run under `php`.
+pub struct SemanticChecker {
+    checks: Vec<Box<dyn SemanticCheck>>,
+}
+
+impl SemanticChecker {
+    pub fn new() -> Self {
+        Self { checks: vec![Box::new(GotoCheck), Box::new(BreakContinueCheck), Box::new(ReservedIdentifiersCheck)] }
+    }
+
+    pub fn check(&self, program: &Program) -> Vec<Issue> {
+        self.checks.iter().flat_map(|check| check.check(program)).collect()
+    }
+}
+
+impl Default for SemanticChecker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct GotoCheck;
+
+impl SemanticCheck for GotoCheck {
+    fn name(&self) -> &'static str {
+        "goto-targets"
+    }
+
+    fn check(&self, program: &Program) -> Vec<Issue> {
+        checks::goto::check_goto_targets(program.statements.as_slice())
+    }
+}
+
+struct BreakContinueCheck;
+
+impl SemanticCheck for BreakContinueCheck {
+    fn name(&self) -> &'static str {
+        "break-continue-targets"
+    }
+
+    fn check(&self, program: &Program) -> Vec<Issue> {
+        checks::break_continue::check_break_continue_targets(program.statements.as_slice())
+    }
+}
+
+struct ReservedIdentifiersCheck;
+
+impl SemanticCheck for ReservedIdentifiersCheck {
+    fn name(&self) -> &'static str {
+        "reserved-identifiers"
+    }
+
+    fn check(&self, program: &Program) -> Vec<Issue> {
+        checks::reserved_identifiers::check_reserved_identifiers(program.statements.as_slice())
+    }
+}
@@ -0,0 +1,43 @@
+use mago_ast::ast::*;
+use mago_reporting::{Annotation, AnnotationKind, Issue, Level};
+use mago_span::HasSpan;
+
+/// Flags `break`/`continue` statements that appear outside of any
+/// loop/switch, which PHP rejects with a fatal compile error ("cannot break
+/// 1 level").
+pub fn check_break_continue_targets(statements: &[Statement]) -> Vec<Issue> {
+    let mut issues = Vec::new();
+    walk(statements, 0, &mut issues);
+    issues
+}
+
+fn walk(statements: &[Statement], depth: u32, issues: &mut Vec<Issue>) {
+    for statement in statements {
+        match statement {
+            Statement::Break(r#break) if depth == 0 => {
+                issues.push(out_of_context_issue("break", r#break.span()));
+            }
+            Statement::Continue(r#continue) if depth == 0 => {
+                issues.push(out_of_context_issue("continue", r#continue.span()));
+            }
+            Statement::For(r#for) => walk(r#for.body.statements(), depth + 1, issues),
+            Statement::Foreach(foreach) => walk(foreach.body.statements(), depth + 1, issues),
+            Statement::While(r#while) => walk(r#while.body.statements(), depth + 1, issues),
+            Statement::DoWhile(do_while) => walk(do_while.body.statements(), depth + 1, issues),
+            Statement::Switch(switch) => {
+                for case in switch.body.cases() {
+                    walk(case.statements(), depth + 1, issues);
+                }
+            }
+            Statement::If(r#if) => walk(r#if.body.statements(), depth, issues),
+            Statement::Block(block) => walk(&block.statements, depth, issues),
+            _ => {}
+        }
+    }
+}
+
+fn out_of_context_issue(keyword: &str, span: mago_span::Span) -> Issue {
+    Issue::new(Level::Error, format!("`{keyword}` outside of a loop or switch"))
+        .with_code("semantics/invalid-break-continue")
+        .with_annotation(Annotation::new(span, AnnotationKind::Primary))
+}
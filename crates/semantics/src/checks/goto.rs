@@ -0,0 +1,89 @@
+use std::collections::HashSet;
+
+use mago_ast::ast::*;
+use mago_reporting::{Annotation, AnnotationKind, Issue, Level};
+use mago_span::HasSpan;
+
+/// Validates `goto` targets within a single function/method body (PHP
+/// forbids jumping into or out of a function, so each body is checked in
+/// isolation).
+///
+/// PHP itself only rejects jumps into a loop/switch body or into another
+/// scope; jumping to an undefined label is also a compile error, which is
+/// the case this mirrors here, without executing anything.
+pub fn check_goto_targets(statements: &[Statement]) -> Vec<Issue> {
+    let mut labels = HashSet::new();
+    collect_labels(statements, &mut labels);
+
+    let mut issues = Vec::new();
+    collect_undefined_gotos(statements, &labels, &mut issues);
+
+    issues
+}
+
+fn collect_labels<'a>(statements: &'a [Statement], labels: &mut HashSet<&'a str>) {
+    walk_nested_statements(statements, &mut |statement| {
+        if let Statement::Label(label) = statement {
+            labels.insert(label.name.value.as_str());
+        }
+    });
+}
+
+fn collect_undefined_gotos(statements: &[Statement], labels: &HashSet<&str>, issues: &mut Vec<Issue>) {
+    walk_nested_statements(statements, &mut |statement| {
+        if let Statement::Goto(goto) = statement {
+            if !labels.contains(goto.label.value.as_str()) {
+                issues.push(
+                    Issue::new(Level::Error, format!("undefined label `{}`", goto.label.value))
+                        .with_code("semantics/undefined-goto-label")
+                        .with_annotation(
+                            Annotation::new(goto.span(), AnnotationKind::Primary)
+                                .with_message("no `goto` target with this name exists in this function"),
+                        ),
+                );
+            }
+        }
+    });
+}
+
+/// Feeds every statement reachable from `statements` to `f`, including
+/// `statements` themselves, without crossing into a nested closure or
+/// arrow function's own body - a `goto`/label pair inside one is its own
+/// isolated scope, not part of the enclosing function's.
+fn walk_nested_statements<'a>(statements: &'a [Statement], f: &mut impl FnMut(&'a Statement)) {
+    for statement in statements {
+        f(statement);
+
+        match statement {
+            Statement::Block(block) => walk_nested_statements(&block.statements, f),
+            Statement::If(r#if) => {
+                walk_nested_statements(std::slice::from_ref(&r#if.body), f);
+                for clause in &r#if.else_if_clauses {
+                    walk_nested_statements(std::slice::from_ref(&clause.body), f);
+                }
+                if let Some(else_clause) = &r#if.else_clause {
+                    walk_nested_statements(std::slice::from_ref(&else_clause.body), f);
+                }
+            }
+            Statement::While(r#while) => walk_nested_statements(std::slice::from_ref(&r#while.body), f),
+            Statement::DoWhile(do_while) => walk_nested_statements(std::slice::from_ref(&do_while.body), f),
+            Statement::For(r#for) => walk_nested_statements(std::slice::from_ref(&r#for.body), f),
+            Statement::Foreach(foreach) => walk_nested_statements(std::slice::from_ref(&foreach.body), f),
+            Statement::Switch(switch) => {
+                for case in switch.body.cases() {
+                    walk_nested_statements(case.statements(), f);
+                }
+            }
+            Statement::Try(r#try) => {
+                walk_nested_statements(&r#try.block.statements, f);
+                for clause in &r#try.catch_clauses {
+                    walk_nested_statements(&clause.block.statements, f);
+                }
+                if let Some(finally) = &r#try.finally_clause {
+                    walk_nested_statements(&finally.block.statements, f);
+                }
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub mod break_continue;
+pub mod goto;
+pub mod reserved_identifiers;
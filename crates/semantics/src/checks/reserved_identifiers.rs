@@ -0,0 +1,78 @@
+use mago_ast::ast::*;
+use mago_reporting::{Annotation, AnnotationKind, Issue, Level};
+use mago_span::HasSpan;
+
+/// Words PHP reserves and won't let a program use as a class-like
+/// (class/interface/trait/enum) or method name.
+///
+/// This mirrors the subset of PHP's reserved-word list that applies to
+/// declarations, not the full keyword list - things like `if` or `class`
+/// itself are rejected by the grammar before a check like this would ever
+/// run.
+const RESERVED_NAMES: &[&str] = &[
+    "self", "parent", "static", "true", "false", "null", "void", "int", "float", "bool", "string", "mixed",
+    "object", "iterable", "never", "callable", "array", "enum",
+];
+
+/// Flags a class-like or method declaration that uses a reserved word as
+/// its name, which PHP rejects with a fatal compile error.
+///
+/// PHP itself reports this as a hard parse/compile error, but this check
+/// models it as a regular semantic diagnostic so that surrounding,
+/// otherwise-valid code can still be analyzed - callers that want
+/// parser-level recovery (continuing to parse past the invalid name) would
+/// need that support in the parser itself, which is out of scope here.
+pub fn check_reserved_identifiers(statements: &[Statement]) -> Vec<Issue> {
+    let mut issues = Vec::new();
+    walk(statements, &mut issues);
+    issues
+}
+
+fn walk(statements: &[Statement], issues: &mut Vec<Issue>) {
+    for statement in statements {
+        match statement {
+            Statement::Class(class) => {
+                check_name(&class.name, "class", issues);
+                check_members(&class.members, issues);
+            }
+            Statement::Interface(interface) => {
+                check_name(&interface.name, "interface", issues);
+                check_members(&interface.members, issues);
+            }
+            Statement::Trait(r#trait) => {
+                check_name(&r#trait.name, "trait", issues);
+                check_members(&r#trait.members, issues);
+            }
+            Statement::Enum(r#enum) => {
+                check_name(&r#enum.name, "enum", issues);
+                check_members(&r#enum.members, issues);
+            }
+            Statement::Namespace(namespace) => walk(&namespace.statements, issues),
+            Statement::Block(block) => walk(&block.statements, issues),
+            _ => {}
+        }
+    }
+}
+
+fn check_members(members: &[ClassLikeMember], issues: &mut Vec<Issue>) {
+    for member in members {
+        if let ClassLikeMember::Method(method) = member {
+            check_name(&method.name, "method", issues);
+        }
+    }
+}
+
+fn check_name(identifier: &LocalIdentifier, kind: &str, issues: &mut Vec<Issue>) {
+    if !RESERVED_NAMES.iter().any(|reserved| identifier.value.eq_ignore_ascii_case(reserved)) {
+        return;
+    }
+
+    issues.push(
+        Issue::new(Level::Error, format!("`{}` is a reserved word and cannot be used as a {kind} name", identifier.value))
+            .with_code("semantics/reserved-identifier")
+            .with_annotation(
+                Annotation::new(identifier.span(), AnnotationKind::Primary)
+                    .with_message("reserved word used here"),
+            ),
+    );
+}
@@ -0,0 +1,5 @@
+pub mod checker;
+pub mod checks;
+
+pub use checker::SemanticCheck;
+pub use checker::SemanticChecker;
@@ -0,0 +1,20 @@
+use crate::SourceIdentifier;
+use crate::SourceManager;
+use crate::overlay::SourceOverlay;
+
+impl SourceManager {
+    /// Loads the content for `identifier`, preferring the in-memory overlay
+    /// (see [`SourceOverlay`]) over whatever is stored on disk.
+    ///
+    /// This is the hook LSP "did change" handling uses: the manager itself
+    /// stays the single source of truth for everything else (paths,
+    /// categories, caching), while the overlay only affects the bytes that
+    /// come back for a given identifier.
+    pub fn load_with_overlay(&self, overlay: &SourceOverlay, identifier: &SourceIdentifier) -> std::io::Result<String> {
+        if let Some(content) = overlay.get(identifier) {
+            return Ok(content);
+        }
+
+        self.load(identifier)
+    }
+}
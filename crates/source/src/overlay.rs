@@ -0,0 +1,66 @@
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use crate::SourceIdentifier;
+
+/// An in-memory layer that shadows on-disk content for a given
+/// [`SourceIdentifier`].
+///
+/// This exists for editor integrations (LSP "dirty buffer" support): while a
+/// file has unsaved changes, the editor's buffer content should take
+/// precedence over whatever is on disk, without us having to write it out.
+#[derive(Debug, Default)]
+pub struct SourceOverlay {
+    buffers: RwLock<HashMap<SourceIdentifier, String>>,
+}
+
+impl SourceOverlay {
+    pub fn new() -> Self {
+        Self { buffers: RwLock::new(HashMap::new()) }
+    }
+
+    /// Registers (or replaces) the overlay content for `identifier`.
+    pub fn set(&self, identifier: SourceIdentifier, content: String) {
+        self.buffers.write().unwrap().insert(identifier, content);
+    }
+
+    /// Removes the overlay for `identifier`, if any, so that subsequent
+    /// reads fall back to the filesystem.
+    pub fn invalidate(&self, identifier: &SourceIdentifier) {
+        self.buffers.write().unwrap().remove(identifier);
+    }
+
+    /// Returns the overlay content for `identifier`, if one is registered.
+    pub fn get(&self, identifier: &SourceIdentifier) -> Option<String> {
+        self.buffers.read().unwrap().get(identifier).cloned()
+    }
+
+    pub fn contains(&self, identifier: &SourceIdentifier) -> bool {
+        self.buffers.read().unwrap().contains_key(identifier)
+    }
+
+    /// Removes every overlay, reverting all sources back to their on-disk
+    /// content.
+    pub fn clear(&self) {
+        self.buffers.write().unwrap().clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overlay_takes_precedence_until_invalidated() {
+        let overlay = SourceOverlay::new();
+        let id = SourceIdentifier::dummy(0);
+
+        assert!(!overlay.contains(&id));
+
+        overlay.set(id, "<?php echo 1;".to_string());
+        assert_eq!(overlay.get(&id).as_deref(), Some("<?php echo 1;"));
+
+        overlay.invalidate(&id);
+        assert_eq!(overlay.get(&id), None);
+    }
+}
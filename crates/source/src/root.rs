@@ -0,0 +1,81 @@
+use std::path::Path;
+use std::path::PathBuf;
+
+/// The kind of source a [`SourceRoot`] contributes, mirroring PHP's
+/// traditional include-path distinction between a project's own code and
+/// the third-party code it merely consumes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SourceCategory {
+    /// Code the user owns; lint and analysis diagnostics apply to it.
+    Project,
+    /// Third-party code (typically `vendor/`), read for reflection but
+    /// never linted.
+    Vendor,
+    /// Bundled stub declarations for core/extension symbols that have no
+    /// PHP source of their own.
+    Stub,
+}
+
+/// A single root directory contributing sources of a given category.
+#[derive(Debug, Clone)]
+pub struct SourceRoot {
+    pub path: PathBuf,
+    pub category: SourceCategory,
+}
+
+impl SourceRoot {
+    pub fn new(path: impl Into<PathBuf>, category: SourceCategory) -> Self {
+        Self { path: path.into(), category }
+    }
+}
+
+/// An ordered collection of [`SourceRoot`]s, used to classify a path by the
+/// most specific (longest) root that contains it.
+///
+/// This is the include-path-style registry the source manager consults
+/// before handing a path to the linter (only `Project` sources are linted)
+/// or to reflection (every category contributes symbols).
+#[derive(Debug, Clone, Default)]
+pub struct SourceRootSet {
+    roots: Vec<SourceRoot>,
+}
+
+impl SourceRootSet {
+    pub fn new() -> Self {
+        Self { roots: Vec::new() }
+    }
+
+    pub fn register(&mut self, root: SourceRoot) {
+        self.roots.push(root);
+    }
+
+    /// Returns the category of the most specific registered root containing
+    /// `path`, or `None` if no root contains it.
+    pub fn category_for(&self, path: &Path) -> Option<SourceCategory> {
+        self.roots
+            .iter()
+            .filter(|root| path.starts_with(&root.path))
+            .max_by_key(|root| root.path.as_os_str().len())
+            .map(|root| root.category)
+    }
+
+    pub fn roots(&self) -> &[SourceRoot] {
+        &self.roots
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn most_specific_root_wins() {
+        let mut set = SourceRootSet::new();
+        set.register(SourceRoot::new("/project", SourceCategory::Project));
+        set.register(SourceRoot::new("/project/vendor", SourceCategory::Vendor));
+
+        assert_eq!(set.category_for(Path::new("/project/src/App.php")), Some(SourceCategory::Project));
+        assert_eq!(set.category_for(Path::new("/project/vendor/acme/lib.php")), Some(SourceCategory::Vendor));
+        assert_eq!(set.category_for(Path::new("/other/file.php")), None);
+    }
+}
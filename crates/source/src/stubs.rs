@@ -0,0 +1,34 @@
+use crate::SourceIdentifier;
+use crate::overlay::SourceOverlay;
+use crate::root::SourceCategory;
+use crate::root::SourceRoot;
+use crate::root::SourceRootSet;
+
+/// Bundled PHP core/extension stubs, embedded at compile time so reflection
+/// and type inference know about built-in functions and classes without
+/// requiring the user's own `vendor/` directory.
+const BUNDLED_STUBS: &[(&str, &str)] =
+    &[("stubs/core.php", include_str!("../stubs/core.php")), ("stubs/array.php", include_str!("../stubs/array.php"))];
+
+/// The virtual root every bundled stub is registered under.
+pub const STUB_ROOT_PATH: &str = "mago://stubs";
+
+/// Adds the virtual stub root to `roots`, and preloads each bundled stub's
+/// content into `overlay` keyed by a [`SourceIdentifier`] derived from its
+/// virtual path.
+///
+/// Stubs are delivered through the overlay rather than the filesystem for
+/// the same reason LSP dirty buffers are: the content has no corresponding
+/// file to read back from disk.
+pub fn register_bundled_stubs(roots: &mut SourceRootSet, overlay: &SourceOverlay) -> Vec<SourceIdentifier> {
+    roots.register(SourceRoot::new(STUB_ROOT_PATH, SourceCategory::Stub));
+
+    BUNDLED_STUBS
+        .iter()
+        .map(|(virtual_path, content)| {
+            let identifier = SourceIdentifier::new(format!("{STUB_ROOT_PATH}/{virtual_path}"));
+            overlay.set(identifier.clone(), (*content).to_string());
+            identifier
+        })
+        .collect()
+}
@@ -0,0 +1,49 @@
+/// A parsed docblock/PHPDoc type expression.
+///
+/// This mirrors the subset of the `phpstan`/`psalm` type syntax that Mago
+/// understands; it is intentionally not a 1:1 mirror of [`mago_ast::ast::Type`]
+/// (the native PHP type hint syntax), since docblock types can express
+/// things native hints cannot (generics, array shapes, literal types).
+#[derive(Debug, Clone, PartialEq)]
+pub enum Type {
+    Scalar(String),
+    Named(String),
+    Nullable(Box<Type>),
+    Generic { base: String, parameters: Vec<Type> },
+    Union(Vec<Type>),
+    Intersection(Vec<Type>),
+    /// `array{foo: int, bar?: string}` — an array literal with a known,
+    /// fixed set of keys, each with its own type. Unlike `Generic`'s
+    /// `array<K, V>`, every key is tracked individually, which is what lets
+    /// a rule flag `$shaped['nope']` as accessing a key the shape doesn't
+    /// declare.
+    ///
+    /// The fields list every key the shape is known to have; for
+    /// assignability, [`crate::subtype::is_subtype_of`] still accepts a
+    /// shape with extra keys where one with fewer is expected.
+    /// Psalm/phpstan's open-shape syntax (a trailing `, ...` entry) isn't
+    /// supported yet.
+    ArrayShape(Vec<ArrayShapeField>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArrayShapeField {
+    pub key: ArrayShapeKey,
+    pub optional: bool,
+    pub value_type: Type,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ArrayShapeKey {
+    Named(String),
+    Integer(i64),
+}
+
+impl std::fmt::Display for ArrayShapeKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ArrayShapeKey::Named(name) => write!(f, "{name}"),
+            ArrayShapeKey::Integer(value) => write!(f, "{value}"),
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use mago_ast::ast::{Array, ArrayElement, Expression, Literal};
+
+use crate::ast::{ArrayShapeField, ArrayShapeKey, Type};
+
+/// Infers the most precise [`Type`] an array literal can be assigned: an
+/// [`Type::ArrayShape`] when every key is known ahead of time, or the
+/// generic `array<array-key, mixed>` fallback once a spread or a skipped
+/// slot makes the final set of keys impossible to pin down statically.
+pub fn infer_from_array_literal(array: &Array) -> Type {
+    let mut fields = Vec::new();
+    let mut next_implicit_index = 0i64;
+
+    for element in array.elements.iter() {
+        match element {
+            ArrayElement::KeyValue(element) => {
+                let Some(key) = literal_key(&element.key) else {
+                    return untyped_array();
+                };
+
+                if let ArrayShapeKey::Integer(index) = &key {
+                    next_implicit_index = next_implicit_index.max(*index + 1);
+                }
+
+                fields.push(ArrayShapeField { key, optional: false, value_type: infer_expression_type(&element.value) });
+            }
+            ArrayElement::Value(element) => {
+                fields.push(ArrayShapeField {
+                    key: ArrayShapeKey::Integer(next_implicit_index),
+                    optional: false,
+                    value_type: infer_expression_type(&element.value),
+                });
+                next_implicit_index += 1;
+            }
+            ArrayElement::Variadic(_) | ArrayElement::Missing(_) => return untyped_array(),
+        }
+    }
+
+    Type::ArrayShape(fields)
+}
+
+fn infer_expression_type(expression: &Expression) -> Type {
+    match expression {
+        Expression::Literal(Literal::String(_)) => Type::Scalar("string".to_string()),
+        Expression::Literal(Literal::Integer(_)) => Type::Scalar("int".to_string()),
+        Expression::Literal(Literal::Float(_)) => Type::Scalar("float".to_string()),
+        Expression::Literal(Literal::Boolean(_)) => Type::Scalar("bool".to_string()),
+        Expression::Literal(Literal::Null(_)) => Type::Scalar("null".to_string()),
+        Expression::Array(nested) => infer_from_array_literal(nested),
+        _ => Type::Named("mixed".to_string()),
+    }
+}
+
+fn literal_key(expression: &Expression) -> Option<ArrayShapeKey> {
+    match expression {
+        Expression::Literal(Literal::String(literal)) => Some(ArrayShapeKey::Named(literal.value.to_string())),
+        Expression::Literal(Literal::Integer(literal)) => Some(ArrayShapeKey::Integer(literal.value)),
+        _ => None,
+    }
+}
+
+fn untyped_array() -> Type {
+    Type::Generic { base: "array".to_string(), parameters: vec![Type::Named("array-key".to_string()), Type::Named("mixed".to_string())] }
+}
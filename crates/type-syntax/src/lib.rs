@@ -0,0 +1,8 @@
+pub mod ast;
+pub mod infer;
+pub mod parser;
+pub mod printer;
+pub mod subtype;
+
+pub use ast::{ArrayShapeField, ArrayShapeKey, Type};
+pub use parser::{parse, ParseError};
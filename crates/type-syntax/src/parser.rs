@@ -0,0 +1,268 @@
+use thiserror::Error;
+
+use crate::ast::{ArrayShapeField, ArrayShapeKey, Type};
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum ParseError {
+    #[error("expected a type, found end of input")]
+    UnexpectedEnd,
+    #[error("expected `{expected}`, found `{found}`")]
+    Expected { expected: char, found: char },
+    #[error("expected a type, found `{found}`")]
+    ExpectedType { found: char },
+    #[error("expected an array shape key or `}}`, found `{found}`")]
+    ExpectedShapeKeyOrEnd { found: char },
+}
+
+/// Parses a docblock/PHPDoc type expression, e.g. `int|null`,
+/// `array<string, int>`, or `array{id: int, name?: string}`.
+pub fn parse(source: &str) -> Result<Type, ParseError> {
+    let mut parser = Parser { chars: source.chars().collect(), cursor: 0 };
+    let result = parser.parse_union()?;
+    parser.skip_whitespace();
+    Ok(result)
+}
+
+struct Parser {
+    chars: Vec<char>,
+    cursor: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.cursor).copied()
+    }
+
+    fn advance(&mut self) -> Option<char> {
+        let ch = self.peek();
+        if ch.is_some() {
+            self.cursor += 1;
+        }
+        ch
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(ch) if ch.is_whitespace()) {
+            self.cursor += 1;
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), ParseError> {
+        self.skip_whitespace();
+        match self.advance() {
+            Some(found) if found == expected => Ok(()),
+            Some(found) => Err(ParseError::Expected { expected, found }),
+            None => Err(ParseError::UnexpectedEnd),
+        }
+    }
+
+    fn consume_if(&mut self, expected: char) -> bool {
+        self.skip_whitespace();
+        if self.peek() == Some(expected) {
+            self.cursor += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn parse_union(&mut self) -> Result<Type, ParseError> {
+        let mut members = vec![self.parse_intersection()?];
+
+        while self.consume_if('|') {
+            members.push(self.parse_intersection()?);
+        }
+
+        if members.len() == 1 { Ok(members.remove(0)) } else { Ok(Type::Union(members)) }
+    }
+
+    fn parse_intersection(&mut self) -> Result<Type, ParseError> {
+        let mut members = vec![self.parse_nullable()?];
+
+        while self.consume_if('&') {
+            members.push(self.parse_nullable()?);
+        }
+
+        if members.len() == 1 { Ok(members.remove(0)) } else { Ok(Type::Intersection(members)) }
+    }
+
+    fn parse_nullable(&mut self) -> Result<Type, ParseError> {
+        if self.consume_if('?') {
+            return Ok(Type::Nullable(Box::new(self.parse_atom()?)));
+        }
+
+        self.parse_atom()
+    }
+
+    fn parse_atom(&mut self) -> Result<Type, ParseError> {
+        self.skip_whitespace();
+
+        let Some(ch) = self.peek() else {
+            return Err(ParseError::UnexpectedEnd);
+        };
+
+        if !is_identifier_start(ch) {
+            return Err(ParseError::ExpectedType { found: ch });
+        }
+
+        let name = self.parse_identifier();
+
+        self.skip_whitespace();
+        if name.eq_ignore_ascii_case("array") && self.peek() == Some('{') {
+            return self.parse_array_shape();
+        }
+
+        if self.consume_if('<') {
+            let mut parameters = vec![self.parse_union()?];
+            while self.consume_if(',') {
+                parameters.push(self.parse_union()?);
+            }
+            self.expect('>')?;
+            return Ok(Type::Generic { base: name, parameters });
+        }
+
+        if is_scalar_keyword(&name) {
+            Ok(Type::Scalar(name))
+        } else {
+            Ok(Type::Named(name))
+        }
+    }
+
+    fn parse_identifier(&mut self) -> String {
+        let start = self.cursor;
+        while matches!(self.peek(), Some(ch) if is_identifier_continue(ch)) {
+            self.cursor += 1;
+        }
+        self.chars[start..self.cursor].iter().collect()
+    }
+
+    fn parse_array_shape(&mut self) -> Result<Type, ParseError> {
+        self.expect('{')?;
+
+        let mut fields = Vec::new();
+        let mut next_implicit_index = 0i64;
+
+        self.skip_whitespace();
+        if !self.consume_if('}') {
+            loop {
+                fields.push(self.parse_array_shape_field(&mut next_implicit_index)?);
+
+                if !self.consume_if(',') {
+                    break;
+                }
+
+                // Allow a trailing comma before the closing brace.
+                self.skip_whitespace();
+                if self.peek() == Some('}') {
+                    break;
+                }
+            }
+
+            self.expect('}')?;
+        }
+
+        Ok(Type::ArrayShape(fields))
+    }
+
+    fn parse_array_shape_field(&mut self, next_implicit_index: &mut i64) -> Result<ArrayShapeField, ParseError> {
+        self.skip_whitespace();
+
+        let checkpoint = self.cursor;
+        let key = if self.peek().is_some_and(is_identifier_start) {
+            let identifier = self.parse_identifier();
+            self.skip_whitespace();
+            let optional = self.consume_if('?');
+
+            if self.consume_if(':') {
+                Some((ArrayShapeKey::Named(identifier), optional))
+            } else {
+                // Wasn't actually `key: Type` or `key?: Type` — it's a bare
+                // positional value type that happens to start like an
+                // identifier; rewind and parse it as the value type below.
+                self.cursor = checkpoint;
+                None
+            }
+        } else {
+            None
+        };
+
+        let (key, optional) = match key {
+            Some((key, optional)) => (key, optional),
+            None => {
+                let key = ArrayShapeKey::Integer(*next_implicit_index);
+                *next_implicit_index += 1;
+                (key, false)
+            }
+        };
+
+        let value_type = self.parse_union()?;
+
+        Ok(ArrayShapeField { key, optional, value_type })
+    }
+}
+
+fn is_identifier_start(ch: char) -> bool {
+    ch.is_ascii_alphabetic() || ch == '_' || ch == '\\'
+}
+
+fn is_identifier_continue(ch: char) -> bool {
+    ch.is_ascii_alphanumeric() || ch == '_' || ch == '\\'
+}
+
+fn is_scalar_keyword(name: &str) -> bool {
+    matches!(
+        name.to_ascii_lowercase().as_str(),
+        "int" | "integer" | "string" | "float" | "double" | "bool" | "boolean" | "true" | "false" | "null" | "void"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_a_simple_union() {
+        assert_eq!(parse("int|string").unwrap(), Type::Union(vec![Type::Scalar("int".to_string()), Type::Scalar("string".to_string())]));
+    }
+
+    #[test]
+    fn parses_a_generic() {
+        assert_eq!(
+            parse("array<string, int>").unwrap(),
+            Type::Generic { base: "array".to_string(), parameters: vec![Type::Scalar("string".to_string()), Type::Scalar("int".to_string())] }
+        );
+    }
+
+    #[test]
+    fn parses_a_nullable_atom() {
+        assert_eq!(parse("?int").unwrap(), Type::Nullable(Box::new(Type::Scalar("int".to_string()))));
+    }
+
+    #[test]
+    fn parses_an_array_shape_with_optional_and_positional_fields() {
+        let parsed = parse("array{id: int, name?: string, bool}").unwrap();
+
+        assert_eq!(
+            parsed,
+            Type::ArrayShape(vec![
+                ArrayShapeField { key: ArrayShapeKey::Named("id".to_string()), optional: false, value_type: Type::Scalar("int".to_string()) },
+                ArrayShapeField {
+                    key: ArrayShapeKey::Named("name".to_string()),
+                    optional: true,
+                    value_type: Type::Scalar("string".to_string())
+                },
+                ArrayShapeField { key: ArrayShapeKey::Integer(0), optional: false, value_type: Type::Scalar("bool".to_string()) },
+            ])
+        );
+    }
+
+    #[test]
+    fn parses_an_empty_array_shape() {
+        assert_eq!(parse("array{}").unwrap(), Type::ArrayShape(vec![]));
+    }
+
+    #[test]
+    fn reports_an_unbalanced_shape() {
+        assert_eq!(parse("array{id: int"), Err(ParseError::UnexpectedEnd));
+    }
+}
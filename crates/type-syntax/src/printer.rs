@@ -0,0 +1,73 @@
+use crate::ast::Type;
+
+/// Renders a [`Type`] back into its canonical textual form.
+///
+/// "Canonical" means a fixed member order for unions/intersections and a
+/// fixed casing for scalar keywords, so that two types that are structurally
+/// equal always print identically, regardless of how the original docblock
+/// spelled them (`INT|string` and `string|int` both print as `int|string`).
+pub fn print(r#type: &Type) -> String {
+    let mut buffer = String::new();
+    write_canonical(r#type, &mut buffer);
+    buffer
+}
+
+fn write_canonical(r#type: &Type, buffer: &mut String) {
+    match r#type {
+        Type::Scalar(name) => buffer.push_str(&name.to_ascii_lowercase()),
+        Type::Named(name) => buffer.push_str(name),
+        Type::Nullable(inner) => {
+            buffer.push('?');
+            write_canonical(inner, buffer);
+        }
+        Type::Generic { base, parameters } => {
+            buffer.push_str(base);
+            buffer.push('<');
+            for (i, parameter) in parameters.iter().enumerate() {
+                if i != 0 {
+                    buffer.push_str(", ");
+                }
+                write_canonical(parameter, buffer);
+            }
+            buffer.push('>');
+        }
+        Type::Union(members) => write_joined(&canonicalize_members(members), "|", buffer),
+        Type::Intersection(members) => write_joined(&canonicalize_members(members), "&", buffer),
+        Type::ArrayShape(fields) => {
+            buffer.push_str("array{");
+            for (i, field) in fields.iter().enumerate() {
+                if i != 0 {
+                    buffer.push_str(", ");
+                }
+                buffer.push_str(&field.key.to_string());
+                if field.optional {
+                    buffer.push('?');
+                }
+                buffer.push_str(": ");
+                write_canonical(&field.value_type, buffer);
+            }
+            buffer.push('}');
+        }
+    }
+}
+
+fn write_joined(members: &[Type], separator: &str, buffer: &mut String) {
+    for (i, member) in members.iter().enumerate() {
+        if i != 0 {
+            buffer.push_str(separator);
+        }
+        write_canonical(member, buffer);
+    }
+}
+
+/// Sorts union/intersection members by their printed form and removes exact
+/// duplicates, which is what lets two syntactically different but
+/// semantically identical unions print the same way.
+fn canonicalize_members(members: &[Type]) -> Vec<Type> {
+    let mut printed: Vec<(String, Type)> = members.iter().map(|member| (print(member), member.clone())).collect();
+
+    printed.sort_by(|a, b| a.0.cmp(&b.0));
+    printed.dedup_by(|a, b| a.0 == b.0);
+
+    printed.into_iter().map(|(_, member)| member).collect()
+}
@@ -0,0 +1,106 @@
+use crate::ast::{ArrayShapeField, ArrayShapeKey, Type};
+
+/// Checks whether `subtype` is assignable to `supertype`.
+///
+/// This is a syntactic, best-effort check over docblock types: it knows
+/// about PHP's scalar widening rules (`int` to `float`), union/intersection
+/// distribution, and nullability, but it does not resolve class hierarchies
+/// (that requires [`mago_reflection`] data the type-syntax crate doesn't
+/// have access to), so `Named` types are only considered compatible when
+/// their names match exactly or when assigning to `mixed`.
+pub fn is_subtype_of(subtype: &Type, supertype: &Type) -> bool {
+    match (subtype, supertype) {
+        (_, Type::Named(name)) if name == "mixed" => true,
+        (Type::Scalar(a), Type::Scalar(b)) => a.eq_ignore_ascii_case(b) || widens_to(a, b),
+        (Type::Named(a), Type::Named(b)) => a == b,
+        (Type::Nullable(inner), Type::Nullable(other)) => is_subtype_of(inner, other),
+        (Type::Nullable(inner), other) => {
+            is_subtype_of(&Type::Scalar("null".to_string()), other) && is_subtype_of(inner, other)
+        }
+        (_, Type::Nullable(other)) => {
+            matches!(subtype, Type::Scalar(name) if name.eq_ignore_ascii_case("null")) || is_subtype_of(subtype, other)
+        }
+        (Type::Union(members), supertype) => members.iter().all(|member| is_subtype_of(member, supertype)),
+        (subtype, Type::Union(members)) => members.iter().any(|member| is_subtype_of(subtype, member)),
+        (Type::Intersection(members), supertype) => members.iter().any(|member| is_subtype_of(member, supertype)),
+        (subtype, Type::Intersection(members)) => members.iter().all(|member| is_subtype_of(subtype, member)),
+        (Type::Generic { base: a, .. }, Type::Generic { base: b, .. }) => a == b,
+        (Type::ArrayShape(sub_fields), Type::ArrayShape(super_fields)) => super_fields.iter().all(|super_field| {
+            match sub_fields.iter().find(|sub_field| sub_field.key == super_field.key) {
+                Some(sub_field) => {
+                    (sub_field.optional || !super_field.optional)
+                        && is_subtype_of(&sub_field.value_type, &super_field.value_type)
+                }
+                None => super_field.optional,
+            }
+        }),
+        _ => false,
+    }
+}
+
+/// PHP's scalar widening: `int` may be passed where `float` is expected.
+fn widens_to(from: &str, to: &str) -> bool {
+    matches!((from.to_ascii_lowercase().as_str(), to.to_ascii_lowercase().as_str()), ("int", "float"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scalar(name: &str) -> Type {
+        Type::Scalar(name.to_string())
+    }
+
+    #[test]
+    fn int_is_a_subtype_of_float() {
+        assert!(is_subtype_of(&scalar("int"), &scalar("float")));
+        assert!(!is_subtype_of(&scalar("float"), &scalar("int")));
+    }
+
+    #[test]
+    fn every_union_member_must_satisfy_a_non_union_supertype() {
+        let union = Type::Union(vec![scalar("int"), scalar("string")]);
+        assert!(!is_subtype_of(&union, &scalar("int")));
+        assert!(is_subtype_of(&union, &Type::Union(vec![scalar("int"), scalar("string"), scalar("bool")])));
+    }
+
+    #[test]
+    fn anything_is_a_subtype_of_mixed() {
+        assert!(is_subtype_of(&scalar("string"), &Type::Named("mixed".to_string())));
+    }
+
+    fn shape(fields: Vec<(&str, bool, Type)>) -> Type {
+        Type::ArrayShape(
+            fields
+                .into_iter()
+                .map(|(key, optional, value_type)| ArrayShapeField {
+                    key: ArrayShapeKey::Named(key.to_string()),
+                    optional,
+                    value_type,
+                })
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn a_shape_with_extra_keys_is_a_subtype_of_one_with_fewer() {
+        let wide = shape(vec![("id", false, scalar("int")), ("name", false, scalar("string"))]);
+        let narrow = shape(vec![("id", false, scalar("int"))]);
+        assert!(is_subtype_of(&wide, &narrow));
+        assert!(!is_subtype_of(&narrow, &wide));
+    }
+
+    #[test]
+    fn a_missing_optional_field_is_still_compatible() {
+        let without_optional = shape(vec![("id", false, scalar("int"))]);
+        let with_optional = shape(vec![("id", false, scalar("int")), ("name", true, scalar("string"))]);
+        assert!(is_subtype_of(&without_optional, &with_optional));
+    }
+
+    #[test]
+    fn a_missing_required_field_is_not_compatible() {
+        let without_name = shape(vec![("id", false, scalar("int"))]);
+        let requires_name = shape(vec![("id", false, scalar("int")), ("name", false, scalar("string"))]);
+        assert!(!is_subtype_of(&without_name, &requires_name));
+    }
+}